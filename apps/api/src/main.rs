@@ -1,87 +1,279 @@
 use anyhow::Result;
 use clap::{Parser, Subcommand};
 use colored::Colorize;
-use gasguard_engine::{ContractScanner, ScanAnalyzer};
-use std::path::PathBuf;
+use gasguard_engine::{
+    CachedScan, ContractScanner, DirectoryScan, GasGuardConfig, JsonScanResult, Profile, SarifLog,
+    ScanAnalyzer, ScanComparison, ScanResult, DEFAULT_CONFIG_FILE,
+};
+use gasguard_rules::ViolationSeverity;
+use std::path::{Path, PathBuf};
+
+/// Exit status reference appended to `--help`
+const EXIT_CODES: &str = "Exit codes:
+  0  Scan finished without reaching a --fail-on or --fail-fast severity or exceeding a budget
+  1  A finding reached the --fail-on or --fail-fast severity, a budget was exceeded, or the scan failed
+  2  Invalid command-line arguments";
 
 #[derive(Parser)]
 #[command(name = "gasguard")]
 #[command(about = "GasGuard: Automated Optimization Suite for Stellar Soroban Contracts")]
 #[command(version = "0.1.0")]
+#[command(after_help = EXIT_CODES)]
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Rule set to run (strict, recommended, gas, security)
+    #[arg(long, global = true, default_value_t = Profile::Recommended)]
+    profile: Profile,
+    /// Project config with severity budgets (default: gasguard.toml, when present)
+    #[arg(long, global = true, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Skip a rule by id; may be repeated
+    #[arg(long, global = true, value_name = "RULE_ID")]
+    disable: Vec<String>,
+    /// Run only these comma-separated rule ids, ignoring the profile's selection
+    #[arg(long, global = true, value_name = "RULE_ID,...", value_delimiter = ',')]
+    enable_only: Option<Vec<String>>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Scan a single Rust file for optimization opportunities
+    #[command(after_help = EXIT_CODES)]
     Scan {
         /// Path to the Rust file to scan
         file: PathBuf,
-        /// Output format (console, json)
+        /// Output format (console, json, sarif)
         #[arg(short, long, default_value = "console")]
         format: String,
+        /// Print a final machine-readable summary line, on stderr for structured formats
+        #[arg(long)]
+        summary_line: bool,
+        /// Also report rules that were skipped because parsed data was missing
+        #[arg(short, long)]
+        verbose: bool,
+        /// Only report findings at or above this severity (info, warning, medium, high, error)
+        #[arg(long, value_name = "SEVERITY")]
+        min_severity: Option<ViolationSeverity>,
+        /// Exit with status 1 after reporting if any finding is at or above this severity
+        #[arg(long, value_name = "SEVERITY")]
+        fail_on: Option<ViolationSeverity>,
+        /// Report how many functions and types each rule inspected, on stderr for structured formats
+        #[arg(long)]
+        coverage: bool,
+        /// Reuse findings saved in this file and run only rules enabled since; the file is updated afterwards
+        #[arg(long, value_name = "RESULT_FILE")]
+        only_changed_rules: Option<PathBuf>,
     },
     /// Scan all Rust files in a directory
+    #[command(after_help = EXIT_CODES)]
     ScanDir {
         /// Path to the directory to scan
         directory: PathBuf,
-        /// Output format (console, json)
+        /// Output format (console, json, sarif, html)
         #[arg(short, long, default_value = "console")]
         format: String,
+        /// Stop at the first file with a finding at or above this severity (default: error)
+        #[arg(long, value_name = "SEVERITY", num_args = 0..=1, default_missing_value = "error")]
+        fail_fast: Option<ViolationSeverity>,
+        /// Print a final machine-readable summary line, on stderr for structured formats
+        #[arg(long)]
+        summary_line: bool,
+        /// Also report rules that were skipped because parsed data was missing
+        #[arg(short, long)]
+        verbose: bool,
+        /// Only report findings at or above this severity (info, warning, medium, high, error)
+        #[arg(long, value_name = "SEVERITY")]
+        min_severity: Option<ViolationSeverity>,
+        /// Exit with status 1 after reporting if any finding is at or above this severity
+        #[arg(long, value_name = "SEVERITY")]
+        fail_on: Option<ViolationSeverity>,
+        /// Report how many functions and types each rule inspected, on stderr for structured formats
+        #[arg(long)]
+        coverage: bool,
     },
     /// Analyze storage optimization potential
+    #[command(after_help = EXIT_CODES)]
     Analyze {
         /// Path to the Rust file or directory to analyze
         path: PathBuf,
+        /// Output format (console, html)
+        #[arg(short, long, default_value = "console")]
+        format: String,
+        /// Rank the estimated savings of applying each rule category's suggestions
+        #[arg(long)]
+        gas_profile: bool,
+        /// Stop at the first file with a finding at or above this severity (default: error)
+        #[arg(long, value_name = "SEVERITY", num_args = 0..=1, default_missing_value = "error")]
+        fail_fast: Option<ViolationSeverity>,
+        /// Print a final machine-readable summary line, on stderr for structured formats
+        #[arg(long)]
+        summary_line: bool,
+        /// Also report rules that were skipped because parsed data was missing
+        #[arg(short, long)]
+        verbose: bool,
+        /// Only report findings at or above this severity (info, warning, medium, high, error)
+        #[arg(long, value_name = "SEVERITY")]
+        min_severity: Option<ViolationSeverity>,
+        /// Exit with status 1 after reporting if any finding is at or above this severity
+        #[arg(long, value_name = "SEVERITY")]
+        fail_on: Option<ViolationSeverity>,
+        /// Report how many functions and types each rule inspected, on stderr for structured formats
+        #[arg(long)]
+        coverage: bool,
+    },
+    /// Compare two scans and report which violations were added or fixed
+    Compare {
+        /// Old version of the file or directory
+        old: PathBuf,
+        /// New version of the file or directory
+        new: PathBuf,
+        /// Output format (console, json)
+        #[arg(short, long, default_value = "console")]
+        format: String,
+        /// List each added and fixed violation under the summary
+        #[arg(long)]
+        details: bool,
+    },
+    /// List every available rule with its severity, category and language
+    Rules {
+        /// Output format (console, json)
+        #[arg(short, long, default_value = "console")]
+        format: String,
     },
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
-    let scanner = ContractScanner::new();
+    let mut scanner = ContractScanner::with_profile(cli.profile);
+    select_rules(&mut scanner, cli.enable_only.as_deref(), &cli.disable);
+    let config = load_config(cli.config.as_deref())?;
 
     match cli.command {
-        Commands::Scan { file, format } => {
-            println!("🔍 Scanning file: {:?}", file);
+        Commands::Scan {
+            file,
+            format,
+            summary_line,
+            verbose,
+            min_severity,
+            fail_on,
+            coverage,
+            only_changed_rules,
+        } => {
+            if format == "console" {
+                println!("🔍 Scanning file: {:?}", file);
+            }
 
-            let result = scanner.scan_file(&file)?;
+            let mut result = match &only_changed_rules {
+                Some(saved) => rescan_changed_rules(&scanner, &file, saved)?,
+                None => scanner.scan_file(&file)?,
+            };
+            if !verbose {
+                result.drop_skip_notes();
+            }
+            // Budgets and --fail-on judge every finding, not only the displayed ones
+            let mut shown = result.clone();
+            if let Some(min) = &min_severity {
+                shown.retain_min_severity(min);
+            }
 
             match format.as_str() {
                 "json" => {
-                    println!("{}", result.to_json()?);
+                    let json = JsonScanResult::new(&shown, scanner.registry());
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                }
+                "sarif" => {
+                    let log =
+                        SarifLog::from_results(std::slice::from_ref(&shown), scanner.registry());
+                    println!("{}", log.to_json()?);
                 }
                 _ => {
-                    println!("{}", ScanAnalyzer::format_violations(&result.violations));
-                    println!("{}", ScanAnalyzer::generate_summary(&result.violations));
+                    println!("{}", ScanAnalyzer::format_violations(&shown.violations));
+                    println!(
+                        "{}",
+                        ScanAnalyzer::generate_summary(&shown.violations, scanner.registry())
+                    );
 
-                    if !result.violations.is_empty() {
-                        let savings = ScanAnalyzer::calculate_storage_savings(&result.violations);
+                    if !shown.violations.is_empty() {
+                        let savings = ScanAnalyzer::calculate_storage_savings(&shown.violations);
                         println!("\n{}", savings);
                     }
                 }
             }
+
+            if coverage {
+                print_coverage(&scanner, &file, &format)?;
+            }
+
+            if summary_line {
+                print_summary_line(std::slice::from_ref(&shown), 1, &format);
+            }
+
+            exit_if_at_least(std::slice::from_ref(&result), fail_on.as_ref());
+            exit_if_over_budget(config.as_ref(), std::slice::from_ref(&result));
         }
-        Commands::ScanDir { directory, format } => {
-            println!("🔍 Scanning directory: {:?}", directory);
+        Commands::ScanDir {
+            directory,
+            format,
+            fail_fast,
+            summary_line,
+            verbose,
+            min_severity,
+            fail_on,
+            coverage,
+        } => {
+            if format == "console" {
+                println!("🔍 Scanning directory: {:?}", directory);
+            }
 
-            let results = scanner.scan_directory(&directory)?;
+            let DirectoryScan {
+                mut results,
+                files_scanned,
+            } = scanner.scan_directory_with_stats(&directory, fail_fast.as_ref())?;
+            if !verbose {
+                results.iter_mut().for_each(ScanResult::drop_skip_notes);
+            }
+            // Budgets and --fail-on judge every finding, not only the displayed ones
+            let shown = displayed(&results, min_severity.as_ref());
 
-            if results.is_empty() {
-                println!("✅ No violations found in any files!");
+            if shown.is_empty() {
+                match format.as_str() {
+                    "sarif" => println!(
+                        "{}",
+                        SarifLog::from_results(&shown, scanner.registry()).to_json()?
+                    ),
+                    "html" => println!("{}", ScanAnalyzer::render_html(&shown, files_scanned)),
+                    _ => println!("✅ No violations found in any files!"),
+                }
+                if coverage {
+                    print_coverage(&scanner, &directory, &format)?;
+                }
+                if summary_line {
+                    print_summary_line(&shown, files_scanned, &format);
+                }
                 return Ok(());
             }
 
-            let total_violations: usize = results.iter().map(|r| r.violations.len()).sum();
+            let total_violations: usize = shown.iter().map(|r| r.violations.len()).sum();
 
             match format.as_str() {
                 "json" => {
-                    println!("{}", serde_json::to_string_pretty(&results)?);
+                    let json: Vec<JsonScanResult> = shown
+                        .iter()
+                        .map(|result| JsonScanResult::new(result, scanner.registry()))
+                        .collect();
+                    println!("{}", serde_json::to_string_pretty(&json)?);
+                }
+                "sarif" => {
+                    let log = SarifLog::from_results(&shown, scanner.registry());
+                    println!("{}", log.to_json()?);
+                }
+                "html" => {
+                    println!("{}", ScanAnalyzer::render_html(&shown, files_scanned));
                 }
                 _ => {
-                    for result in &results {
+                    for result in &shown {
                         println!("\n📁 File: {}", result.source);
                         println!("{}", ScanAnalyzer::format_violations(&result.violations));
                     }
@@ -90,40 +282,94 @@ async fn main() -> Result<()> {
                         "\n{}",
                         format!(
                             "📊 Total violations across {} files: {}",
-                            results.len(),
+                            shown.len(),
                             total_violations
                         )
                         .bold()
                     );
 
                     let all_violations: Vec<_> =
-                        results.iter().flat_map(|r| r.violations.clone()).collect();
+                        shown.iter().flat_map(|r| r.violations.clone()).collect();
                     let savings = ScanAnalyzer::calculate_storage_savings(&all_violations);
                     println!("\n{}", savings);
                 }
             }
+
+            if coverage {
+                print_coverage(&scanner, &directory, &format)?;
+            }
+
+            if summary_line {
+                print_summary_line(&shown, files_scanned, &format);
+            }
+
+            exit_if_at_least(&results, fail_fast.as_ref());
+            exit_if_at_least(&results, fail_on.as_ref());
+            exit_if_over_budget(config.as_ref(), &results);
         }
-        Commands::Analyze { path } => {
-            println!("📊 Analyzing storage optimization potential: {:?}", path);
+        Commands::Analyze {
+            path,
+            format,
+            gas_profile,
+            fail_fast,
+            summary_line,
+            verbose,
+            min_severity,
+            fail_on,
+            coverage,
+        } => {
+            if format == "console" {
+                println!("📊 Analyzing storage optimization potential: {:?}", path);
+            }
 
-            let results = if path.is_file() {
-                vec![scanner.scan_file(&path)?]
+            let DirectoryScan {
+                mut results,
+                files_scanned,
+            } = if path.is_file() {
+                DirectoryScan {
+                    results: vec![scanner.scan_file(&path)?],
+                    files_scanned: 1,
+                }
             } else {
-                scanner.scan_directory(&path)?
+                scanner.scan_directory_with_stats(&path, fail_fast.as_ref())?
             };
+            if !verbose {
+                results.iter_mut().for_each(ScanResult::drop_skip_notes);
+            }
+            // Budgets and --fail-on judge every finding, not only the displayed ones
+            let shown = displayed(&results, min_severity.as_ref());
 
-            if results.is_empty() {
+            if format == "html" {
+                println!("{}", ScanAnalyzer::render_html(&shown, files_scanned));
+                if coverage {
+                    print_coverage(&scanner, &path, &format)?;
+                }
+                if summary_line {
+                    print_summary_line(&shown, files_scanned, &format);
+                }
+                exit_if_at_least(&results, fail_fast.as_ref());
+                exit_if_at_least(&results, fail_on.as_ref());
+                exit_if_over_budget(config.as_ref(), &results);
+                return Ok(());
+            }
+
+            if shown.is_empty() {
                 println!("✅ No optimization opportunities found!");
+                if coverage {
+                    print_coverage(&scanner, &path, "console")?;
+                }
+                if summary_line {
+                    print_summary_line(&shown, files_scanned, &format);
+                }
                 return Ok(());
             }
 
-            let all_violations: Vec<_> =
-                results.iter().flat_map(|r| r.violations.clone()).collect();
+            let all_violations: Vec<_> = shown.iter().flat_map(|r| r.violations.clone()).collect();
             let savings = ScanAnalyzer::calculate_storage_savings(&all_violations);
 
             println!("\n🎯 Storage Analysis Report");
             println!("========================");
-            println!("Files analyzed: {}", results.len());
+            println!("Files analyzed: {}", files_scanned);
             println!("Total violations: {}", all_violations.len());
             println!("\n{}", savings);
 
@@ -144,8 +390,215 @@ async fn main() -> Result<()> {
                 println!("  • Consider using more efficient data types where possible");
                 println!("  • Implement lazy loading patterns for rarely accessed data");
             }
+
+            if gas_profile {
+                println!(
+                    "\n{}",
+                    ScanAnalyzer::gas_profile(&shown, scanner.registry())
+                );
+            }
+
+            if coverage {
+                print_coverage(&scanner, &path, "console")?;
+            }
+
+            if summary_line {
+                print_summary_line(&shown, files_scanned, &format);
+            }
+
+            exit_if_at_least(&results, fail_fast.as_ref());
+            exit_if_at_least(&results, fail_on.as_ref());
+            exit_if_over_budget(config.as_ref(), &results);
         }
+        Commands::Compare {
+            old,
+            new,
+            format,
+            details,
+        } => {
+            let mut old_results = scan_path(&scanner, &old)?;
+            let mut new_results = scan_path(&scanner, &new)?;
+            old_results.iter_mut().for_each(ScanResult::drop_skip_notes);
+            new_results.iter_mut().for_each(ScanResult::drop_skip_notes);
+
+            let comparison = ScanComparison::between(&old_results, &old, &new_results, &new);
+
+            match format.as_str() {
+                "json" => {
+                    println!("{}", serde_json::to_string_pretty(&comparison)?);
+                }
+                _ if details => println!("{}", comparison),
+                _ => println!("🔀 {}", comparison.summary()),
+            }
+        }
+        Commands::Rules { format } => match format.as_str() {
+            "json" => println!("{}", serde_json::to_string_pretty(&scanner.list_rules())?),
+            _ => print_rules(&scanner),
+        },
+    }
+
+    Ok(())
+}
+
+/// Scan a single file or every supported file under a directory
+fn scan_path(scanner: &ContractScanner, path: &Path) -> Result<Vec<ScanResult>> {
+    if path.is_file() {
+        Ok(vec![scanner.scan_file(path)?])
+    } else {
+        scanner.scan_directory(path)
     }
+}
 
+/// Scan `file` reusing the findings saved in `saved` for rules that already ran, then save the new result
+fn rescan_changed_rules(
+    scanner: &ContractScanner,
+    file: &Path,
+    saved: &Path,
+) -> Result<ScanResult> {
+    let content = std::fs::read_to_string(file)?;
+    let previous: Option<CachedScan> = match std::fs::read_to_string(saved) {
+        Ok(json) => serde_json::from_str(&json)
+            .ok()
+            .filter(|previous: &CachedScan| previous.result.source == file.to_string_lossy()),
+        Err(_) => None,
+    };
+
+    let current = match previous {
+        Some(previous) => scanner.rescan_changed_rules(&content, &previous)?,
+        None => scanner.snapshot(scanner.scan_file(file)?),
+    };
+    std::fs::write(saved, serde_json::to_string_pretty(&current)?)?;
+
+    Ok(current.result)
+}
+
+/// Copy of `results` keeping only the findings at or above `--min-severity`, for display
+fn displayed(results: &[ScanResult], min_severity: Option<&ViolationSeverity>) -> Vec<ScanResult> {
+    let mut shown = results.to_vec();
+    if let Some(min) = min_severity {
+        shown.iter_mut().for_each(|r| r.retain_min_severity(min));
+    }
+    shown
+}
+
+/// Print per-rule coverage for `path`; structured formats get it on stderr so stdout stays one document
+fn print_coverage(scanner: &ContractScanner, path: &Path, format: &str) -> Result<()> {
+    let report = scanner.coverage(path)?;
+    match format {
+        "json" => eprintln!("{}", serde_json::to_string_pretty(&report)?),
+        "sarif" | "html" => eprintln!("{}", report),
+        _ => println!("\n{}", report),
+    }
     Ok(())
 }
+
+/// Print the `--summary-line`; structured formats get it on stderr so stdout stays one document
+fn print_summary_line(results: &[ScanResult], files_scanned: usize, format: &str) {
+    let line = ScanAnalyzer::summary_line(results, files_scanned);
+    match format {
+        "json" | "sarif" | "html" => eprintln!("{}", line),
+        _ => println!("{}", line),
+    }
+}
+
+/// Print every rule as a table, disabled rules dimmed
+fn print_rules(scanner: &ContractScanner) {
+    let rules = scanner.list_rules();
+    let id_width = rules.iter().map(|rule| rule.id.len()).max().unwrap_or(0);
+
+    println!(
+        "{:<id_width$}  {:<8}  {:<8}  {:<11}  DESCRIPTION",
+        "ID", "LANGUAGE", "SEVERITY", "CATEGORY"
+    );
+    for rule in &rules {
+        let row = format!(
+            "{:<id_width$}  {:<8}  {:<8}  {:<11}  {}",
+            rule.id,
+            rule.language.to_string(),
+            format!("{:?}", rule.severity).to_lowercase(),
+            rule.category.to_string(),
+            rule.description
+        );
+        if rule.enabled {
+            println!("{}", row);
+        } else {
+            println!("{}", row.dimmed());
+        }
+    }
+
+    let enabled = rules.iter().filter(|rule| rule.enabled).count();
+    println!(
+        "\n📋 {} rules, {} enabled under the {} profile",
+        rules.len(),
+        enabled,
+        scanner.registry().profile()
+    );
+}
+
+/// Exit with status 1 when a `--fail-fast`/`--fail-on` threshold was given and reached
+fn exit_if_at_least(results: &[ScanResult], threshold: Option<&ViolationSeverity>) {
+    let Some(threshold) = threshold else {
+        return;
+    };
+
+    let failed = results
+        .iter()
+        .flat_map(|r| r.violations.iter())
+        .any(|v| v.severity.is_at_least(threshold));
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Apply `--enable-only` and then `--disable`, warning about ids no rule has
+fn select_rules(scanner: &mut ContractScanner, enable_only: Option<&[String]>, disable: &[String]) {
+    let requested = enable_only.unwrap_or_default().iter().chain(disable);
+    let unknown: Vec<&String> = requested
+        .filter(|id| scanner.registry().get(id).is_none())
+        .collect();
+    if !unknown.is_empty() {
+        let valid: Vec<&str> = scanner
+            .registry()
+            .rules()
+            .map(|rule| rule.id.as_str())
+            .collect();
+        for id in unknown {
+            eprintln!("{}", format!("⚠️  Unknown rule id '{}'", id).yellow());
+        }
+        eprintln!("Valid rule ids: {}", valid.join(", "));
+    }
+
+    if let Some(ids) = enable_only {
+        scanner.enable_only(ids);
+    }
+    for id in disable {
+        scanner.set_rule_enabled(id, false);
+    }
+}
+
+/// Read `--config`, or `gasguard.toml` when it exists, with the directory its globs are relative to
+fn load_config(path: Option<&Path>) -> Result<Option<(GasGuardConfig, PathBuf)>> {
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None if Path::new(DEFAULT_CONFIG_FILE).is_file() => PathBuf::from(DEFAULT_CONFIG_FILE),
+        None => return Ok(None),
+    };
+    let config = GasGuardConfig::load(&path)?;
+    let root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    Ok(Some((config, root)))
+}
+
+/// Exit with status 1, naming each budget, when findings exceed a configured budget
+fn exit_if_over_budget(config: Option<&(GasGuardConfig, PathBuf)>, results: &[ScanResult]) {
+    let Some((config, root)) = config else {
+        return;
+    };
+
+    let exceeded = config.evaluate_budgets(results, root);
+    for budget in &exceeded {
+        eprintln!("{}", format!("💸 {}", budget).red().bold());
+    }
+    if !exceeded.is_empty() {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,139 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// Only warning- and info-level findings: an unused parameter and a pure function
+const WARNINGS_ONLY: &str = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn ping(env: Env, unused: u32) -> u32 {
+        1
+    }
+}
+"#;
+
+/// Directory holding `WARNINGS_ONLY` and a config allowing no warnings
+fn project(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("gasguard-cli-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("vault.rs"), WARNINGS_ONLY).unwrap();
+    std::fs::write(
+        dir.join("gasguard.toml"),
+        "[budgets.\"*.rs\"]\nwarnings = 0\n",
+    )
+    .unwrap();
+    dir
+}
+
+fn gasguard(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_gasguard-api"))
+        .arg("--config")
+        .arg(dir.join("gasguard.toml"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_min_severity_does_not_hide_findings_from_budgets() {
+    let dir = project("budget");
+    let directory = dir.to_string_lossy().to_string();
+
+    let output = gasguard(
+        &dir,
+        &[
+            "scan-dir",
+            &directory,
+            "--min-severity",
+            "high",
+            "--summary-line",
+        ],
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("gasguard: files=1 errors=0 warnings=0 info=0"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Budget '*.rs' exceeded"));
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_min_severity_does_not_hide_findings_from_fail_on() {
+    let dir = project("fail-on");
+    std::fs::write(dir.join("gasguard.toml"), "").unwrap();
+    let directory = dir.to_string_lossy().to_string();
+
+    let filtered = gasguard(
+        &dir,
+        &[
+            "scan-dir",
+            &directory,
+            "--min-severity",
+            "error",
+            "--fail-on",
+            "warning",
+        ],
+    );
+    let passing = gasguard(
+        &dir,
+        &[
+            "scan-dir",
+            &directory,
+            "--min-severity",
+            "error",
+            "--fail-on",
+            "high",
+        ],
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(filtered.status.code(), Some(1));
+    assert_eq!(passing.status.code(), Some(0));
+}
+
+#[test]
+fn test_coverage_keeps_json_output_parseable() {
+    let dir = project("coverage");
+    std::fs::write(dir.join("gasguard.toml"), "").unwrap();
+    let directory = dir.to_string_lossy().to_string();
+
+    let output = gasguard(
+        &dir,
+        &["scan-dir", &directory, "--format", "json", "--coverage"],
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let results: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(results.as_array().map(Vec::len), Some(1));
+    assert_eq!(results[0]["fixable"], 0);
+    let coverage: serde_json::Value = serde_json::from_slice(&output.stderr).unwrap();
+    assert_eq!(coverage["files"], 1);
+}
+
+#[test]
+fn test_html_output_ends_with_the_document() {
+    let dir = project("html");
+    std::fs::write(dir.join("gasguard.toml"), "").unwrap();
+    std::fs::write(dir.join("clean.rs"), "pub fn clean() {}\n").unwrap();
+    let directory = dir.to_string_lossy().to_string();
+
+    let output = gasguard(
+        &dir,
+        &[
+            "scan-dir",
+            &directory,
+            "--format",
+            "html",
+            "--summary-line",
+            "--coverage",
+        ],
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.trim_end().ends_with("</html>"));
+    assert!(stdout.contains("2 files scanned"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("gasguard: files=2"));
+}
@@ -1,9 +1,41 @@
+use crate::{RuleRegistry, ScanResult};
 use colored::*;
-use gasguard_rules::{RuleViolation, ViolationSeverity};
+use gasguard_rules::{RuleCategory, RuleViolation, SavingUnit, ViolationSeverity};
+use std::collections::BTreeMap;
 use std::fmt;
 
 pub struct ScanAnalyzer;
 
+/// Size assumed for an unused field whose type is unknown, as the cost model does for collections
+const UNKNOWN_FIELD_BYTES: u64 = 64;
+
+/// Rough ledger rent for keeping one KB of contract data for a month
+const RENT_XLM_PER_KB_MONTH: f64 = 0.001;
+
+/// Page wrapping `render_html` output; `{title}` and `{body}` are filled in
+const HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 2rem auto; max-width: 960px; color: #1f2328; }
+h1 { font-size: 1.6rem; }
+section.file { border: 1px solid #d0d7de; border-radius: 6px; padding: 0 1rem 1rem; margin-bottom: 1.5rem; }
+.violation { border-top: 1px solid #eaeef2; padding: 0.6rem 0; }
+.badge { display: inline-block; border-radius: 1em; padding: 0.1em 0.7em; color: #fff; font-size: 0.8rem; font-weight: 600; }
+.error { background: #cf222e; } .high { background: #bc4c00; } .medium { background: #9a6700; }
+.warning { background: #bf8700; } .info { background: #0969da; }
+.suggestion { font-style: italic; color: #57606a; }
+.savings { background: #dafbe1; border-radius: 6px; padding: 0.5rem 1rem; }
+</style>
+</head>
+<body>
+{body}
+</body>
+</html>
+"#;
+
 impl ScanAnalyzer {
     pub fn format_violations(violations: &[RuleViolation]) -> String {
         if violations.is_empty() {
@@ -45,37 +77,237 @@ impl ScanAnalyzer {
         output
     }
 
-    pub fn generate_summary(violations: &[RuleViolation]) -> String {
+    pub fn generate_summary(violations: &[RuleViolation], registry: &RuleRegistry) -> String {
         let total = violations.len();
         let (errors, warnings, info) = Self::categorize_violations(violations);
 
-        format!(
+        let mut summary = format!(
             "Scan Summary: {} total violations ({} errors, {} warnings, {} info)",
             total,
             errors.len(),
             warnings.len(),
             info.len()
+        );
+        let fixable = Self::fixable_count(violations, registry);
+        if fixable > 0 {
+            summary.push_str(&format!(" ({} auto-fixable — run `gasguard fix`)", fixable));
+        }
+        summary
+    }
+
+    /// Number of findings from rules whose suggestions `gasguard fix` can apply
+    pub fn fixable_count(violations: &[RuleViolation], registry: &RuleRegistry) -> usize {
+        violations
+            .iter()
+            .filter(|v| {
+                registry
+                    .get(&v.rule_name)
+                    .is_some_and(|rule| rule.auto_fixable)
+            })
+            .count()
+    }
+
+    /// Count violations per severity class and per rule
+    pub fn rule_statistics(violations: &[RuleViolation]) -> RuleStatistics {
+        let (errors, warnings, info) = Self::categorize_violations(violations);
+
+        let mut by_rule = BTreeMap::new();
+        for violation in violations {
+            *by_rule.entry(violation.rule_name.clone()).or_insert(0) += 1;
+        }
+
+        RuleStatistics {
+            errors: errors.len(),
+            warnings: warnings.len(),
+            info: info.len(),
+            by_rule,
+        }
+    }
+
+    /// Score from 0 to 100, where each error costs 10 points, each warning 3 and each info 1
+    pub fn optimization_score(statistics: &RuleStatistics) -> u32 {
+        let penalty = statistics.errors * 10 + statistics.warnings * 3 + statistics.info;
+        100u32.saturating_sub(penalty.min(100) as u32)
+    }
+
+    /// Stable single-line summary for CI systems parsing the last line of output
+    ///
+    /// `files_scanned` counts every file read, including those without
+    /// violations that a directory scan leaves out of `results`.
+    pub fn summary_line(results: &[ScanResult], files_scanned: usize) -> String {
+        let all_violations: Vec<_> = results.iter().flat_map(|r| r.violations.clone()).collect();
+        let statistics = Self::rule_statistics(&all_violations);
+
+        format!(
+            "gasguard: files={} errors={} warnings={} info={} score={}",
+            files_scanned,
+            statistics.errors,
+            statistics.warnings,
+            statistics.info,
+            Self::optimization_score(&statistics)
         )
     }
 
+    /// Sum estimated savings per rule category and per contract, biggest wins first
+    ///
+    /// Categories come from `registry`; findings of unregistered rules count as gas.
+    pub fn gas_profile(results: &[ScanResult], registry: &RuleRegistry) -> GasProfile {
+        let mut by_category: BTreeMap<(SavingUnit, RuleCategory), BTreeMap<String, RuleSaving>> =
+            BTreeMap::new();
+        let mut contracts = Vec::new();
+
+        for result in results {
+            let mut contract = ContractSaving {
+                source: result.source.clone(),
+                instructions: 0,
+                bytes: 0,
+            };
+
+            for violation in &result.violations {
+                let Some(saving) = violation.estimated_saving else {
+                    continue;
+                };
+
+                match saving.unit {
+                    SavingUnit::Instructions => contract.instructions += saving.amount,
+                    SavingUnit::Bytes => contract.bytes += saving.amount,
+                }
+
+                let category = registry
+                    .get(&violation.rule_name)
+                    .map_or(RuleCategory::Gas, |rule| rule.category);
+                let rule = by_category
+                    .entry((saving.unit, category))
+                    .or_default()
+                    .entry(violation.rule_name.clone())
+                    .or_insert_with(|| RuleSaving {
+                        rule_name: violation.rule_name.clone(),
+                        findings: 0,
+                        total_saving: 0,
+                    });
+                rule.findings += 1;
+                rule.total_saving += saving.amount;
+            }
+
+            contracts.push(contract);
+        }
+
+        let mut wins: Vec<ProfileEntry> = by_category
+            .into_iter()
+            .map(|((unit, category), rules)| {
+                let mut rules: Vec<RuleSaving> = rules.into_values().collect();
+                rules.sort_by_key(|rule| std::cmp::Reverse(rule.total_saving));
+                ProfileEntry {
+                    category,
+                    unit,
+                    findings: rules.iter().map(|rule| rule.findings).sum(),
+                    total_saving: rules.iter().map(|rule| rule.total_saving).sum(),
+                    rules,
+                }
+            })
+            .collect();
+        wins.sort_by(|a, b| {
+            a.unit
+                .cmp(&b.unit)
+                .then(b.total_saving.cmp(&a.total_saving))
+        });
+        contracts.sort_by(|a, b| {
+            b.instructions
+                .cmp(&a.instructions)
+                .then(b.bytes.cmp(&a.bytes))
+        });
+
+        GasProfile { wins, contracts }
+    }
+
     pub fn calculate_storage_savings(violations: &[RuleViolation]) -> StorageSavings {
         let mut unused_vars = 0;
-        let mut estimated_savings_kb = 0.0;
+        let mut estimated_savings_bytes = 0;
 
         for violation in violations {
             if violation.rule_name == "unused-state-variable" {
                 unused_vars += 1;
-                // Estimate average storage cost per unused variable
-                // This is a rough estimate - actual costs vary by type
-                estimated_savings_kb += 2.5; // Average variable size in KB
+                // The analyzer sizes each field from its declared type
+                estimated_savings_bytes += match &violation.estimated_saving {
+                    Some(saving) if saving.unit == SavingUnit::Bytes => saving.amount,
+                    _ => UNKNOWN_FIELD_BYTES,
+                };
             }
         }
 
         StorageSavings {
             unused_variables: unused_vars,
-            estimated_savings_kb,
-            monthly_ledger_rent_savings: estimated_savings_kb * 0.001, // Rough estimate
+            estimated_savings_bytes,
+            monthly_ledger_rent_savings: estimated_savings_bytes as f64 / 1024.0
+                * RENT_XLM_PER_KB_MONTH,
+        }
+    }
+
+    /// Self-contained HTML page listing each file's findings by severity and the storage savings
+    pub fn render_html(results: &[ScanResult], files_scanned: usize) -> String {
+        let severities = [
+            (ViolationSeverity::Error, "Error", "error"),
+            (ViolationSeverity::High, "High", "high"),
+            (ViolationSeverity::Medium, "Medium", "medium"),
+            (ViolationSeverity::Warning, "Warning", "warning"),
+            (ViolationSeverity::Info, "Info", "info"),
+        ];
+        let all_violations: Vec<_> = results.iter().flat_map(|r| r.violations.clone()).collect();
+        let mut body = format!(
+            "<h1>GasGuard Scan Report</h1>\n<p>{} files scanned, {} violations found.</p>\n",
+            files_scanned,
+            all_violations.len()
+        );
+
+        for result in results {
+            body.push_str(&format!(
+                "<section class=\"file\">\n<h2>{}</h2>\n",
+                escape_html(&result.source)
+            ));
+            if result.violations.is_empty() {
+                body.push_str("<p>No violations found.</p>\n");
+            }
+            for (severity, label, class) in &severities {
+                let group: Vec<_> = result
+                    .violations
+                    .iter()
+                    .filter(|v| v.severity == *severity)
+                    .collect();
+                if group.is_empty() {
+                    continue;
+                }
+                body.push_str(&format!("<h3>{} ({})</h3>\n", label, group.len()));
+                for violation in group {
+                    body.push_str(&format!(
+                        "<div class=\"violation\"><span class=\"badge {}\">{}</span> \
+                         <strong>{}</strong> line {} <code>{}</code>\
+                         <p>{}</p><p class=\"suggestion\">{}</p></div>\n",
+                        class,
+                        label,
+                        escape_html(&violation.variable_name),
+                        violation.line_number,
+                        escape_html(&violation.rule_name),
+                        escape_html(&violation.description),
+                        escape_html(&violation.suggestion)
+                    ));
+                }
+            }
+            body.push_str("</section>\n");
         }
+
+        let savings = Self::calculate_storage_savings(&all_violations);
+        body.push_str(&format!(
+            "<section class=\"savings\">\n<h2>Storage Optimization Potential</h2>\n<ul>\n\
+             <li>{} unused state variables</li>\n<li>{} bytes storage savings</li>\n\
+             <li>{:.4} XLM/month ledger rent savings</li>\n</ul>\n</section>",
+            savings.unused_variables,
+            savings.estimated_savings_bytes,
+            savings.monthly_ledger_rent_savings
+        ));
+
+        HTML_TEMPLATE
+            .replace("{title}", "GasGuard Scan Report")
+            .replace("{body}", &body)
     }
 
     fn categorize_violations(
@@ -122,10 +354,35 @@ impl ScanAnalyzer {
     }
 }
 
+/// Escape text for use in HTML element content and attribute values
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct RuleStatistics {
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+    pub by_rule: BTreeMap<String, usize>,
+}
+
 #[derive(Debug)]
 pub struct StorageSavings {
     pub unused_variables: usize,
-    pub estimated_savings_kb: f64,
+    /// Encoded size of the unused fields, from their declared types
+    pub estimated_savings_bytes: u64,
     pub monthly_ledger_rent_savings: f64,
 }
 
@@ -133,10 +390,325 @@ impl fmt::Display for StorageSavings {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "💰 Storage Optimization Potential:\n   • {} unused state variables\n   • {:.1} KB storage savings\n   • {:.4} XLM/month ledger rent savings",
+            "💰 Storage Optimization Potential:\n   • {} unused state variables\n   • {} bytes storage savings\n   • {:.6} XLM/month ledger rent savings",
             self.unused_variables,
-            self.estimated_savings_kb,
+            self.estimated_savings_bytes,
             self.monthly_ledger_rent_savings
         )
     }
-}
\ No newline at end of file
+}
+/// Estimated savings of one rule category across all scanned files
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProfileEntry {
+    pub category: RuleCategory,
+    pub unit: SavingUnit,
+    pub findings: usize,
+    pub total_saving: u64,
+    /// Rules contributing to the category, biggest saving first
+    pub rules: Vec<RuleSaving>,
+}
+
+/// Estimated savings of one rule within its category
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RuleSaving {
+    pub rule_name: String,
+    pub findings: usize,
+    pub total_saving: u64,
+}
+
+/// Estimated savings available in one scanned file
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ContractSaving {
+    pub source: String,
+    pub instructions: u64,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct GasProfile {
+    /// Rule categories ranked by total saving, instructions before bytes
+    pub wins: Vec<ProfileEntry>,
+    /// Files ranked by instructions saved, then bytes saved
+    pub contracts: Vec<ContractSaving>,
+}
+
+impl fmt::Display for GasProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "🏆 Biggest Wins:")?;
+        if self.wins.is_empty() {
+            return write!(f, "   • No findings with an estimated saving");
+        }
+
+        for (rank, entry) in self.wins.iter().enumerate() {
+            let unit = match entry.unit {
+                SavingUnit::Instructions => "instructions",
+                SavingUnit::Bytes => "bytes",
+            };
+            writeln!(
+                f,
+                "   {}. {} — ~{} {} ({} findings)",
+                rank + 1,
+                entry.category,
+                entry.total_saving,
+                unit,
+                entry.findings
+            )?;
+            for rule in &entry.rules {
+                writeln!(
+                    f,
+                    "      - {} — ~{} {} ({} findings)",
+                    rule.rule_name, rule.total_saving, unit, rule.findings
+                )?;
+            }
+        }
+
+        write!(f, "\n📁 By File:")?;
+        for contract in &self.contracts {
+            write!(
+                f,
+                "\n   • {} — ~{} instructions, ~{} bytes",
+                contract.source, contract.instructions, contract.bytes
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule_name: &str, severity: ViolationSeverity) -> RuleViolation {
+        RuleViolation {
+            rule_name: rule_name.to_string(),
+            description: String::new(),
+            severity,
+            line_number: 1,
+            column_number: 0,
+            variable_name: "x".to_string(),
+            suggestion: String::new(),
+            estimated_saving: None,
+        }
+    }
+
+    fn result(source: &str, violations: Vec<RuleViolation>) -> ScanResult {
+        ScanResult {
+            source: source.to_string(),
+            violations,
+            scan_time: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_summary_line_fields() {
+        let results = vec![
+            result(
+                "a.rs",
+                vec![
+                    violation("rule-a", ViolationSeverity::Error),
+                    violation("rule-b", ViolationSeverity::Warning),
+                    violation("rule-b", ViolationSeverity::High),
+                ],
+            ),
+            result(
+                "b.rs",
+                vec![
+                    violation("rule-c", ViolationSeverity::Medium),
+                    violation("rule-d", ViolationSeverity::Warning),
+                    violation("rule-e", ViolationSeverity::Info),
+                ],
+            ),
+            result("c.rs", vec![violation("rule-e", ViolationSeverity::Info)]),
+        ];
+
+        // Two more files were scanned without findings
+        // 1 error (10) + 4 warnings (12) + 2 info (2) = 24 penalty
+        assert_eq!(
+            ScanAnalyzer::summary_line(&results, 5),
+            "gasguard: files=5 errors=1 warnings=4 info=2 score=76"
+        );
+    }
+
+    #[test]
+    fn test_render_html_lists_violations_and_savings() {
+        let mut unused = violation("unused-state-variable", ViolationSeverity::Warning);
+        unused.variable_name = "stale_counter".to_string();
+        unused.estimated_saving = Some(gasguard_rules::EstimatedSaving::bytes(8));
+        let mut clone = violation("unnecessary-clone", ViolationSeverity::Medium);
+        clone.variable_name = "Vec<Address>".to_string();
+        let results = vec![
+            result("vault.rs", vec![unused, clone]),
+            result("clean.rs", vec![]),
+        ];
+
+        let html = ScanAnalyzer::render_html(&results, 4);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(!html.contains("<script"));
+        assert!(html.contains("4 files scanned, 2 violations found."));
+        assert!(html.contains("stale_counter"));
+        assert!(html.contains("Vec&lt;Address&gt;"));
+        assert!(html.contains("<span class=\"badge medium\">Medium</span>"));
+        assert!(html.contains("Storage Optimization Potential"));
+        assert!(html.contains("1 unused state variables"));
+        assert!(html.contains("8 bytes storage savings"));
+    }
+
+    #[test]
+    fn test_storage_savings_use_declared_field_types() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map};
+
+#[contracttype]
+pub struct Vault {
+    pub admin: Address,
+    pub stale_admin: Address,
+    pub stale_nonce: u64,
+    pub stale_index: Map<Address, u32>,
+}
+
+#[contractimpl]
+impl Vault {
+    pub fn admin(env: Env, vault: Vault) -> Address {
+        vault.admin
+    }
+}
+"#;
+        let result = crate::ContractScanner::new()
+            .scan_soroban_content(source, "vault.rs".to_string())
+            .unwrap();
+        let savings = ScanAnalyzer::calculate_storage_savings(&result.violations);
+
+        assert_eq!(savings.unused_variables, 3);
+        // Address (32) + u64 (8) + Map, sized conservatively (64)
+        assert_eq!(savings.estimated_savings_bytes, 104);
+        assert!(savings.to_string().contains("104 bytes storage savings"));
+    }
+
+    #[test]
+    fn test_optimization_score_floors_at_zero() {
+        let violations: Vec<_> = (0..20)
+            .map(|_| violation("rule-a", ViolationSeverity::Error))
+            .collect();
+        let statistics = ScanAnalyzer::rule_statistics(&violations);
+
+        assert_eq!(statistics.by_rule.get("rule-a"), Some(&20));
+        assert_eq!(ScanAnalyzer::optimization_score(&statistics), 0);
+    }
+
+    #[test]
+    fn test_gas_profile_ranks_quadratic_loop_above_style_finding() {
+        let quadratic = r#"
+use soroban_sdk::{contract, contractimpl, Env, Vec};
+
+#[contractimpl]
+impl Pairs {
+    pub fn score(env: Env, items: Vec<u32>) -> u32 {
+        let mut total = 0;
+        for a in items.iter() {
+            for b in items.iter() {
+                total += a * b;
+            }
+        }
+        total
+    }
+}
+"#;
+        let style_only = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Ping {
+    pub fn ping(env: Env, unused: u32) -> u32 {
+        1
+    }
+}
+"#;
+        let scanner = crate::ContractScanner::new();
+        let results = vec![
+            scanner
+                .scan_soroban_content(style_only, "style.rs".to_string())
+                .unwrap(),
+            scanner
+                .scan_soroban_content(quadratic, "quadratic.rs".to_string())
+                .unwrap(),
+        ];
+        let profile = ScanAnalyzer::gas_profile(&results, scanner.registry());
+
+        assert_eq!(profile.wins[0].category, RuleCategory::Gas);
+        assert_eq!(profile.wins[0].rules[0].rule_name, "soroban-unbounded-loop");
+        assert_eq!(profile.contracts[0].source, "quadratic.rs");
+        assert!(profile.contracts[0].instructions > profile.contracts[1].instructions);
+    }
+
+    #[test]
+    fn test_gas_profile_groups_rules_by_category() {
+        let mut registry = RuleRegistry::new();
+        registry.register("unbounded-loop", "", RuleCategory::Gas);
+        registry.register("unnecessary-clone", "", RuleCategory::Gas);
+        registry.register("unchecked-arithmetic", "", RuleCategory::Correctness);
+        let saving = |rule_name: &str, amount: u64| {
+            let mut finding = violation(rule_name, ViolationSeverity::Warning);
+            finding.estimated_saving = Some(gasguard_rules::EstimatedSaving::instructions(amount));
+            finding
+        };
+        let results = vec![
+            result(
+                "a.rs",
+                vec![
+                    saving("unbounded-loop", 500),
+                    saving("unchecked-arithmetic", 300),
+                ],
+            ),
+            result(
+                "b.rs",
+                vec![
+                    saving("unnecessary-clone", 100),
+                    saving("unnecessary-clone", 50),
+                ],
+            ),
+        ];
+
+        let profile = ScanAnalyzer::gas_profile(&results, &registry);
+
+        assert_eq!(profile.wins.len(), 2);
+        assert_eq!(profile.wins[0].category, RuleCategory::Gas);
+        assert_eq!(profile.wins[0].total_saving, 650);
+        assert_eq!(profile.wins[0].findings, 3);
+        let rules: Vec<_> = profile.wins[0]
+            .rules
+            .iter()
+            .map(|rule| rule.rule_name.as_str())
+            .collect();
+        assert_eq!(rules, ["unbounded-loop", "unnecessary-clone"]);
+        assert_eq!(profile.wins[0].rules[1].findings, 2);
+        assert_eq!(profile.wins[1].category, RuleCategory::Correctness);
+        assert_eq!(profile.wins[1].rules[0].total_saving, 300);
+        assert!(profile
+            .to_string()
+            .contains("1. gas — ~650 instructions (3 findings)"));
+    }
+
+    #[test]
+    fn test_fixable_count_matches_findings_of_auto_fixable_rules() {
+        let mut registry = RuleRegistry::new();
+        registry.register("unused-imports", "", RuleCategory::Style);
+        registry.register("unbounded-loop", "", RuleCategory::Gas);
+        registry.set_auto_fixable("unused-imports", true);
+        let violations = vec![
+            violation("unused-imports", ViolationSeverity::Info),
+            violation("unused-imports", ViolationSeverity::Info),
+            violation("unbounded-loop", ViolationSeverity::Warning),
+            violation("rule-skipped", ViolationSeverity::Info),
+        ];
+
+        assert_eq!(ScanAnalyzer::fixable_count(&violations, &registry), 2);
+        assert!(ScanAnalyzer::generate_summary(&violations, &registry)
+            .ends_with("(2 auto-fixable — run `gasguard fix`)"));
+
+        registry.set_auto_fixable("unused-imports", false);
+        assert_eq!(ScanAnalyzer::fixable_count(&violations, &registry), 0);
+        assert!(!ScanAnalyzer::generate_summary(&violations, &registry).contains("auto-fixable"));
+    }
+}
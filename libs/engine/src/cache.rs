@@ -0,0 +1,60 @@
+use crate::ScanResult;
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Cache directory used when none is configured, relative to the scanned directory
+pub const DEFAULT_CACHE_DIR: &str = ".gasguard/cache";
+
+/// Scan results stored on disk, one JSON file per distinct file content and rule set
+#[derive(Debug, Clone)]
+pub struct ScanCache {
+    dir: PathBuf,
+}
+
+impl ScanCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Key of a file's entry: its content plus the engine version, profile and enabled rules
+    ///
+    /// Any change to the rule set yields a new key, so stale findings are never
+    /// served after upgrading or reconfiguring the scanner.
+    pub fn key(content: &str, profile: &str, rules: &BTreeSet<String>) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(env!("CARGO_PKG_VERSION"));
+        hasher.update([0]);
+        hasher.update(profile);
+        for rule in rules {
+            hasher.update([0]);
+            hasher.update(rule);
+        }
+        hasher.update([0]);
+        hasher.update(content);
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// The stored result for `key`; unreadable or corrupt entries count as misses
+    pub fn load(&self, key: &str) -> Option<ScanResult> {
+        let json = std::fs::read_to_string(self.entry_path(key)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn store(&self, key: &str, result: &ScanResult) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create cache directory: {:?}", self.dir))?;
+        let path = self.entry_path(key);
+        std::fs::write(&path, serde_json::to_string(result)?)
+            .with_context(|| format!("Failed to write cache entry: {:?}", path))
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", key))
+    }
+}
@@ -0,0 +1,191 @@
+use crate::ScanResult;
+use gasguard_rules::RuleViolation;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::Path;
+
+/// A violation keyed by an id that survives unrelated edits to the file
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ComparedViolation {
+    pub id: String,
+    pub source: String,
+    pub violation: RuleViolation,
+}
+
+/// Difference between two scans of the same project
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ScanComparison {
+    /// In the new scan only
+    pub added: Vec<ComparedViolation>,
+    /// In the old scan only
+    pub removed: Vec<ComparedViolation>,
+    /// In both scans; entries are taken from the new scan
+    pub unchanged: Vec<ComparedViolation>,
+}
+
+impl ScanComparison {
+    /// Compare scans rooted at `old_root` and `new_root`
+    ///
+    /// Sources are matched by their path relative to the scan root, so two
+    /// checkouts of the same project compare file by file.
+    pub fn between(
+        old: &[ScanResult],
+        old_root: &Path,
+        new: &[ScanResult],
+        new_root: &Path,
+    ) -> Self {
+        let old = Self::keyed(old, old_root);
+        let mut new = Self::keyed(new, new_root);
+        let mut comparison = Self::default();
+
+        for (id, entry) in old {
+            match new.remove(&id) {
+                Some(current) => comparison.unchanged.push(current),
+                None => comparison.removed.push(entry),
+            }
+        }
+        comparison.added = new.into_values().collect();
+
+        comparison
+    }
+
+    /// One-line summary, e.g. `+3 new, -5 fixed, 12 unchanged`
+    pub fn summary(&self) -> String {
+        format!(
+            "+{} new, -{} fixed, {} unchanged",
+            self.added.len(),
+            self.removed.len(),
+            self.unchanged.len()
+        )
+    }
+
+    /// Stable id of every violation, ordered by id
+    fn keyed(results: &[ScanResult], root: &Path) -> BTreeMap<String, ComparedViolation> {
+        let mut keyed = BTreeMap::new();
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+
+        for result in results {
+            let source = Self::relative_source(&result.source, root);
+            for violation in &result.violations {
+                // Line numbers are left out so findings keep their id when code above them moves
+                let base = format!(
+                    "{}:{}:{}",
+                    violation.rule_name, source, violation.variable_name
+                );
+                let count = occurrences.entry(base.clone()).or_insert(0);
+                *count += 1;
+                let id = if *count == 1 {
+                    base
+                } else {
+                    format!("{}#{}", base, count)
+                };
+
+                keyed.insert(
+                    id.clone(),
+                    ComparedViolation {
+                        id,
+                        source: result.source.clone(),
+                        violation: violation.clone(),
+                    },
+                );
+            }
+        }
+
+        keyed
+    }
+
+    fn relative_source(source: &str, root: &Path) -> String {
+        Path::new(source)
+            .strip_prefix(root)
+            .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_else(|_| source.to_string())
+    }
+}
+
+impl fmt::Display for ScanComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "🔀 {}", self.summary())?;
+
+        for (marker, entries) in [("+", &self.added), ("-", &self.removed)] {
+            for entry in entries {
+                write!(
+                    f,
+                    "\n   {} [{}] {}: {}",
+                    marker, entry.violation.rule_name, entry.source, entry.violation.description
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ContractScanner;
+
+    const BEFORE: &str = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env) {
+        todo!()
+    }
+
+    pub fn ping(env: Env, unused: u32) -> u32 {
+        env.ledger().sequence()
+    }
+}
+"#;
+
+    const AFTER: &str = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env) {}
+
+    pub fn ping(env: Env, unused: u32) -> u32 {
+        env.ledger().sequence()
+    }
+
+    pub fn pong(env: Env, ignored: u32) -> u32 {
+        env.ledger().sequence() + 1
+    }
+}
+"#;
+
+    #[test]
+    fn test_compare_reports_added_and_removed_violations() {
+        let root = std::env::temp_dir().join(format!("gasguard-compare-{}", std::process::id()));
+        let (old_dir, new_dir) = (root.join("old"), root.join("new"));
+        std::fs::create_dir_all(&old_dir).unwrap();
+        std::fs::create_dir_all(&new_dir).unwrap();
+        std::fs::write(old_dir.join("vault.rs"), BEFORE).unwrap();
+        std::fs::write(new_dir.join("vault.rs"), AFTER).unwrap();
+
+        let scanner = ContractScanner::new();
+        let old = scanner.scan_directory(&old_dir).unwrap();
+        let new = scanner.scan_directory(&new_dir).unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        let comparison = ScanComparison::between(&old, &old_dir, &new, &new_dir);
+        let ids = |entries: &[ComparedViolation]| -> Vec<String> {
+            entries.iter().map(|e| e.id.clone()).collect()
+        };
+
+        assert_eq!(
+            ids(&comparison.removed),
+            vec!["soroban-unfinished-code:vault.rs:withdraw".to_string()]
+        );
+        assert_eq!(
+            ids(&comparison.added),
+            vec!["soroban-unused-parameter:vault.rs:ignored".to_string()]
+        );
+        assert!(ids(&comparison.unchanged)
+            .contains(&"soroban-unused-parameter:vault.rs:unused".to_string()));
+        assert!(comparison.summary().starts_with("+1 new, -1 fixed"));
+    }
+}
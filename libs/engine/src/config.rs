@@ -0,0 +1,193 @@
+use crate::{RuleStatistics, ScanAnalyzer, ScanResult};
+use anyhow::{Context, Result};
+use glob::Pattern;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// File the CLI reads when no `--config` is given
+pub const DEFAULT_CONFIG_FILE: &str = "gasguard.toml";
+
+/// Reads one severity class out of `RuleStatistics`
+type ClassCount = fn(&RuleStatistics) -> usize;
+
+/// Project settings read from `gasguard.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GasGuardConfig {
+    /// Maximum findings allowed per severity, keyed by a glob over source paths
+    #[serde(default)]
+    pub budgets: BTreeMap<String, SeverityBudget>,
+}
+
+/// Upper bounds on the findings of each severity class; absent classes are unbounded
+///
+/// Classes follow `RuleStatistics`: `High` and `Medium` findings count as warnings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeverityBudget {
+    pub errors: Option<usize>,
+    pub warnings: Option<usize>,
+    pub info: Option<usize>,
+}
+
+/// A budget whose glob matched more findings of one class than it allows
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct BudgetExceeded {
+    pub pattern: String,
+    pub class: &'static str,
+    pub allowed: usize,
+    pub found: usize,
+    /// Matched sources that contributed findings of this class
+    pub sources: Vec<String>,
+}
+
+impl fmt::Display for BudgetExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Budget '{}' exceeded: {} {} found, {} allowed ({})",
+            self.pattern,
+            self.found,
+            self.class,
+            self.allowed,
+            self.sources.join(", ")
+        )
+    }
+}
+
+impl GasGuardConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("Invalid config {}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let config: Self = toml::from_str(content)?;
+        for pattern in config.budgets.keys() {
+            Pattern::new(pattern)
+                .with_context(|| format!("Invalid budget pattern '{}'", pattern))?;
+        }
+        Ok(config)
+    }
+
+    /// Check every budget against the findings of the sources its glob matches
+    ///
+    /// Sources are matched by their path relative to `root`, normally the
+    /// directory holding the config file. A glob matching several files is
+    /// a budget for all of them together.
+    pub fn evaluate_budgets(&self, results: &[ScanResult], root: &Path) -> Vec<BudgetExceeded> {
+        let mut exceeded = Vec::new();
+
+        for (pattern, budget) in &self.budgets {
+            let Ok(glob) = Pattern::new(pattern) else {
+                continue;
+            };
+            let matched: Vec<&ScanResult> = results
+                .iter()
+                .filter(|r| glob.matches(&Self::relative_source(&r.source, root)))
+                .collect();
+            let violations: Vec<_> = matched
+                .iter()
+                .flat_map(|r| r.violations.iter().cloned())
+                .collect();
+            let statistics = ScanAnalyzer::rule_statistics(&violations);
+
+            let classes: [(&'static str, Option<usize>, ClassCount); 3] = [
+                ("errors", budget.errors, |s| s.errors),
+                ("warnings", budget.warnings, |s| s.warnings),
+                ("info", budget.info, |s| s.info),
+            ];
+            for (class, allowed, count) in classes {
+                let Some(allowed) = allowed else {
+                    continue;
+                };
+                let found = count(&statistics);
+                if found <= allowed {
+                    continue;
+                }
+                let sources = matched
+                    .iter()
+                    .filter(|r| count(&ScanAnalyzer::rule_statistics(&r.violations)) > 0)
+                    .map(|r| r.source.clone())
+                    .collect();
+                exceeded.push(BudgetExceeded {
+                    pattern: pattern.clone(),
+                    class,
+                    allowed,
+                    found,
+                    sources,
+                });
+            }
+        }
+
+        exceeded
+    }
+
+    fn relative_source(source: &str, root: &Path) -> String {
+        let path = Path::new(source);
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        let relative = relative.strip_prefix(".").unwrap_or(relative);
+        relative.to_string_lossy().replace('\\', "/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gasguard_rules::{RuleViolation, ViolationSeverity};
+
+    fn result(source: &str, severities: &[ViolationSeverity]) -> ScanResult {
+        ScanResult {
+            source: source.to_string(),
+            violations: severities
+                .iter()
+                .map(|severity| RuleViolation {
+                    rule_name: "test-rule".to_string(),
+                    description: String::new(),
+                    severity: severity.clone(),
+                    line_number: 1,
+                    column_number: 0,
+                    variable_name: String::new(),
+                    suggestion: String::new(),
+                    estimated_saving: None,
+                })
+                .collect(),
+            scan_time: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_budget_exceeded_names_pattern_and_sources() {
+        let config = GasGuardConfig::parse(
+            r#"
+[budgets]
+"contracts/token/**" = { warnings = 1 }
+"contracts/vault.rs" = { warnings = 5, errors = 0 }
+"#,
+        )
+        .unwrap();
+        let results = vec![
+            result("./contracts/token/lib.rs", &[ViolationSeverity::Warning]),
+            result("./contracts/token/admin.rs", &[ViolationSeverity::Medium]),
+            result("./contracts/vault.rs", &[ViolationSeverity::Warning]),
+        ];
+
+        let exceeded = config.evaluate_budgets(&results, Path::new(""));
+
+        assert_eq!(exceeded.len(), 1);
+        assert_eq!(exceeded[0].pattern, "contracts/token/**");
+        assert_eq!((exceeded[0].allowed, exceeded[0].found), (1, 2));
+        assert_eq!(exceeded[0].sources.len(), 2);
+        assert!(exceeded[0]
+            .to_string()
+            .starts_with("Budget 'contracts/token/**' exceeded: 2 warnings found, 1 allowed"));
+    }
+
+    #[test]
+    fn test_invalid_budget_pattern_is_rejected() {
+        assert!(GasGuardConfig::parse("[budgets]\n\"src/[\" = { info = 1 }\n").is_err());
+    }
+}
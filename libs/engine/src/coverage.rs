@@ -0,0 +1,57 @@
+use gasguard_rules::RuleCoverage;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// What each rule inspected across a scan
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct CoverageReport {
+    /// Number of files the rules were run against
+    pub files: usize,
+    /// Coverage keyed by rule id, including rules that inspected nothing
+    pub rules: BTreeMap<String, RuleCoverage>,
+}
+
+impl CoverageReport {
+    /// Empty report listing every rule in `rule_ids`
+    pub fn new<I, S>(rule_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            files: 0,
+            rules: rule_ids
+                .into_iter()
+                .map(|id| (id.into(), RuleCoverage::default()))
+                .collect(),
+        }
+    }
+
+    /// Add the per-rule coverage of one file
+    pub fn add_file(&mut self, coverage: Vec<(String, RuleCoverage)>) {
+        self.files += 1;
+        for (rule_id, counts) in coverage {
+            *self.rules.entry(rule_id).or_default() += counts;
+        }
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "📐 Rule Coverage ({} files):", self.files)?;
+
+        for (rule_id, coverage) in &self.rules {
+            let percentage = coverage
+                .percentage()
+                .map(|p| format!("{:.1}%", p))
+                .unwrap_or_else(|| "n/a".to_string());
+            write!(
+                f,
+                "\n   • {} — {} inspected, {} not inspected ({})",
+                rule_id, coverage.inspected, coverage.uninspected, percentage
+            )?;
+        }
+
+        Ok(())
+    }
+}
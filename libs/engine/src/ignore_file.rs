@@ -0,0 +1,57 @@
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Name of the file listing paths a directory scan skips, in gitignore syntax
+pub const IGNORE_FILE_NAME: &str = ".gasguardignore";
+
+/// `.gasguardignore` files under a scan root, each loaded the first time its directory is seen
+///
+/// Like git, a file applies to its own directory and everything below it,
+/// and patterns in a deeper file take precedence over those above it.
+pub(crate) struct IgnoreFiles {
+    root: PathBuf,
+    matchers: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl IgnoreFiles {
+    pub(crate) fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            matchers: HashMap::new(),
+        }
+    }
+
+    /// Whether an ignore file in an ancestor directory of `path`, up to the root, excludes it
+    pub(crate) fn is_ignored(&mut self, path: &Path, is_dir: bool) -> bool {
+        let root = self.root.clone();
+        for dir in path.ancestors().skip(1).take_while(|dir| dir.starts_with(&root)) {
+            let Some(matcher) = self.matcher(dir) else {
+                continue;
+            };
+            match matcher.matched(path, is_dir) {
+                Match::Ignore(_) => return true,
+                Match::Whitelist(_) => return false,
+                Match::None => {}
+            }
+        }
+        false
+    }
+
+    fn matcher(&mut self, dir: &Path) -> Option<&Gitignore> {
+        self.matchers
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| {
+                let file = dir.join(IGNORE_FILE_NAME);
+                if !file.is_file() {
+                    return None;
+                }
+                let mut builder = GitignoreBuilder::new(dir);
+                // Malformed lines are dropped; the valid patterns around them still apply
+                let _ = builder.add(file);
+                builder.build().ok()
+            })
+            .as_ref()
+    }
+}
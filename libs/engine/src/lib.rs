@@ -1,5 +1,19 @@
 pub mod analyzer;
+pub mod cache;
+pub mod compare;
+pub mod config;
+pub mod coverage;
+pub mod ignore_file;
+pub mod registry;
+pub mod sarif;
 pub mod scanner;
 
 pub use analyzer::*;
+pub use cache::*;
+pub use compare::*;
+pub use config::*;
+pub use coverage::*;
+pub use ignore_file::*;
+pub use registry::*;
+pub use sarif::*;
 pub use scanner::*;
@@ -0,0 +1,228 @@
+use gasguard_rules::{RuleCategory, RuleViolation, ViolationSeverity};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// Named preset selecting which rules run and how severe their findings are
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Profile {
+    /// Every rule, with advisory `Info` findings raised to `Warning`
+    Strict,
+    /// Every rule except style checks, at their own severities
+    #[default]
+    Recommended,
+    /// Only gas rules
+    Gas,
+    /// Security and correctness rules, with security findings raised to at least `High`
+    Security,
+}
+
+impl Profile {
+    /// Whether rules of `category` run under this profile
+    pub fn enables(&self, category: RuleCategory) -> bool {
+        match self {
+            Profile::Strict => true,
+            Profile::Recommended => category != RuleCategory::Style,
+            Profile::Gas => category == RuleCategory::Gas,
+            Profile::Security => {
+                matches!(category, RuleCategory::Security | RuleCategory::Correctness)
+            }
+        }
+    }
+
+    /// Severity a finding of `category` is reported at, if the profile changes it
+    fn severity_override(
+        &self,
+        category: RuleCategory,
+        severity: &ViolationSeverity,
+    ) -> Option<ViolationSeverity> {
+        match self {
+            Profile::Strict if *severity == ViolationSeverity::Info => {
+                Some(ViolationSeverity::Warning)
+            }
+            Profile::Security
+                if category == RuleCategory::Security
+                    && !severity.is_at_least(&ViolationSeverity::High) =>
+            {
+                Some(ViolationSeverity::High)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "strict" => Ok(Profile::Strict),
+            "recommended" => Ok(Profile::Recommended),
+            "gas" => Ok(Profile::Gas),
+            "security" => Ok(Profile::Security),
+            other => Err(format!(
+                "Unknown profile '{}' (expected strict, recommended, gas or security)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Profile::Strict => "strict",
+            Profile::Recommended => "recommended",
+            Profile::Gas => "gas",
+            Profile::Security => "security",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A rule known to the scanner and its current state
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RegisteredRule {
+    pub id: String,
+    pub description: String,
+    pub category: RuleCategory,
+    pub enabled: bool,
+    /// Whether `gasguard fix` can apply the rule's suggestions
+    pub auto_fixable: bool,
+}
+
+/// Every rule the scanner can report, keyed by the rule name its findings carry
+#[derive(Debug, Clone, Default)]
+pub struct RuleRegistry {
+    rules: BTreeMap<String, RegisteredRule>,
+    profile: Profile,
+}
+
+impl RuleRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a rule; it starts enabled under the current profile's rules
+    pub fn register(
+        &mut self,
+        id: impl Into<String>,
+        description: impl Into<String>,
+        category: RuleCategory,
+    ) {
+        let id = id.into();
+        let enabled = self.profile.enables(category);
+        self.rules.insert(
+            id.clone(),
+            RegisteredRule {
+                id,
+                description: description.into(),
+                category,
+                enabled,
+                auto_fixable: false,
+            },
+        );
+    }
+
+    /// Enable exactly the rules `profile` selects and adopt its severities
+    pub fn apply_profile(&mut self, profile: Profile) {
+        self.profile = profile;
+        for rule in self.rules.values_mut() {
+            rule.enabled = profile.enables(rule.category);
+        }
+    }
+
+    /// Enable or disable a single rule; unregistered ids are ignored
+    pub fn set_enabled(&mut self, id: &str, enabled: bool) {
+        if let Some(rule) = self.rules.get_mut(id) {
+            rule.enabled = enabled;
+        }
+    }
+
+    /// Mark whether a rule's findings can be fixed automatically; unregistered ids are ignored
+    pub fn set_auto_fixable(&mut self, id: &str, auto_fixable: bool) {
+        if let Some(rule) = self.rules.get_mut(id) {
+            rule.auto_fixable = auto_fixable;
+        }
+    }
+
+    /// Ids of every rule that currently runs
+    pub fn enabled_rules(&self) -> BTreeSet<String> {
+        self.rules
+            .values()
+            .filter(|rule| rule.enabled)
+            .map(|rule| rule.id.clone())
+            .collect()
+    }
+
+    pub fn profile(&self) -> Profile {
+        self.profile
+    }
+
+    pub fn get(&self, id: &str) -> Option<&RegisteredRule> {
+        self.rules.get(id)
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &RegisteredRule> {
+        self.rules.values()
+    }
+
+    /// Whether findings of `id` are reported; notes from unregistered names always are
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.rules.get(id).is_none_or(|rule| rule.enabled)
+    }
+
+    /// Drop findings of disabled rules and apply the profile's severities to the rest
+    pub fn filter(&self, violations: Vec<RuleViolation>) -> Vec<RuleViolation> {
+        violations
+            .into_iter()
+            .filter(|v| self.is_enabled(&v.rule_name))
+            .map(|mut v| {
+                if let Some(rule) = self.rules.get(&v.rule_name) {
+                    if let Some(severity) =
+                        self.profile.severity_override(rule.category, &v.severity)
+                    {
+                        v.severity = severity;
+                    }
+                }
+                v
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule_name: &str, severity: ViolationSeverity) -> RuleViolation {
+        RuleViolation {
+            rule_name: rule_name.to_string(),
+            description: String::new(),
+            severity,
+            line_number: 1,
+            column_number: 0,
+            variable_name: String::new(),
+            suggestion: String::new(),
+            estimated_saving: None,
+        }
+    }
+
+    #[test]
+    fn test_security_profile_drops_gas_rules_and_raises_security_findings() {
+        let mut registry = RuleRegistry::new();
+        registry.register("storage", "", RuleCategory::Gas);
+        registry.register("auth", "", RuleCategory::Security);
+        registry.apply_profile("security".parse().unwrap());
+
+        let kept = registry.filter(vec![
+            violation("storage", ViolationSeverity::Medium),
+            violation("auth", ViolationSeverity::Info),
+            violation(gasguard_rules::RULE_SKIPPED, ViolationSeverity::Info),
+        ]);
+
+        let names: Vec<_> = kept.iter().map(|v| v.rule_name.as_str()).collect();
+        assert_eq!(names, ["auth", gasguard_rules::RULE_SKIPPED]);
+        assert_eq!(kept[0].severity, ViolationSeverity::High);
+        assert_eq!(kept[1].severity, ViolationSeverity::Info);
+    }
+}
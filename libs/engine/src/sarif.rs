@@ -0,0 +1,245 @@
+use crate::{RuleRegistry, ScanResult};
+use gasguard_rules::{RuleViolation, ViolationSeverity};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// SARIF 2.1.0 log of one scan, the format code-scanning UIs such as GitHub's consume
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    pub version: &'static str,
+    pub runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifRun {
+    pub tool: SarifTool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifTool {
+    pub driver: SarifDriver,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub rules: Vec<SarifRule>,
+}
+
+/// Metadata for one rule, shown next to its results
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    pub id: String,
+    pub name: String,
+    pub short_description: SarifMessage,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    /// Index of the rule in `tool.driver.rules`
+    pub rule_index: usize,
+    pub level: &'static str,
+    pub message: SarifMessage,
+    pub locations: Vec<SarifLocation>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifMessage {
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    pub physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    pub artifact_location: SarifArtifactLocation,
+    /// Omitted when the rule could not place the finding on a line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifArtifactLocation {
+    pub uri: String,
+}
+
+/// One-based position of a finding; SARIF has no line or column zero
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    pub start_line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+}
+
+impl SarifLog {
+    /// Build a log from scan results, describing every enabled rule and any rule the results cite
+    pub fn from_results(results: &[ScanResult], registry: &RuleRegistry) -> Self {
+        let mut descriptions: BTreeMap<String, String> = registry
+            .rules()
+            .filter(|rule| rule.enabled)
+            .map(|rule| (rule.id.clone(), rule.description.clone()))
+            .collect();
+        for violation in results.iter().flat_map(|r| r.violations.iter()) {
+            descriptions
+                .entry(violation.rule_name.clone())
+                .or_insert_with(|| {
+                    registry
+                        .get(&violation.rule_name)
+                        .map(|rule| rule.description.clone())
+                        .unwrap_or_default()
+                });
+        }
+
+        let rules: Vec<SarifRule> = descriptions
+            .into_iter()
+            .map(|(id, description)| SarifRule {
+                name: id.clone(),
+                id,
+                short_description: SarifMessage { text: description },
+            })
+            .collect();
+        let rule_index: BTreeMap<&str, usize> = rules
+            .iter()
+            .enumerate()
+            .map(|(idx, rule)| (rule.id.as_str(), idx))
+            .collect();
+
+        let results = results
+            .iter()
+            .flat_map(|result| {
+                result
+                    .violations
+                    .iter()
+                    .map(|violation| Self::result(&result.source, violation, &rule_index))
+            })
+            .collect();
+
+        Self {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "GasGuard",
+                        version: env!("CARGO_PKG_VERSION"),
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    fn result(
+        source: &str,
+        violation: &RuleViolation,
+        rule_index: &BTreeMap<&str, usize>,
+    ) -> SarifResult {
+        let region = (violation.line_number > 0).then(|| SarifRegion {
+            start_line: violation.line_number,
+            start_column: (violation.column_number > 0).then_some(violation.column_number),
+        });
+
+        SarifResult {
+            rule_id: violation.rule_name.clone(),
+            rule_index: rule_index[violation.rule_name.as_str()],
+            level: Self::level(&violation.severity),
+            message: SarifMessage {
+                text: violation.description.clone(),
+            },
+            locations: vec![SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation {
+                        uri: source.replace('\\', "/"),
+                    },
+                    region,
+                },
+            }],
+        }
+    }
+
+    /// SARIF has three result levels, so the five severities are folded onto them
+    fn level(severity: &ViolationSeverity) -> &'static str {
+        match severity {
+            ViolationSeverity::Error | ViolationSeverity::High => "error",
+            ViolationSeverity::Medium | ViolationSeverity::Warning => "warning",
+            ViolationSeverity::Info => "note",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gasguard_rules::RuleCategory;
+
+    #[test]
+    fn test_results_reference_their_rule_metadata() {
+        let mut registry = RuleRegistry::new();
+        registry.register("storage", "Repeated storage reads", RuleCategory::Gas);
+        registry.register("auth", "Missing authorization", RuleCategory::Security);
+        let violation = |rule_name: &str, severity, line_number| RuleViolation {
+            rule_name: rule_name.to_string(),
+            description: format!("{} finding", rule_name),
+            severity,
+            line_number,
+            column_number: 0,
+            variable_name: String::new(),
+            suggestion: String::new(),
+            estimated_saving: None,
+        };
+        let results = vec![ScanResult {
+            source: "src\\lib.rs".to_string(),
+            violations: vec![
+                violation("storage", ViolationSeverity::Medium, 12),
+                violation("auth", ViolationSeverity::High, 0),
+            ],
+            scan_time: chrono::Utc::now(),
+        }];
+
+        let log = SarifLog::from_results(&results, &registry);
+        let json: serde_json::Value = serde_json::from_str(&log.to_json().unwrap()).unwrap();
+        let run = &json["runs"][0];
+
+        assert_eq!(json["version"], "2.1.0");
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "auth");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][1]["shortDescription"]["text"],
+            "Repeated storage reads"
+        );
+        let storage = &run["results"][0];
+        assert_eq!(storage["ruleId"], "storage");
+        assert_eq!(storage["ruleIndex"], 1);
+        assert_eq!(storage["level"], "warning");
+        let location = &storage["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/lib.rs");
+        assert_eq!(location["region"]["startLine"], 12);
+        assert!(location["region"].get("startColumn").is_none());
+        // Line zero means the rule could not place the finding
+        assert!(run["results"][1]["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+        assert_eq!(run["results"][1]["level"], "error");
+    }
+}
@@ -1,13 +1,23 @@
 use anyhow::{Context, Result};
-use gasguard_rules::{RuleEngine, UnusedStateVariablesRule, VyperRuleEngine, SorobanRuleEngine};
-use std::path::Path;
+use gasguard_rules::{
+    ConstantExpressionRule, GlobReexportRule, InfiniteLoopRule, NarrowingCastRule,
+    RedundantTryRule, RuleCategory, RuleEngine, SolidityRuleEngine, SorobanAnalyzer, SorobanRuleEngine,
+    UnusedImportsRule, UnusedStateVariablesRule, ViolationSeverity, VyperRuleEngine,
+};
+use crate::ignore_file::IgnoreFiles;
+use crate::{CoverageReport, Profile, RuleRegistry, ScanAnalyzer, ScanCache, DEFAULT_CACHE_DIR};
+use rayon::prelude::*;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 
 /// Supported languages for scanning
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Language {
     Rust,
     Vyper,
     Soroban, // Added Soroban support
+    Solidity,
 }
 
 impl Language {
@@ -16,6 +26,7 @@ impl Language {
         match ext.to_lowercase().as_str() {
             "rs" => Some(Language::Rust),
             "vy" => Some(Language::Vyper),
+            "sol" => Some(Language::Solidity),
             _ => None,
         }
     }
@@ -30,6 +41,11 @@ impl Language {
             return Some(Language::Soroban);
         }
         
+        // Solidity also declares `interface`s, so check it before Vyper
+        if content.contains("pragma solidity") {
+            return Some(Language::Solidity);
+        }
+
         // Check for Vyper patterns
         if content.contains("# @version") || content.contains("interface ") {
             return Some(Language::Vyper);
@@ -44,23 +60,230 @@ impl Language {
     }
 }
 
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Language::Rust => "rust",
+            Language::Vyper => "vyper",
+            Language::Soroban => "soroban",
+            Language::Solidity => "solidity",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A rule any engine of the scanner can run, for documentation and tooling
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RuleInfo {
+    pub id: String,
+    /// Human-readable name; engines without one repeat the id
+    pub name: String,
+    pub description: String,
+    pub severity: ViolationSeverity,
+    pub category: RuleCategory,
+    pub language: Language,
+    /// Whether the rule runs with the scanner's current profile and selection
+    pub enabled: bool,
+}
+
 pub struct ContractScanner {
     rule_engine: RuleEngine,
     vyper_rule_engine: VyperRuleEngine,
+    solidity_rule_engine: SolidityRuleEngine,
     soroban_rule_engine: SorobanRuleEngine, // Added Soroban rule engine
+    registry: RuleRegistry,
+    cache: Option<ScanCache>,
 }
 
 impl ContractScanner {
     pub fn new() -> Self {
-        let rule_engine = RuleEngine::new().add_rule(Box::new(UnusedStateVariablesRule));
+        Self::with_profile(Profile::default())
+    }
+
+    /// Scanner running only the rules `profile` selects
+    pub fn with_profile(profile: Profile) -> Self {
+        let rule_engine = RuleEngine::new()
+            .add_rule(Box::new(UnusedStateVariablesRule))
+            .add_rule(Box::new(GlobReexportRule))
+            .add_rule(Box::new(UnusedImportsRule))
+            .add_rule(Box::new(ConstantExpressionRule))
+            .add_rule(Box::new(NarrowingCastRule))
+            .add_rule(Box::new(InfiniteLoopRule))
+            .add_rule(Box::new(RedundantTryRule));
         let vyper_rule_engine = VyperRuleEngine::with_default_rules();
+        let solidity_rule_engine = SolidityRuleEngine::with_default_rules();
         let soroban_rule_engine = SorobanRuleEngine::with_default_rules(); // Initialize Soroban engine
 
-        Self {
+        let mut registry = RuleRegistry::new();
+        for rule in rule_engine.rules() {
+            registry.register(rule.name(), rule.description(), rule.category());
+            registry.set_auto_fixable(rule.name(), rule.auto_fixable());
+        }
+        for rule in vyper_rule_engine.rules() {
+            registry.register(rule.name(), rule.description(), rule.category());
+            registry.set_auto_fixable(rule.name(), rule.auto_fixable());
+        }
+        for rule in solidity_rule_engine.rules() {
+            registry.register(rule.name(), rule.description(), rule.category());
+            registry.set_auto_fixable(rule.name(), rule.auto_fixable());
+        }
+        for rule in soroban_rule_engine.get_rules() {
+            registry.register(rule.id(), rule.description(), rule.category());
+            registry.set_auto_fixable(rule.id(), rule.auto_fixable());
+        }
+        for (name, category, description, _) in SorobanAnalyzer::CHECKS {
+            registry.register(name, description, category);
+        }
+        registry.apply_profile(profile);
+
+        let mut scanner = Self {
             rule_engine,
             vyper_rule_engine,
+            solidity_rule_engine,
             soroban_rule_engine,
+            registry: RuleRegistry::new(),
+            cache: None,
+        };
+        // Disabled rules are skipped outright; analyzer checks are filtered afterwards
+        for rule in registry.rules() {
+            scanner.set_engine_rule_enabled(&rule.id, rule.enabled);
+        }
+        scanner.registry = registry;
+        scanner
+    }
+
+    /// Reuse results stored under `path` for files whose content has not changed
+    ///
+    /// Directory scans consult the cache before analyzing a file and store
+    /// what they analyze.
+    pub fn with_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache = Some(ScanCache::new(path));
+        self
+    }
+
+    /// Rules known to the scanner and whether the active profile runs them
+    pub fn registry(&self) -> &RuleRegistry {
+        &self.registry
+    }
+
+    /// Every rule of every engine, ordered by id
+    pub fn list_rules(&self) -> Vec<RuleInfo> {
+        let info = |id: &str, name: &str, description: &str, severity, category, language| RuleInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            severity,
+            category,
+            language,
+            enabled: self.registry.is_enabled(id),
+        };
+
+        let mut rules = Vec::new();
+        for rule in self.rule_engine.rules() {
+            let (id, description) = (rule.name(), rule.description());
+            rules.push(info(id, id, description, rule.severity(), rule.category(), Language::Rust));
+        }
+        for rule in self.vyper_rule_engine.rules() {
+            let (id, description) = (rule.name(), rule.description());
+            rules.push(info(id, id, description, rule.severity(), rule.category(), Language::Vyper));
+        }
+        for rule in self.solidity_rule_engine.rules() {
+            let (id, description) = (rule.name(), rule.description());
+            rules.push(info(id, id, description, rule.severity(), rule.category(), Language::Solidity));
         }
+        for rule in self.soroban_rule_engine.get_rules() {
+            rules.push(info(
+                rule.id(),
+                rule.name(),
+                rule.description(),
+                rule.severity(),
+                rule.category(),
+                Language::Soroban,
+            ));
+        }
+        for (id, category, description, severity) in SorobanAnalyzer::CHECKS {
+            rules.push(info(id, id, description, severity, category, Language::Soroban));
+        }
+
+        rules.sort_by(|a, b| a.id.cmp(&b.id));
+        rules
+    }
+
+    /// Enable or disable a single rule on top of the active profile
+    pub fn set_rule_enabled(&mut self, id: &str, enabled: bool) {
+        self.registry.set_enabled(id, enabled);
+        self.set_engine_rule_enabled(id, enabled);
+    }
+
+    /// Run exactly the rules in `ids`, whatever the profile selects
+    pub fn enable_only<S: AsRef<str>>(&mut self, ids: &[S]) {
+        let all: Vec<String> = self.registry.rules().map(|rule| rule.id.clone()).collect();
+        for id in all {
+            let enabled = ids.iter().any(|wanted| wanted.as_ref() == id);
+            self.set_rule_enabled(&id, enabled);
+        }
+    }
+
+    /// Pass a rule's state to whichever engine runs it; ids are unique across languages
+    fn set_engine_rule_enabled(&mut self, id: &str, enabled: bool) {
+        self.rule_engine.set_rule_enabled(id, enabled);
+        self.vyper_rule_engine.set_rule_enabled(id, enabled);
+        self.solidity_rule_engine.set_rule_enabled(id, enabled);
+        self.soroban_rule_engine.set_rule_enabled(id, enabled);
+    }
+
+    /// Pair a result of this scanner with the rules that produced it
+    pub fn snapshot(&self, result: ScanResult) -> CachedScan {
+        CachedScan {
+            rules: self.registry.enabled_rules(),
+            result,
+        }
+    }
+
+    /// Re-scan `content` running only the rules enabled since `previous` was taken
+    ///
+    /// Findings of rules that are still enabled are taken from `previous`
+    /// unchanged, and those of rules disabled since are dropped, so editing the
+    /// rule set does not pay for a full re-scan. `content` must be the source
+    /// `previous` was produced from.
+    pub fn rescan_changed_rules(&self, content: &str, previous: &CachedScan) -> Result<CachedScan> {
+        let rules = self.registry.enabled_rules();
+        let added: BTreeSet<String> = rules.difference(&previous.rules).cloned().collect();
+
+        // Notes from unregistered names, e.g. skipped rules, belong to the earlier run
+        let mut violations: Vec<_> = previous
+            .result
+            .violations
+            .iter()
+            .filter(|v| rules.contains(&v.rule_name) || self.registry.get(&v.rule_name).is_none())
+            .cloned()
+            .collect();
+
+        if !added.is_empty() {
+            let mut scanner = Self::with_profile(self.registry.profile());
+            for rule in self.registry.rules() {
+                scanner.set_rule_enabled(&rule.id, added.contains(&rule.id));
+            }
+
+            let source = &previous.result.source;
+            let language = Self::detect_language(content, Path::new(source));
+            let fresh = scanner.scan_content_with_language(content, source.clone(), language)?;
+            violations.extend(
+                fresh
+                    .violations
+                    .into_iter()
+                    .filter(|v| added.contains(&v.rule_name)),
+            );
+        }
+
+        Ok(CachedScan {
+            rules,
+            result: ScanResult {
+                source: previous.result.source.clone(),
+                violations,
+                scan_time: chrono::Utc::now(),
+            },
+        })
     }
 
     pub fn scan_file(&self, file_path: &Path) -> Result<ScanResult> {
@@ -100,6 +323,10 @@ impl ContractScanner {
                 .soroban_rule_engine
                 .analyze(content, &source)
                 .map_err(|e| anyhow::anyhow!(format!("Soroban analysis failed: {:?}", e)))?,
+            Some(Language::Solidity) => self
+                .solidity_rule_engine
+                .analyze(content)
+                .map_err(|e| anyhow::anyhow!(e))?,
             None => {
                 // Unknown language, try to detect and analyze
                 if content.contains("soroban_sdk") {
@@ -117,7 +344,7 @@ impl ContractScanner {
 
         Ok(ScanResult {
             source,
-            violations,
+            violations: self.registry.filter(violations),
             scan_time: chrono::Utc::now(),
         })
     }
@@ -139,11 +366,33 @@ impl ContractScanner {
 
         Ok(ScanResult {
             source,
-            violations,
+            violations: self.registry.filter(violations),
             scan_time: chrono::Utc::now(),
         })
     }
     
+    /// Scan a Solidity file specifically
+    pub fn scan_solidity_file(&self, file_path: &Path) -> Result<ScanResult> {
+        let content = std::fs::read_to_string(file_path)
+            .with_context(|| format!("Failed to read file: {:?}", file_path))?;
+
+        self.scan_solidity_content(&content, file_path.to_string_lossy().to_string())
+    }
+
+    /// Scan Solidity content directly
+    pub fn scan_solidity_content(&self, content: &str, source: String) -> Result<ScanResult> {
+        let violations = self
+            .solidity_rule_engine
+            .analyze(content)
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(ScanResult {
+            source,
+            violations: self.registry.filter(violations),
+            scan_time: chrono::Utc::now(),
+        })
+    }
+
     /// Scan a Soroban contract file specifically
     pub fn scan_soroban_file(&self, file_path: &Path) -> Result<ScanResult> {
         let content = std::fs::read_to_string(file_path)
@@ -161,52 +410,204 @@ impl ContractScanner {
 
         Ok(ScanResult {
             source,
-            violations,
+            violations: self.registry.filter(violations),
             scan_time: chrono::Utc::now(),
         })
     }
 
     pub fn scan_directory(&self, dir_path: &Path) -> Result<Vec<ScanResult>> {
+        Ok(self.scan_directory_with_stats(dir_path, None)?.results)
+    }
+
+    /// Scan a directory through the configured cache, or `.gasguard/cache` inside it if none is set
+    pub fn scan_directory_cached(&self, dir_path: &Path) -> Result<Vec<ScanResult>> {
+        let cache = self
+            .cache
+            .clone()
+            .unwrap_or_else(|| ScanCache::new(dir_path.join(DEFAULT_CACHE_DIR)));
+        Ok(self.scan_files(dir_path, None, Some(&cache))?.results)
+    }
+
+    /// Scan a directory, stopping at the first file with a violation at or above `threshold`
+    ///
+    /// Files are visited in name order, so the same tree always stops at the same file.
+    pub fn scan_directory_fail_fast(
+        &self,
+        dir_path: &Path,
+        threshold: &ViolationSeverity,
+    ) -> Result<Vec<ScanResult>> {
+        Ok(self.scan_directory_with_stats(dir_path, Some(threshold))?.results)
+    }
+
+    /// Scan a directory, stopping early if `threshold` is given, and count the files read
+    pub fn scan_directory_with_stats(
+        &self,
+        dir_path: &Path,
+        threshold: Option<&ViolationSeverity>,
+    ) -> Result<DirectoryScan> {
+        self.scan_files(dir_path, threshold, self.cache.as_ref())
+    }
+
+    /// Scan every supported file under `dir_path`, keeping results with violations
+    ///
+    /// Without a threshold files are scanned in parallel and the results sorted
+    /// by source path. With one they are scanned one at a time in name order,
+    /// so the scan can stop at the first file that reaches it.
+    fn scan_files(
+        &self,
+        dir_path: &Path,
+        threshold: Option<&ViolationSeverity>,
+        cache: Option<&ScanCache>,
+    ) -> Result<DirectoryScan> {
+        let files = Self::supported_files(dir_path);
+        let profile = self.registry.profile().to_string();
+        let rules = self.registry.enabled_rules();
+        let scan = |path: &PathBuf| self.scan_cached(path, cache, &profile, &rules);
+
+        let Some(threshold) = threshold else {
+            let scanned = files.par_iter().map(scan).collect::<Result<Vec<_>>>()?;
+            let mut results: Vec<ScanResult> =
+                scanned.into_iter().filter(ScanResult::has_violations).collect();
+            results.sort_by(|a, b| a.source.cmp(&b.source));
+            return Ok(DirectoryScan {
+                results,
+                files_scanned: files.len(),
+            });
+        };
+
         let mut results = Vec::new();
+        let mut files_scanned = 0;
+        for path in &files {
+            let result = scan(path)?;
+            files_scanned += 1;
+            let stop = result.violations.iter().any(|v| v.severity.is_at_least(threshold));
+            if result.has_violations() {
+                results.push(result);
+            }
+            if stop {
+                break;
+            }
+        }
+
+        Ok(DirectoryScan {
+            results,
+            files_scanned,
+        })
+    }
+
+    /// Scan the file at `path`, reusing and filling `cache` if one is given
+    fn scan_cached(
+        &self,
+        path: &Path,
+        cache: Option<&ScanCache>,
+        profile: &str,
+        rules: &BTreeSet<String>,
+    ) -> Result<ScanResult> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {:?}", path))?;
+        let Some(cache) = cache else {
+            return self.scan_detected(&content, path);
+        };
+
+        let key = ScanCache::key(&content, profile, rules);
+        if let Some(mut result) = cache.load(&key) {
+            // Entries are shared by identical files, so report the current path
+            result.source = path.to_string_lossy().to_string();
+            return Ok(result);
+        }
+        let result = self.scan_detected(&content, path)?;
+        cache.store(&key, &result)?;
+        Ok(result)
+    }
+
+    /// Scan `content` read from `path` with the rules for its detected language
+    fn scan_detected(&self, content: &str, path: &Path) -> Result<ScanResult> {
+        let language = Self::detect_language(content, path);
+
+        let source = path.to_string_lossy().to_string();
+        match language {
+            Some(Language::Soroban) => self.scan_soroban_content(content, source),
+            Some(Language::Vyper) => self.scan_vyper_content(content, source),
+            // Default to general scanning
+            _ => self.scan_content_with_language(content, source, language),
+        }
+    }
+
+    /// Count what each enabled rule inspected in a file or every file under a directory
+    ///
+    /// Every enabled rule of every language is listed, so a rule that never
+    /// got to inspect anything shows up with zero counts instead of going
+    /// unnoticed.
+    pub fn coverage(&self, path: &Path) -> Result<CoverageReport> {
+        let mut report = CoverageReport::new(self.registry.enabled_rules());
+        let files = if path.is_file() {
+            vec![path.to_path_buf()]
+        } else {
+            Self::supported_files(path)
+        };
 
-        for entry in walkdir::WalkDir::new(dir_path)
+        for file in files {
+            let content = std::fs::read_to_string(&file)
+                .with_context(|| format!("Failed to read file: {:?}", file))?;
+
+            let coverage = match Self::detect_language(&content, &file) {
+                Some(Language::Soroban) => self
+                    .soroban_rule_engine
+                    .coverage(&content, &file.to_string_lossy())
+                    .map_err(|e| anyhow::anyhow!(format!("Soroban analysis failed: {:?}", e)))?,
+                Some(Language::Vyper) => self
+                    .vyper_rule_engine
+                    .coverage(&content)
+                    .map_err(|e| anyhow::anyhow!(e))?,
+                Some(Language::Solidity) => self
+                    .solidity_rule_engine
+                    .coverage(&content)
+                    .map_err(|e| anyhow::anyhow!(e))?,
+                Some(Language::Rust) | None => self
+                    .rule_engine
+                    .coverage(&content)
+                    .map_err(|e| anyhow::anyhow!(e))?,
+            };
+            // Analyzer checks are reported for every contract, enabled or not
+            report.add_file(
+                coverage
+                    .into_iter()
+                    .filter(|(rule_id, _)| self.registry.is_enabled(rule_id))
+                    .collect(),
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// Rust, Vyper and Solidity files under `dir_path`, in name order, minus paths `.gasguardignore` files exclude
+    fn supported_files(dir_path: &Path) -> Vec<PathBuf> {
+        let mut ignore_files = IgnoreFiles::new(dir_path);
+
+        walkdir::WalkDir::new(dir_path)
+            .sort_by_file_name()
             .into_iter()
+            // Ignored directories are pruned, so nothing below them is read
+            .filter_entry(|e| {
+                e.depth() == 0 || !ignore_files.is_ignored(e.path(), e.file_type().is_dir())
+            })
             .filter_map(|e| e.ok())
             .filter(|e| {
-                e.path().extension().map_or(false, |ext| {
+                e.path().extension().is_some_and(|ext| {
                     let ext_str = ext.to_str().unwrap_or("");
-                    ext_str == "rs" || ext_str == "vy" // Both Rust and Vyper files
+                    matches!(ext_str, "rs" | "vy" | "sol")
                 })
             })
-        {
-            let content = std::fs::read_to_string(entry.path())
-                .with_context(|| format!("Failed to read file: {:?}", entry.path()))?;
-            
-            // Detect language from content for better accuracy
-            let language = Language::from_content(&content).or_else(|| {
-                entry.path().extension()
-                    .and_then(|ext| Language::from_extension(ext.to_str().unwrap_or("")))
-            });
-            
-            let result = match language {
-                Some(Language::Soroban) => {
-                    self.scan_soroban_content(&content, entry.path().to_string_lossy().to_string())?
-                },
-                Some(Language::Vyper) => {
-                    self.scan_vyper_content(&content, entry.path().to_string_lossy().to_string())?
-                },
-                _ => {
-                    // Default to general scanning
-                    self.scan_content_with_language(&content, entry.path().to_string_lossy().to_string(), language)?
-                }
-            };
-            
-            if !result.violations.is_empty() {
-                results.push(result);
-            }
-        }
+            .map(|e| e.into_path())
+            .collect()
+    }
 
-        Ok(results)
+    /// Detect language from content for better accuracy, falling back to the extension
+    fn detect_language(content: &str, path: &Path) -> Option<Language> {
+        Language::from_content(content).or_else(|| {
+            path.extension()
+                .and_then(|ext| Language::from_extension(ext.to_str().unwrap_or("")))
+        })
     }
 }
 
@@ -216,13 +617,46 @@ impl Default for ContractScanner {
     }
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ScanResult {
     pub source: String,
     pub violations: Vec<gasguard_rules::RuleViolation>,
     pub scan_time: chrono::DateTime<chrono::Utc>,
 }
 
+/// A scan result as printed by `--format json`, with its count of auto-fixable findings
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct JsonScanResult<'a> {
+    #[serde(flatten)]
+    pub result: &'a ScanResult,
+    pub fixable: usize,
+}
+
+impl<'a> JsonScanResult<'a> {
+    pub fn new(result: &'a ScanResult, registry: &RuleRegistry) -> Self {
+        Self {
+            result,
+            fixable: ScanAnalyzer::fixable_count(&result.violations, registry),
+        }
+    }
+}
+
+/// Results of a directory scan and the number of files it read
+#[derive(Debug, Clone)]
+pub struct DirectoryScan {
+    /// Results of the files with violations, as `scan_directory` returns them
+    pub results: Vec<ScanResult>,
+    /// Files analyzed, with or without violations, up to where a fail-fast scan stopped
+    pub files_scanned: usize,
+}
+
+/// A scan result together with the rules that were enabled when it was produced
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CachedScan {
+    pub rules: BTreeSet<String>,
+    pub result: ScanResult,
+}
+
 impl ScanResult {
     pub fn has_violations(&self) -> bool {
         !self.violations.is_empty()
@@ -238,7 +672,452 @@ impl ScanResult {
             .collect()
     }
 
+    /// Violations at or above `min`; findings exactly at the threshold are kept
+    pub fn filter_by_min_severity(
+        &self,
+        min: gasguard_rules::ViolationSeverity,
+    ) -> Vec<&gasguard_rules::RuleViolation> {
+        self.violations
+            .iter()
+            .filter(|v| v.severity.is_at_least(&min))
+            .collect()
+    }
+
+    /// Drop violations below `min`, so output, counts and savings only cover what is shown
+    pub fn retain_min_severity(&mut self, min: &gasguard_rules::ViolationSeverity) {
+        self.violations.retain(|v| v.severity.is_at_least(min));
+    }
+
+    /// Remove `rule-skipped` notes, which are only shown in verbose output
+    pub fn drop_skip_notes(&mut self) {
+        self.violations.retain(|v| !v.is_skip_note());
+    }
+
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IGNORE_FILE_NAME;
+
+    const UNFINISHED: &str = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env) {
+        todo!()
+    }
+}
+"#;
+
+    const MINOR: &str = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn ping(env: Env, unused: u32) -> u32 {
+        1
+    }
+}
+"#;
+
+    #[test]
+    fn test_fail_fast_stops_after_first_matching_file() {
+        let dir = std::env::temp_dir().join(format!("gasguard-fail-fast-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("0_clean.rs"), "fn main() {}\n").unwrap();
+        std::fs::write(dir.join("a_minor.rs"), MINOR).unwrap();
+        std::fs::write(dir.join("b_unfinished.rs"), UNFINISHED).unwrap();
+        std::fs::write(dir.join("c_minor.rs"), MINOR).unwrap();
+        std::fs::write(dir.join("d_unfinished.rs"), UNFINISHED).unwrap();
+
+        let scanner = ContractScanner::new();
+        let full = scanner.scan_directory_with_stats(&dir, None).unwrap();
+        let fast = scanner
+            .scan_directory_with_stats(&dir, Some(&ViolationSeverity::High))
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(full.results.len(), 4);
+        assert_eq!(full.files_scanned, 5);
+        // The clean file is counted although it has no result
+        assert_eq!(fast.files_scanned, 3);
+        let scanned: Vec<_> = fast.results.iter().map(|r| r.source.clone()).collect();
+        assert_eq!(scanned.len(), 2);
+        assert!(scanned[0].ends_with("a_minor.rs"));
+        assert!(scanned[1].ends_with("b_unfinished.rs"));
+    }
+
+    #[test]
+    fn test_nested_ignore_files_compose() {
+        let root = std::env::temp_dir().join(format!("gasguard-ignore-{}", std::process::id()));
+        let files = [
+            "a.rs",
+            "a_mock.rs",
+            "vendor/lib.rs",
+            "contracts/token.rs",
+            "contracts/token_mock.rs",
+            "contracts/generated_client.rs",
+            "contracts/generated_keep.rs",
+        ];
+        for file in files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, MINOR).unwrap();
+        }
+        std::fs::write(root.join(IGNORE_FILE_NAME), "vendor/\n*_mock.rs\n").unwrap();
+        std::fs::write(
+            root.join("contracts").join(IGNORE_FILE_NAME),
+            "generated_*.rs\n!generated_keep.rs\n!token_mock.rs\n",
+        )
+        .unwrap();
+
+        let scanned: Vec<String> = ContractScanner::supported_files(&root)
+            .iter()
+            .map(|path| {
+                let relative = path.strip_prefix(&root).unwrap();
+                relative.to_string_lossy().replace('\\', "/")
+            })
+            .collect();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            scanned,
+            [
+                "a.rs",
+                "contracts/generated_keep.rs",
+                "contracts/token.rs",
+                "contracts/token_mock.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solidity_files_dispatch_to_solidity_rules() {
+        let source = r#"
+pragma solidity ^0.8.0;
+
+interface IPool {
+    function swap(uint256 amount) external;
+}
+
+contract Pool {
+    function calculateFee(uint256 amount) public pure returns (uint256) {
+        return amount * 3 / 1000;
+    }
+
+    function swap(uint256 amount) external {
+        uint256 fee = calculateFee(amount);
+    }
+}
+"#;
+        assert_eq!(Language::from_extension("sol"), Some(Language::Solidity));
+        assert_eq!(Language::from_content(source), Some(Language::Solidity));
+
+        let result = ContractScanner::new()
+            .scan_content_with_language(source, "Pool.sol".to_string(), None)
+            .unwrap();
+
+        assert!(result
+            .violations
+            .iter()
+            .any(|v| v.rule_name == "solidity-public-only-internal"
+                && v.variable_name == "calculateFee"));
+    }
+
+    #[test]
+    fn test_coverage_lists_every_enabled_rule() {
+        let dir = std::env::temp_dir().join(format!("gasguard-coverage-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("minor.rs"), MINOR).unwrap();
+        std::fs::write(dir.join("unfinished.rs"), UNFINISHED).unwrap();
+        std::fs::write(dir.join("token.vy"), "# @version ^0.3.0\nevent Paused: pass\n").unwrap();
+        std::fs::write(
+            dir.join("tool.rs"),
+            "use std::io;\n\nmod inner {\n    use std::fmt;\n}\n\nfn main() {}\n",
+        )
+        .unwrap();
+
+        let scanner = ContractScanner::with_profile(Profile::Strict);
+        let report = scanner.coverage(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.rules.len(), scanner.registry().enabled_rules().len());
+        assert_eq!(report.files, 4);
+        let functions = report.rules["soroban-unfinished-code"];
+        assert_eq!(functions.inspected, 2);
+        assert_eq!(functions.percentage(), Some(100.0));
+        assert_eq!(report.rules["unbounded-loop"].inspected, 2);
+        // Neither Soroban fixture declares a contract type
+        assert_eq!(report.rules["soroban-timestamp-type"].percentage(), None);
+        assert_eq!(report.rules["vyper-unemitted-event"].inspected, 1);
+        assert_eq!(report.rules["solidity-struct-packing"].percentage(), None);
+        // The import inside `inner` is out of the rule's reach
+        let imports = report.rules["unused-imports"];
+        assert_eq!((imports.inspected, imports.uninspected), (1, 1));
+        assert_eq!(report.rules["infinite-loop"].inspected, 1);
+    }
+
+    const MIXED: &str = r#"
+use soroban_sdk::{contract, contractimpl, Bytes, Env, Vec};
+
+#[contractimpl]
+impl Encoder {
+    pub fn encode(env: Env, values: Vec<u32>) -> Bytes {
+        let mut out = Bytes::new(&env);
+        for value in values.iter() {
+            out.append(&Bytes::from_array(&env, &value.to_be_bytes()));
+        }
+        todo!()
+    }
+}
+"#;
+
+    #[test]
+    fn test_gas_profile_only_runs_gas_rules() {
+        let category = |scanner: &ContractScanner, rule_name: &str| {
+            scanner.registry().get(rule_name).map(|rule| rule.category)
+        };
+
+        let strict = ContractScanner::with_profile(Profile::Strict);
+        let all = strict
+            .scan_soroban_content(MIXED, "mixed.rs".to_string())
+            .unwrap();
+        assert!(all
+            .violations
+            .iter()
+            .any(|v| category(&strict, &v.rule_name) != Some(RuleCategory::Gas)));
+
+        let gas = ContractScanner::with_profile(Profile::Gas);
+        let result = gas
+            .scan_soroban_content(MIXED, "mixed.rs".to_string())
+            .unwrap();
+        assert!(!result.violations.is_empty());
+        for violation in &result.violations {
+            assert_eq!(
+                category(&gas, &violation.rule_name),
+                Some(RuleCategory::Gas),
+                "{} is not a gas rule",
+                violation.rule_name
+            );
+        }
+        assert!(gas
+            .registry()
+            .rules()
+            .all(|rule| rule.enabled == (rule.category == RuleCategory::Gas)));
+    }
+
+    #[test]
+    fn test_contract_doc_rule_runs_only_under_strict() {
+        let rule = "soroban-missing-contract-doc";
+        assert!(!ContractScanner::new().registry().is_enabled(rule));
+        assert!(ContractScanner::with_profile(Profile::Strict)
+            .registry()
+            .is_enabled(rule));
+    }
+
+    #[test]
+    fn test_rescan_runs_only_newly_enabled_rules() {
+        let mut scanner = ContractScanner::with_profile(Profile::Gas);
+        let first = scanner
+            .scan_soroban_content(MIXED, "mixed.rs".to_string())
+            .unwrap();
+        assert!(!first.violations.is_empty());
+        let mut previous = scanner.snapshot(first);
+        // Re-running the gas rules would replace these descriptions
+        for violation in &mut previous.result.violations {
+            violation.description = "cached".to_string();
+        }
+
+        scanner.set_rule_enabled("soroban-unfinished-code", true);
+        let rescanned = scanner.rescan_changed_rules(MIXED, &previous).unwrap();
+
+        assert!(rescanned.rules.contains("soroban-unfinished-code"));
+        let (fresh, cached): (Vec<_>, Vec<_>) = rescanned
+            .result
+            .violations
+            .iter()
+            .partition(|v| v.rule_name == "soroban-unfinished-code");
+        assert!(!fresh.is_empty());
+        assert!(fresh.iter().all(|v| v.description != "cached"));
+        assert_eq!(cached.len(), previous.result.violations.len());
+        assert!(cached.iter().all(|v| v.description == "cached"));
+    }
+
+    #[test]
+    fn test_parallel_scan_matches_sequential_scan() {
+        let dir = std::env::temp_dir().join(format!("gasguard-parallel-{}", std::process::id()));
+        for idx in 0..24 {
+            let path = dir.join(format!("group_{}", idx % 4)).join(format!("file_{}.rs", idx));
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            let source = match idx % 3 {
+                0 => MINOR.to_string(),
+                1 => UNFINISHED.to_string(),
+                _ => MIXED.replace("Encoder", &format!("Encoder{}", idx)),
+            };
+            std::fs::write(path, source).unwrap();
+        }
+        std::fs::write(dir.join("clean.rs"), "fn main() {}\n").unwrap();
+
+        let scanner = ContractScanner::new();
+        let parallel = scanner.scan_directory(&dir).unwrap();
+        let mut sequential: Vec<ScanResult> = ContractScanner::supported_files(&dir)
+            .iter()
+            .map(|path| {
+                let content = std::fs::read_to_string(path).unwrap();
+                scanner.scan_detected(&content, path).unwrap()
+            })
+            .filter(ScanResult::has_violations)
+            .collect();
+        sequential.sort_by(|a, b| a.source.cmp(&b.source));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(parallel.len(), 24);
+        let summary = |results: &[ScanResult]| -> Vec<(String, Vec<(String, usize)>)> {
+            results
+                .iter()
+                .map(|r| {
+                    let findings = r
+                        .violations
+                        .iter()
+                        .map(|v| (v.rule_name.clone(), v.line_number))
+                        .collect();
+                    (r.source.clone(), findings)
+                })
+                .collect()
+        };
+        assert_eq!(summary(&parallel), summary(&sequential));
+    }
+
+    #[test]
+    fn test_modified_file_invalidates_only_its_cache_entry() {
+        let dir = std::env::temp_dir().join(format!("gasguard-cache-{}", std::process::id()));
+        let cache_dir = dir.join(DEFAULT_CACHE_DIR);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("minor.rs"), MINOR).unwrap();
+        std::fs::write(dir.join("unfinished.rs"), UNFINISHED).unwrap();
+
+        let scanner = ContractScanner::new().with_cache(&cache_dir);
+        let first = scanner.scan_directory_cached(&dir).unwrap();
+        assert_eq!(first.len(), 2);
+        let entries: Vec<_> = std::fs::read_dir(&cache_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        // Re-analysing a file would replace these descriptions
+        for entry in &entries {
+            let mut result: ScanResult =
+                serde_json::from_str(&std::fs::read_to_string(entry).unwrap()).unwrap();
+            for violation in &mut result.violations {
+                violation.description = "cached".to_string();
+            }
+            std::fs::write(entry, serde_json::to_string(&result).unwrap()).unwrap();
+        }
+
+        std::fs::write(dir.join("minor.rs"), MINOR.replace("1\n", "2\n")).unwrap();
+        let second = scanner.scan_directory(&dir).unwrap();
+        let entry_count = std::fs::read_dir(&cache_dir).unwrap().count();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(entry_count, 3);
+        let descriptions = |name: &str| -> Vec<String> {
+            let result = second.iter().find(|r| r.source.ends_with(name)).unwrap();
+            result.violations.iter().map(|v| v.description.clone()).collect()
+        };
+        assert!(descriptions("minor.rs").iter().all(|d| d != "cached"));
+        assert!(descriptions("unfinished.rs").iter().all(|d| d == "cached"));
+    }
+
+    #[test]
+    fn test_disabled_rules_are_skipped_in_every_language() {
+        let vyper = "# @version ^0.3.0\nevent Paused: pass\n";
+        let rust = "fn spin() { loop {} }\n";
+        let rule_names = |scanner: &ContractScanner, content: &str, language| -> Vec<String> {
+            scanner
+                .scan_content_with_language(content, "test".to_string(), Some(language))
+                .unwrap()
+                .violations
+                .into_iter()
+                .map(|v| v.rule_name)
+                .collect()
+        };
+
+        let mut scanner = ContractScanner::new();
+        assert!(rule_names(&scanner, vyper, Language::Vyper).contains(&"vyper-unemitted-event".to_string()));
+        assert!(rule_names(&scanner, rust, Language::Rust).contains(&"infinite-loop".to_string()));
+
+        scanner.set_rule_enabled("vyper-unemitted-event", false);
+        assert!(!scanner.vyper_rule_engine.is_rule_enabled("vyper-unemitted-event"));
+        assert!(rule_names(&scanner, vyper, Language::Vyper).is_empty());
+
+        scanner.enable_only(&["vyper-unemitted-event"]);
+        assert!(!scanner.rule_engine.is_rule_enabled("infinite-loop"));
+        assert!(rule_names(&scanner, rust, Language::Rust).is_empty());
+        assert_eq!(rule_names(&scanner, vyper, Language::Vyper), ["vyper-unemitted-event"]);
+        assert_eq!(scanner.registry().enabled_rules().len(), 1);
+    }
+
+    #[test]
+    fn test_list_rules_covers_every_engine() {
+        let scanner = ContractScanner::new();
+        let rules = scanner.list_rules();
+
+        assert_eq!(rules.len(), scanner.registry().rules().count());
+        for language in [Language::Rust, Language::Vyper, Language::Solidity, Language::Soroban] {
+            assert!(rules.iter().any(|rule| rule.language == language), "no {} rules", language);
+        }
+        let infinite_loop = rules.iter().find(|rule| rule.id == "infinite-loop").unwrap();
+        assert_eq!(infinite_loop.severity, ViolationSeverity::High);
+        let unfinished = rules.iter().find(|rule| rule.id == "soroban-unfinished-code").unwrap();
+        assert_ne!(unfinished.name, unfinished.id);
+        assert!(!rules.iter().find(|rule| rule.id == "soroban-missing-contract-doc").unwrap().enabled);
+    }
+
+    #[test]
+    fn test_min_severity_keeps_findings_at_the_threshold() {
+        let violation = |severity| gasguard_rules::RuleViolation {
+            rule_name: "test-rule".to_string(),
+            description: String::new(),
+            severity,
+            line_number: 1,
+            column_number: 0,
+            variable_name: String::new(),
+            suggestion: String::new(),
+            estimated_saving: None,
+        };
+        let mut result = ScanResult {
+            source: "test.rs".to_string(),
+            violations: vec![
+                violation(ViolationSeverity::Info),
+                violation(ViolationSeverity::Warning),
+                violation(ViolationSeverity::Medium),
+                violation(ViolationSeverity::Error),
+            ],
+            scan_time: chrono::Utc::now(),
+        };
+
+        let kept: Vec<_> = result
+            .filter_by_min_severity(ViolationSeverity::Warning)
+            .into_iter()
+            .map(|v| v.severity.clone())
+            .collect();
+        assert_eq!(
+            kept,
+            [
+                ViolationSeverity::Warning,
+                ViolationSeverity::Medium,
+                ViolationSeverity::Error
+            ]
+        );
+        assert_eq!(result.filter_by_min_severity(ViolationSeverity::Info).len(), 4);
+
+        result.retain_min_severity(&ViolationSeverity::Error);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].severity, ViolationSeverity::Error);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::rule_engine::{Rule, RuleViolation, ViolationSeverity};
+use quote::ToTokens;
+use syn::visit::{self, Visit};
+use syn::{BinOp, Expr, ImplItemFn, Item, ItemFn, UnOp};
+
+pub struct ConstantExpressionRule;
+
+impl Rule for ConstantExpressionRule {
+    fn name(&self) -> &str {
+        "constant-expression"
+    }
+
+    fn description(&self) -> &str {
+        "Identifies arithmetic expressions inside functions whose operands are all literals, which are recomputed on every call instead of being folded into a `const`."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, ast: &[Item]) -> Vec<RuleViolation> {
+        let mut visitor = ConstantExpressionVisitor::default();
+        for item in ast {
+            visitor.visit_item(item);
+        }
+
+        visitor
+            .found
+            .into_iter()
+            .map(|(function, expression)| RuleViolation {
+                rule_name: self.name().to_string(),
+                description: format!(
+                    "Function '{}' computes literal-only expression '{}' on every call.",
+                    function, expression
+                ),
+                severity: self.severity(),
+                line_number: 0,
+                column_number: 0,
+                variable_name: function,
+                suggestion: format!(
+                    "Move '{}' into a named `const` so it is evaluated at compile time.",
+                    expression
+                ),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+}
+
+/// Collects `(function name, expression)` for outermost literal-only binary expressions
+#[derive(Default)]
+struct ConstantExpressionVisitor {
+    current_function: Option<String>,
+    found: Vec<(String, String)>,
+}
+
+impl ConstantExpressionVisitor {
+    fn is_arithmetic(op: &BinOp) -> bool {
+        matches!(
+            op,
+            BinOp::Add(_)
+                | BinOp::Sub(_)
+                | BinOp::Mul(_)
+                | BinOp::Div(_)
+                | BinOp::Rem(_)
+                | BinOp::Shl(_)
+                | BinOp::Shr(_)
+                | BinOp::BitAnd(_)
+                | BinOp::BitOr(_)
+                | BinOp::BitXor(_)
+        )
+    }
+
+    /// Operands built only from literals, parentheses and negation
+    fn is_literal_only(expr: &Expr) -> bool {
+        match expr {
+            Expr::Lit(_) => true,
+            Expr::Paren(paren) => Self::is_literal_only(&paren.expr),
+            Expr::Group(group) => Self::is_literal_only(&group.expr),
+            Expr::Unary(unary) => {
+                matches!(unary.op, UnOp::Neg(_)) && Self::is_literal_only(&unary.expr)
+            }
+            Expr::Binary(binary) => {
+                Self::is_arithmetic(&binary.op)
+                    && Self::is_literal_only(&binary.left)
+                    && Self::is_literal_only(&binary.right)
+            }
+            _ => false,
+        }
+    }
+
+    fn visit_function_body<F: FnOnce(&mut Self)>(&mut self, name: String, visit_body: F) {
+        let outer = self.current_function.replace(name);
+        visit_body(self);
+        self.current_function = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for ConstantExpressionVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.visit_function_body(node.sig.ident.to_string(), |v| {
+            visit::visit_item_fn(v, node)
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.visit_function_body(node.sig.ident.to_string(), |v| {
+            visit::visit_impl_item_fn(v, node)
+        });
+    }
+
+    // Items nested in a function body (including `const`s) are not per-call work
+    fn visit_item_const(&mut self, _node: &'ast syn::ItemConst) {}
+
+    fn visit_item_static(&mut self, _node: &'ast syn::ItemStatic) {}
+
+    // Array lengths are evaluated at compile time, so only the repeated value is checked
+    fn visit_expr_repeat(&mut self, node: &'ast syn::ExprRepeat) {
+        self.visit_expr(&node.expr);
+    }
+
+    fn visit_type_array(&mut self, node: &'ast syn::TypeArray) {
+        self.visit_type(&node.elem);
+    }
+
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        if let (Some(function), Expr::Binary(binary)) = (&self.current_function, node) {
+            if Self::is_arithmetic(&binary.op) && Self::is_literal_only(node) {
+                let expression = node.to_token_stream().to_string();
+                self.found.push((function.clone(), expression));
+                return;
+            }
+        }
+        visit::visit_expr(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_literal_only_arithmetic_is_flagged() {
+        let code = quote! {
+            pub struct Fees;
+
+            impl Fees {
+                pub fn fee(amount: i128) -> i128 {
+                    amount * (1_000_000 * 3 / 1000) / 10_000
+                }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = ConstantExpressionRule.check(&file.items);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "fee");
+        assert!(violations[0].description.contains("1_000_000 * 3 / 1000"));
+    }
+
+    #[test]
+    fn test_expression_with_variable_is_not_flagged() {
+        let code = quote! {
+            const FEE_BPS: i128 = 1_000_000 * 3 / 1000;
+
+            pub fn fee(amount: i128) -> i128 {
+                amount * FEE_BPS / 10_000
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = ConstantExpressionRule.check(&file.items);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_array_lengths_are_not_flagged() {
+        let code = quote! {
+            pub fn buffer() -> [u8; 32 * 2] {
+                let buf: [u8; 32 * 2] = [0u8; 32 * 2];
+                buf
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = ConstantExpressionRule.check(&file.items);
+
+        assert!(violations.is_empty());
+    }
+}
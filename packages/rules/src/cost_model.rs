@@ -0,0 +1,119 @@
+//! Rough Soroban cost model
+//!
+//! Rules use these estimates to attach an `EstimatedSaving` to their findings.
+//! The numbers are deliberately coarse: they exist to rank findings against
+//! each other, not to predict a transaction's fee.
+
+use crate::soroban::helpers::strip_comments_and_strings;
+use regex::Regex;
+
+/// Instructions charged for a single ledger read or write host call
+pub const STORAGE_ACCESS_INSTRUCTIONS: u64 = 2_500;
+
+/// Instructions charged for fetching a storage bucket handle
+pub const HOST_CALL_INSTRUCTIONS: u64 = 250;
+
+/// Instructions assumed for one iteration of a loop body
+pub const LOOP_BODY_INSTRUCTIONS: u64 = 500;
+
+/// Iterations assumed for a loop whose bound is unknown
+pub const ASSUMED_LOOP_ITERATIONS: u64 = 100;
+
+/// Estimate the instructions spent in a loop nest of the given depth
+///
+/// Each level multiplies the work by `ASSUMED_LOOP_ITERATIONS`, so a nested
+/// (quadratic) loop is estimated a hundred times more expensive than a flat one.
+pub fn estimate_cpu_cost(loop_depth: u32) -> u64 {
+    if loop_depth == 0 {
+        return 0;
+    }
+
+    LOOP_BODY_INSTRUCTIONS.saturating_mul(ASSUMED_LOOP_ITERATIONS.saturating_pow(loop_depth))
+}
+
+/// Estimate the encoded ledger size of a value of the given type
+pub fn type_size_bytes(type_name: &str) -> u64 {
+    let type_name = type_name.trim();
+
+    if let Some(inner) = type_name
+        .strip_prefix("Option<")
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        return 1 + type_size_bytes(inner);
+    }
+
+    match type_name {
+        "bool" | "u8" | "i8" => 1,
+        "u16" | "i16" => 2,
+        "u32" | "i32" => 4,
+        "u64" | "i64" => 8,
+        "u128" | "i128" => 16,
+        "u256" | "i256" | "Address" | "BytesN<32>" => 32,
+        "Symbol" => 10,
+        // Strings, byte buffers and collections vary; assume a modest payload
+        _ => 64,
+    }
+}
+
+/// Deepest nesting of `for`/`while`/`loop` blocks in a piece of source
+pub fn loop_nesting_depth(source: &str) -> u32 {
+    let stripped = strip_comments_and_strings(source);
+    let loop_re = Regex::new(r"\b(for|while|loop)\b").unwrap();
+    let loop_starts: Vec<usize> = loop_re.find_iter(&stripped).map(|m| m.start()).collect();
+
+    // Brace depth at which each open loop body started
+    let mut open_loops: Vec<usize> = Vec::new();
+    let mut pending_loop = false;
+    let mut brace_depth = 0usize;
+    let mut deepest = 0u32;
+
+    for (idx, c) in stripped.char_indices() {
+        if loop_starts.contains(&idx) {
+            pending_loop = true;
+        }
+
+        match c {
+            '{' => {
+                brace_depth += 1;
+                if pending_loop {
+                    pending_loop = false;
+                    open_loops.push(brace_depth);
+                    deepest = deepest.max(open_loops.len() as u32);
+                }
+            }
+            '}' => {
+                if open_loops.last() == Some(&brace_depth) {
+                    open_loops.pop();
+                }
+                brace_depth = brace_depth.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    deepest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_loops_cost_more() {
+        let flat = "for a in items.iter() { total += a; }";
+        let nested = "for a in items.iter() { for b in items.iter() { total += a * b; } }";
+
+        assert_eq!(loop_nesting_depth(flat), 1);
+        assert_eq!(loop_nesting_depth(nested), 2);
+        assert!(estimate_cpu_cost(2) > estimate_cpu_cost(1));
+        assert_eq!(estimate_cpu_cost(0), 0);
+    }
+
+    #[test]
+    fn test_type_size_bytes() {
+        assert_eq!(type_size_bytes("u32"), 4);
+        assert_eq!(type_size_bytes("Address"), 32);
+        assert_eq!(type_size_bytes("Option<u64>"), 9);
+        assert_eq!(type_size_bytes("Map<Address, i128>"), 64);
+    }
+}
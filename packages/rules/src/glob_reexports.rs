@@ -0,0 +1,146 @@
+use crate::rule_engine::{top_level_coverage, Rule, RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use std::collections::HashSet;
+use syn::{Item, ItemUse, UseTree, Visibility};
+
+pub struct GlobReexportRule;
+
+impl Rule for GlobReexportRule {
+    fn name(&self) -> &str {
+        "glob-reexport"
+    }
+
+    fn description(&self) -> &str {
+        "Identifies `pub use module::*` re-exports of private modules, which silently expose every helper in that module as part of the contract crate's public surface."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn check(&self, ast: &[Item]) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+        let private_modules = self.find_private_modules(ast);
+
+        for item in ast {
+            if let Item::Use(item_use) = item {
+                if !matches!(item_use.vis, Visibility::Public(_)) {
+                    continue;
+                }
+
+                for module_path in self.glob_paths(item_use) {
+                    let root = module_path
+                        .iter()
+                        .find(|segment| !matches!(segment.as_str(), "self" | "crate" | "super"));
+
+                    if let Some(root) = root {
+                        if private_modules.contains(root) {
+                            let module_name = module_path.join("::");
+                            violations.push(RuleViolation {
+                                rule_name: self.name().to_string(),
+                                description: format!(
+                                    "Glob re-export 'pub use {}::*' exposes every public item of private module '{}'.",
+                                    module_name, root
+                                ),
+                                severity: self.severity(),
+                                line_number: 0,
+                                column_number: 0,
+                                variable_name: module_name.clone(),
+                                suggestion: format!(
+                                    "Replace the glob with explicit re-exports (e.g. 'pub use {}::{{TypeA, TypeB}}') so only the intended items become public.",
+                                    module_name
+                                ),
+                                estimated_saving: None,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, ast: &[Item]) -> RuleCoverage {
+        top_level_coverage(ast, |item| matches!(item, Item::Use(_)))
+    }
+}
+
+impl GlobReexportRule {
+    fn find_private_modules(&self, ast: &[Item]) -> HashSet<String> {
+        ast.iter()
+            .filter_map(|item| match item {
+                Item::Mod(item_mod) if matches!(item_mod.vis, Visibility::Inherited) => {
+                    Some(item_mod.ident.to_string())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Collect the module path of every glob inside a use tree
+    fn glob_paths(&self, item_use: &ItemUse) -> Vec<Vec<String>> {
+        let mut paths = Vec::new();
+        Self::collect_glob_paths(&item_use.tree, &mut Vec::new(), &mut paths);
+        paths
+    }
+
+    fn collect_glob_paths(tree: &UseTree, prefix: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+        match tree {
+            UseTree::Path(use_path) => {
+                prefix.push(use_path.ident.to_string());
+                Self::collect_glob_paths(&use_path.tree, prefix, paths);
+                prefix.pop();
+            }
+            UseTree::Group(group) => {
+                for item in &group.items {
+                    Self::collect_glob_paths(item, prefix, paths);
+                }
+            }
+            UseTree::Glob(_) => paths.push(prefix.clone()),
+            UseTree::Name(_) | UseTree::Rename(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_glob_reexport_of_private_module() {
+        let code = quote! {
+            mod internal {
+                pub fn helper() {}
+            }
+
+            pub use internal::*;
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = GlobReexportRule.check(&file.items);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "internal");
+    }
+
+    #[test]
+    fn test_explicit_reexport_not_flagged() {
+        let code = quote! {
+            mod internal {
+                pub struct Bar;
+            }
+
+            pub use internal::Bar;
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = GlobReexportRule.check(&file.items);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::rule_engine::{Rule, RuleCategory, RuleViolation, ViolationSeverity};
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprLoop, ImplItemFn, Item, ItemFn, Lifetime};
+
+/// Macros that always leave the current invocation
+const EXITING_MACROS: [&str; 5] = [
+    "panic",
+    "panic_with_error",
+    "unreachable",
+    "todo",
+    "unimplemented",
+];
+
+pub struct InfiniteLoopRule;
+
+impl Rule for InfiniteLoopRule {
+    fn name(&self) -> &str {
+        "infinite-loop"
+    }
+
+    fn description(&self) -> &str {
+        "Identifies `loop` blocks with no `break`, `return`, `?` or panicking macro, which never terminate and exhaust the CPU budget."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn check(&self, ast: &[Item]) -> Vec<RuleViolation> {
+        let mut visitor = InfiniteLoopVisitor::default();
+        for item in ast {
+            visitor.visit_item(item);
+        }
+
+        visitor
+            .found
+            .into_iter()
+            .map(|function| RuleViolation {
+                rule_name: self.name().to_string(),
+                description: format!(
+                    "Function '{}' contains a `loop` with no way to exit; it runs until the CPU budget is exhausted.",
+                    function
+                ),
+                severity: self.severity(),
+                line_number: 0,
+                column_number: 0,
+                variable_name: function,
+                suggestion: "Add a `break` or `return` on the loop's termination condition, or iterate over a bounded range instead.".to_string(),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+}
+
+/// Collects the function name of every `loop` without an exit
+#[derive(Default)]
+struct InfiniteLoopVisitor {
+    current_function: Option<String>,
+    found: Vec<String>,
+}
+
+impl InfiniteLoopVisitor {
+    fn visit_function_body<F: FnOnce(&mut Self)>(&mut self, name: String, visit_body: F) {
+        let outer = self.current_function.replace(name);
+        visit_body(self);
+        self.current_function = outer;
+    }
+}
+
+impl<'ast> Visit<'ast> for InfiniteLoopVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.visit_function_body(node.sig.ident.to_string(), |v| {
+            visit::visit_item_fn(v, node)
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.visit_function_body(node.sig.ident.to_string(), |v| {
+            visit::visit_impl_item_fn(v, node)
+        });
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        if let Some(function) = &self.current_function {
+            let mut exits = LoopExitFinder {
+                label: node.label.as_ref().map(|l| &l.name),
+                nested_loops: 0,
+                found: false,
+            };
+            exits.visit_block(&node.body);
+            if !exits.found {
+                self.found.push(function.clone());
+            }
+        }
+        visit::visit_expr_loop(self, node);
+    }
+}
+
+/// Looks for anything in a loop body that leaves that loop
+struct LoopExitFinder<'a> {
+    label: Option<&'a Lifetime>,
+    /// Loops entered inside the body; an unlabeled `break` there exits only them
+    nested_loops: usize,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for LoopExitFinder<'_> {
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        match node {
+            Expr::Return(_) | Expr::Try(_) => self.found = true,
+            Expr::Break(brk) => match &brk.label {
+                Some(label) => self.found |= self.label == Some(label),
+                None => self.found |= self.nested_loops == 0,
+            },
+            Expr::Macro(mac) => {
+                let name = mac.mac.path.segments.last().map(|s| s.ident.to_string());
+                self.found |= name.is_some_and(|n| EXITING_MACROS.contains(&n.as_str()));
+            }
+            Expr::Loop(_) | Expr::While(_) | Expr::ForLoop(_) => {
+                self.nested_loops += 1;
+                visit::visit_expr(self, node);
+                self.nested_loops -= 1;
+                return;
+            }
+            // `return` inside a closure leaves the closure, not the loop
+            Expr::Closure(_) => return,
+            _ => {}
+        }
+        visit::visit_expr(self, node);
+    }
+
+    fn visit_stmt_macro(&mut self, node: &'ast syn::StmtMacro) {
+        let name = node.mac.path.segments.last().map(|s| s.ident.to_string());
+        self.found |= name.is_some_and(|n| EXITING_MACROS.contains(&n.as_str()));
+    }
+
+    // Nested functions have their own control flow
+    fn visit_item(&mut self, _node: &'ast Item) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_loop_without_exit_is_flagged() {
+        let code = quote! {
+            impl Game {
+                pub fn spin(env: Env) {
+                    let mut turns = 0u32;
+                    loop {
+                        turns += 1;
+                        for _ in 0..3 {
+                            break;
+                        }
+                    }
+                }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = InfiniteLoopRule.check(&file.items);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "spin");
+    }
+
+    #[test]
+    fn test_loop_with_conditional_break_is_not_flagged() {
+        let code = quote! {
+            impl Game {
+                pub fn spin(env: Env, limit: u32) {
+                    let mut turns = 0u32;
+                    loop {
+                        turns += 1;
+                        if turns >= limit {
+                            break;
+                        }
+                    }
+                }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = InfiniteLoopRule.check(&file.items);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -1,11 +1,25 @@
 pub mod rule_engine;
+pub mod cost_model;
 pub mod unused_state_variables;
+pub mod glob_reexports;
+pub mod unused_imports;
+pub mod constant_expression;
+pub mod narrowing_cast;
+pub mod infinite_loop;
+pub mod redundant_try;
 pub mod vyper;
+pub mod solidity;
 pub mod soroban;
 
 // Explicitly export core types to avoid ambiguity
-pub use rule_engine::{Rule, RuleEngine, RuleViolation, ViolationSeverity, extract_struct_fields, find_variable_usage};
+pub use rule_engine::{EstimatedSaving, Rule, RuleCategory, RuleCoverage, RuleEngine, RuleViolation, SavingUnit, ViolationSeverity, RULE_SKIPPED, extract_struct_fields, find_identifier_usage, find_variable_usage};
 pub use unused_state_variables::UnusedStateVariablesRule;
+pub use glob_reexports::GlobReexportRule;
+pub use unused_imports::UnusedImportsRule;
+pub use constant_expression::ConstantExpressionRule;
+pub use narrowing_cast::NarrowingCastRule;
+pub use infinite_loop::InfiniteLoopRule;
+pub use redundant_try::RedundantTryRule;
 
 // Export Soroban types specifically
 pub use soroban::{
@@ -15,11 +29,16 @@ pub use soroban::{
     SorobanResult, 
     SorobanRuleEngine,
     SorobanStruct,
+    SorobanEnum,
     SorobanImpl,
+    SorobanInterface,
     SorobanFunction,
     SorobanField,
     SorobanParam
 };
 
+// Export Solidity types
+pub use solidity::{SolidityContract, SolidityRule, SolidityRuleEngine};
+
 // Export Vyper types (keeping glob here is fine if Vyper module is clean, but let's be safe)
 pub use vyper::*;
\ No newline at end of file
@@ -0,0 +1,238 @@
+use crate::rule_engine::{Rule, RuleCategory, RuleViolation, ViolationSeverity};
+use quote::ToTokens;
+use std::collections::HashMap;
+use syn::visit::{self, Visit};
+use syn::{Expr, Fields, FnArg, ImplItemFn, Item, ItemFn, Local, Member, Pat, Type};
+
+/// Identifiers holding token quantities, where truncation is an accounting bug
+const QUANTITY_NAMES: [&str; 3] = ["amount", "balance", "supply"];
+
+pub struct NarrowingCastRule;
+
+impl Rule for NarrowingCastRule {
+    fn name(&self) -> &str {
+        "narrowing-cast"
+    }
+
+    fn description(&self) -> &str {
+        "Identifies `as` casts that narrow an amount, balance or supply to a smaller integer type, silently truncating the value."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn check(&self, ast: &[Item]) -> Vec<RuleViolation> {
+        let mut visitor = NarrowingCastVisitor::default();
+        for item in ast {
+            if let Item::Struct(item_struct) = item {
+                if let Fields::Named(fields) = &item_struct.fields {
+                    for field in &fields.named {
+                        if let (Some(ident), Some(ty)) = (&field.ident, integer_type(&field.ty)) {
+                            visitor.field_types.insert(ident.to_string(), ty);
+                        }
+                    }
+                }
+            }
+        }
+        for item in ast {
+            visitor.visit_item(item);
+        }
+
+        visitor
+            .found
+            .into_iter()
+            .map(|cast| RuleViolation {
+                rule_name: self.name().to_string(),
+                description: format!(
+                    "Function '{}' casts '{}' from {} to {} with `as`, which truncates values that do not fit.",
+                    cast.function, cast.value, cast.from, cast.to
+                ),
+                severity: self.severity(),
+                line_number: 0,
+                column_number: 0,
+                variable_name: cast.value.clone(),
+                suggestion: format!(
+                    "Use `{}::try_from({})` (or `.try_into()`) and handle the overflow error instead of `as`.",
+                    cast.to, cast.value
+                ),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+}
+
+/// Bit width of a primitive integer type name
+fn integer_width(name: &str) -> Option<u32> {
+    match name {
+        "u8" | "i8" => Some(8),
+        "u16" | "i16" => Some(16),
+        "u32" | "i32" => Some(32),
+        "u64" | "i64" | "usize" | "isize" => Some(64),
+        "u128" | "i128" => Some(128),
+        _ => None,
+    }
+}
+
+/// Name of a primitive integer type, looking through references
+fn integer_type(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Reference(reference) => integer_type(&reference.elem),
+        Type::Path(path) => {
+            let ident = path.path.get_ident()?.to_string();
+            integer_width(&ident).map(|_| ident)
+        }
+        _ => None,
+    }
+}
+
+struct NarrowingCast {
+    function: String,
+    value: String,
+    from: String,
+    to: String,
+}
+
+/// Collects narrowing casts of quantity-named values whose source type is known
+#[derive(Default)]
+struct NarrowingCastVisitor {
+    field_types: HashMap<String, String>,
+    current_function: Option<String>,
+    local_types: HashMap<String, String>,
+    found: Vec<NarrowingCast>,
+}
+
+impl NarrowingCastVisitor {
+    fn visit_function<F: FnOnce(&mut Self)>(
+        &mut self,
+        name: String,
+        inputs: &syn::punctuated::Punctuated<FnArg, syn::Token![,]>,
+        visit_body: F,
+    ) {
+        let outer_function = self.current_function.replace(name);
+        let outer_locals = std::mem::take(&mut self.local_types);
+        for input in inputs {
+            if let FnArg::Typed(arg) = input {
+                self.record_binding(&arg.pat, &arg.ty);
+            }
+        }
+
+        visit_body(self);
+
+        self.local_types = outer_locals;
+        self.current_function = outer_function;
+    }
+
+    fn record_binding(&mut self, pat: &Pat, ty: &Type) {
+        if let (Pat::Ident(ident), Some(ty)) = (pat, integer_type(ty)) {
+            self.local_types.insert(ident.ident.to_string(), ty);
+        }
+    }
+
+    /// Identifier and integer type of a cast operand, when both are known
+    fn operand(&self, expr: &Expr) -> Option<(String, String)> {
+        match expr {
+            Expr::Paren(paren) => self.operand(&paren.expr),
+            Expr::Group(group) => self.operand(&group.expr),
+            Expr::Path(path) => {
+                let name = path.path.get_ident()?.to_string();
+                let ty = self.local_types.get(&name)?.clone();
+                Some((name, ty))
+            }
+            Expr::Field(field) => {
+                let Member::Named(member) = &field.member else {
+                    return None;
+                };
+                let ty = self.field_types.get(&member.to_string())?.clone();
+                Some((field.to_token_stream().to_string().replace(' ', ""), ty))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for NarrowingCastVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.visit_function(node.sig.ident.to_string(), &node.sig.inputs, |v| {
+            visit::visit_item_fn(v, node)
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.visit_function(node.sig.ident.to_string(), &node.sig.inputs, |v| {
+            visit::visit_impl_item_fn(v, node)
+        });
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        if let Pat::Type(typed) = &node.pat {
+            self.record_binding(&typed.pat, &typed.ty);
+        }
+        visit::visit_local(self, node);
+    }
+
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        if let (Some(function), Expr::Cast(cast)) = (&self.current_function, node) {
+            let target = integer_type(&cast.ty);
+            if let (Some((value, from)), Some(to)) = (self.operand(&cast.expr), target) {
+                let lower = value.to_lowercase();
+                let is_quantity = QUANTITY_NAMES.iter().any(|name| lower.contains(name));
+                if is_quantity && integer_width(&to) < integer_width(&from) {
+                    self.found.push(NarrowingCast {
+                        function: function.clone(),
+                        value,
+                        from,
+                        to,
+                    });
+                }
+            }
+        }
+        visit::visit_expr(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_narrowing_amount_cast_is_flagged() {
+        let code = quote! {
+            impl Token {
+                pub fn transfer(env: Env, amount: u128) {
+                    let units = amount as u32;
+                    emit(&env, units);
+                }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = NarrowingCastRule.check(&file.items);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "amount");
+        assert!(violations[0].description.contains("from u128 to u32"));
+    }
+
+    #[test]
+    fn test_widening_cast_is_not_flagged() {
+        let code = quote! {
+            impl Token {
+                pub fn transfer(env: Env, amount: u32) {
+                    let total: u128 = amount as u128;
+                    emit(&env, total);
+                }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = NarrowingCastRule.check(&file.items);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+use crate::rule_engine::{Rule, RuleViolation, ViolationSeverity};
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprTry, ImplItemFn, Item, ItemFn};
+
+pub struct RedundantTryRule;
+
+impl Rule for RedundantTryRule {
+    fn name(&self) -> &str {
+        "redundant-try"
+    }
+
+    fn description(&self) -> &str {
+        "Identifies `?` applied to an `Ok(...)` value, which can never fail and only adds an error branch to the compiled code."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, ast: &[Item]) -> Vec<RuleViolation> {
+        let mut visitor = RedundantTryVisitor::default();
+        for item in ast {
+            visitor.visit_item(item);
+        }
+
+        visitor
+            .found
+            .into_iter()
+            .map(|found| RuleViolation {
+                rule_name: self.name().to_string(),
+                description: format!(
+                    "Function '{}' applies `?` to `{}`, which is always `Ok`.",
+                    found.function, found.expression
+                ),
+                severity: self.severity(),
+                line_number: found.line,
+                column_number: found.column,
+                variable_name: found.function,
+                suggestion: "Remove the `Ok(...)?` wrapper and use the inner value directly."
+                    .to_string(),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+}
+
+/// A `?` on an `Ok(...)` call
+struct FoundTry {
+    function: String,
+    expression: String,
+    /// Zero when the tokens carry no source position
+    line: usize,
+    column: usize,
+}
+
+/// Collects every `?` applied directly to an `Ok(...)` call
+#[derive(Default)]
+struct RedundantTryVisitor {
+    current_function: Option<String>,
+    found: Vec<FoundTry>,
+}
+
+impl RedundantTryVisitor {
+    fn visit_function_body<F: FnOnce(&mut Self)>(&mut self, name: String, visit_body: F) {
+        let outer = self.current_function.replace(name);
+        visit_body(self);
+        self.current_function = outer;
+    }
+
+    /// Whether `expr` is a call to `Ok` or `Result::Ok`, with or without a turbofish
+    fn is_ok_call(expr: &Expr) -> bool {
+        let Expr::Call(call) = expr else {
+            return false;
+        };
+        let Expr::Path(path) = call.func.as_ref() else {
+            return false;
+        };
+        let segments: Vec<String> = path
+            .path
+            .segments
+            .iter()
+            .map(|s| s.ident.to_string())
+            .collect();
+        matches!(
+            segments
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .as_slice(),
+            ["Ok"] | ["Result", "Ok"] | ["core" | "std", "result", "Result", "Ok"]
+        )
+    }
+}
+
+impl<'ast> Visit<'ast> for RedundantTryVisitor {
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        self.visit_function_body(node.sig.ident.to_string(), |v| {
+            visit::visit_item_fn(v, node)
+        });
+    }
+
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        self.visit_function_body(node.sig.ident.to_string(), |v| {
+            visit::visit_impl_item_fn(v, node)
+        });
+    }
+
+    fn visit_expr_try(&mut self, node: &'ast ExprTry) {
+        if let Some(function) = &self.current_function {
+            if Self::is_ok_call(&node.expr) {
+                let start = node.span().start();
+                self.found.push(FoundTry {
+                    function: function.clone(),
+                    expression: node.expr.to_token_stream().to_string(),
+                    line: start.line,
+                    column: if start.line > 0 { start.column + 1 } else { 0 },
+                });
+            }
+        }
+        visit::visit_expr_try(self, node);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_on_ok_literal_is_flagged() {
+        let code = r#"
+impl Vault {
+    pub fn shares(env: Env, amount: i128) -> Result<i128, Error> {
+        let shares = Ok::<i128, Error>(amount / 2)?;
+        Ok(shares)
+    }
+}
+"#;
+
+        let file = syn::parse_file(code).unwrap();
+        let violations = RedundantTryRule.check(&file.items);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "shares");
+        assert_eq!(
+            (violations[0].line_number, violations[0].column_number),
+            (4, 22)
+        );
+    }
+
+    #[test]
+    fn test_try_on_fallible_call_is_not_flagged() {
+        let code = r#"
+impl Vault {
+    pub fn shares(env: Env, amount: i128) -> Result<i128, Error> {
+        let shares = checked_div(amount, 2)?;
+        Ok(shares)
+    }
+}
+"#;
+
+        let file = syn::parse_file(code).unwrap();
+        let violations = RedundantTryRule.check(&file.items);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -2,8 +2,10 @@
 //!
 //! Provides the fundamental traits and AST traversal logic for the rules engine.
 
+use quote::ToTokens;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::fmt;
 use syn::{Expr, Item, ItemImpl, ItemStruct, Member, Pat};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,9 +17,101 @@ pub struct RuleViolation {
     pub column_number: usize,
     pub variable_name: String,
     pub suggestion: String,
+    /// Rough payoff of applying the suggestion, when the rule can estimate one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_saving: Option<EstimatedSaving>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Rule name carried by notes explaining that a rule could not run
+pub const RULE_SKIPPED: &str = "rule-skipped";
+
+impl RuleViolation {
+    /// Note that `rule_id` could not check `subject` because parsed data was missing
+    pub fn rule_skipped(rule_id: &str, subject: &str, reason: &str, line_number: usize) -> Self {
+        Self {
+            rule_name: RULE_SKIPPED.to_string(),
+            description: format!("Rule '{}' skipped '{}': {}", rule_id, subject, reason),
+            severity: ViolationSeverity::Info,
+            line_number,
+            column_number: 0,
+            variable_name: subject.to_string(),
+            suggestion: "Review this code manually; the parser could not extract what the rule needs"
+                .to_string(),
+            estimated_saving: None,
+        }
+    }
+
+    pub fn is_skip_note(&self) -> bool {
+        self.rule_name == RULE_SKIPPED
+    }
+}
+
+/// How many items a rule examined in a contract, and how many it had to pass over
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleCoverage {
+    /// Functions or types the rule was able to examine
+    pub inspected: usize,
+    /// Functions or types hidden from the parser, such as those a macro generates
+    pub uninspected: usize,
+}
+
+impl RuleCoverage {
+    /// Share of items that were inspected, or `None` when there was nothing to inspect
+    pub fn percentage(&self) -> Option<f64> {
+        let total = self.inspected + self.uninspected;
+        (total > 0).then(|| self.inspected as f64 * 100.0 / total as f64)
+    }
+}
+
+impl std::ops::Add for RuleCoverage {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            inspected: self.inspected + other.inspected,
+            uninspected: self.uninspected + other.uninspected,
+        }
+    }
+}
+
+impl std::ops::AddAssign for RuleCoverage {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// Unit an estimated saving is expressed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum SavingUnit {
+    /// CPU instructions per invocation
+    Instructions,
+    /// Ledger storage bytes
+    Bytes,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EstimatedSaving {
+    pub amount: u64,
+    pub unit: SavingUnit,
+}
+
+impl EstimatedSaving {
+    pub fn instructions(amount: u64) -> Self {
+        Self {
+            amount,
+            unit: SavingUnit::Instructions,
+        }
+    }
+
+    pub fn bytes(amount: u64) -> Self {
+        Self {
+            amount,
+            unit: SavingUnit::Bytes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ViolationSeverity {
     Error,
     High,
@@ -26,19 +120,162 @@ pub enum ViolationSeverity {
     Info,
 }
 
-pub trait Rule {
+impl ViolationSeverity {
+    /// Position in the severity order; higher is more severe
+    pub fn rank(&self) -> u8 {
+        match self {
+            ViolationSeverity::Error => 4,
+            ViolationSeverity::High => 3,
+            ViolationSeverity::Medium => 2,
+            ViolationSeverity::Warning => 1,
+            ViolationSeverity::Info => 0,
+        }
+    }
+
+    pub fn is_at_least(&self, threshold: &ViolationSeverity) -> bool {
+        self.rank() >= threshold.rank()
+    }
+}
+
+impl std::str::FromStr for ViolationSeverity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(ViolationSeverity::Error),
+            "high" => Ok(ViolationSeverity::High),
+            "medium" => Ok(ViolationSeverity::Medium),
+            "warning" => Ok(ViolationSeverity::Warning),
+            "info" => Ok(ViolationSeverity::Info),
+            other => Err(format!(
+                "Unknown severity '{}' (expected error, high, medium, warning or info)",
+                other
+            )),
+        }
+    }
+}
+
+/// What kind of problem a rule looks for, used to select rule sets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum RuleCategory {
+    /// Wasted CPU instructions, storage or fees
+    Gas,
+    /// Missing or weakened access control and unsafe value handling
+    Security,
+    /// Logic that can misbehave at runtime
+    Correctness,
+    /// Readability and publishing hygiene with no runtime effect
+    Style,
+}
+
+impl fmt::Display for RuleCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RuleCategory::Gas => "gas",
+            RuleCategory::Security => "security",
+            RuleCategory::Correctness => "correctness",
+            RuleCategory::Style => "style",
+        };
+        f.write_str(name)
+    }
+}
+
+pub trait Rule: Send + Sync {
     fn name(&self) -> &str;
     fn description(&self) -> &str;
+    /// Severity the rule reports its findings at
+    fn severity(&self) -> ViolationSeverity;
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Gas
+    }
+    /// Whether `gasguard fix` can apply the suggestion without review
+    fn auto_fixable(&self) -> bool {
+        false
+    }
     fn check(&self, ast: &[Item]) -> Vec<RuleViolation>;
+
+    /// Count what the rule inspected in a parsed file
+    ///
+    /// Most rules look at function bodies; rules that look at other items
+    /// override this with [`top_level_coverage`].
+    fn coverage(&self, ast: &[Item]) -> RuleCoverage {
+        function_coverage(ast)
+    }
+}
+
+/// Coverage over functions at any depth; item macros, whose expansion syn cannot see, are uninspected
+pub fn function_coverage(items: &[Item]) -> RuleCoverage {
+    let mut coverage = RuleCoverage::default();
+
+    for item in items {
+        match item {
+            Item::Fn(_) => coverage.inspected += 1,
+            Item::Impl(item_impl) => {
+                for impl_item in &item_impl.items {
+                    match impl_item {
+                        syn::ImplItem::Fn(_) => coverage.inspected += 1,
+                        syn::ImplItem::Macro(_) => coverage.uninspected += 1,
+                        _ => {}
+                    }
+                }
+            }
+            Item::Mod(module) => {
+                if let Some((_, items)) = &module.content {
+                    coverage += function_coverage(items);
+                }
+            }
+            // `macro_rules!` definitions carry a name and expand to nothing here
+            Item::Macro(item_macro) if item_macro.ident.is_none() => coverage.uninspected += 1,
+            _ => {}
+        }
+    }
+
+    coverage
+}
+
+/// Coverage over the items `is_target` selects; matches inside inline modules are uninspected
+pub fn top_level_coverage(items: &[Item], is_target: fn(&Item) -> bool) -> RuleCoverage {
+    fn nested(items: &[Item], is_target: fn(&Item) -> bool) -> usize {
+        items
+            .iter()
+            .map(|item| match item {
+                Item::Mod(module) => module
+                    .content
+                    .as_ref()
+                    .map_or(0, |(_, items)| nested(items, is_target)),
+                item => usize::from(is_target(item)),
+            })
+            .sum()
+    }
+
+    let mut coverage = RuleCoverage::default();
+    for item in items {
+        match item {
+            Item::Mod(module) => {
+                if let Some((_, items)) = &module.content {
+                    coverage.uninspected += nested(items, is_target);
+                }
+            }
+            item if is_target(item) => coverage.inspected += 1,
+            _ => {}
+        }
+    }
+
+    coverage
 }
 
 pub struct RuleEngine {
     rules: Vec<Box<dyn Rule>>,
+    /// Names of rules skipped by `analyze`
+    disabled: HashSet<String>,
 }
 
 impl RuleEngine {
     pub fn new() -> Self {
-        Self { rules: Vec::new() }
+        Self {
+            rules: Vec::new(),
+            disabled: HashSet::new(),
+        }
     }
 
     pub fn add_rule(mut self, rule: Box<dyn Rule>) -> Self {
@@ -46,16 +283,51 @@ impl RuleEngine {
         self
     }
 
+    pub fn rules(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.iter().map(|r| r.as_ref())
+    }
+
+    /// Enable or disable a rule by name; unknown names are ignored
+    pub fn set_rule_enabled(&mut self, name: &str, enabled: bool) {
+        if enabled {
+            self.disabled.remove(name);
+        } else if self.rules.iter().any(|r| r.name() == name) {
+            self.disabled.insert(name.to_string());
+        }
+    }
+
+    pub fn is_rule_enabled(&self, name: &str) -> bool {
+        !self.disabled.contains(name)
+    }
+
     pub fn analyze(&self, code: &str) -> Result<Vec<RuleViolation>, String> {
         let ast = syn::parse_file(code).map_err(|e| format!("Failed to parse Rust code: {}", e))?;
 
         let mut violations = Vec::new();
-        for rule in &self.rules {
+        for rule in self.rules.iter().filter(|r| !self.disabled.contains(r.name())) {
             violations.extend(rule.check(&ast.items));
         }
 
         Ok(violations)
     }
+
+    /// Count, per enabled rule, what it inspected in `code`
+    pub fn coverage(&self, code: &str) -> Result<Vec<(String, RuleCoverage)>, String> {
+        let ast = syn::parse_file(code).map_err(|e| format!("Failed to parse Rust code: {}", e))?;
+
+        Ok(self
+            .rules
+            .iter()
+            .filter(|r| !self.disabled.contains(r.name()))
+            .map(|r| (r.name().to_string(), r.coverage(&ast.items)))
+            .collect())
+    }
+}
+
+impl Default for RuleEngine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 // Helper functions for AST analysis
@@ -82,6 +354,29 @@ pub fn find_variable_usage(impl_block: &ItemImpl) -> HashSet<String> {
     used_vars
 }
 
+/// Every identifier that appears in the given items, including inside macro invocations
+pub fn find_identifier_usage(items: &[Item]) -> HashSet<String> {
+    let mut identifiers = HashSet::new();
+
+    for item in items {
+        collect_identifiers(item.to_token_stream(), &mut identifiers);
+    }
+
+    identifiers
+}
+
+fn collect_identifiers(tokens: proc_macro2::TokenStream, identifiers: &mut HashSet<String>) {
+    for token in tokens {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) => {
+                identifiers.insert(ident.to_string());
+            }
+            proc_macro2::TokenTree::Group(group) => collect_identifiers(group.stream(), identifiers),
+            _ => {}
+        }
+    }
+}
+
 fn extract_variables_from_stmt(stmt: &syn::Stmt, used_vars: &mut HashSet<String>) {
     match stmt {
         syn::Stmt::Local(local) => {
@@ -0,0 +1,189 @@
+use crate::rule_engine::{RuleViolation, ViolationSeverity};
+use crate::solidity::parser::{SolidityContract, SolidityFunction};
+use crate::solidity::rule_engine::SolidityRule;
+use crate::soroban::helpers::{line_offset, matching_close, strip_comments_and_strings};
+use regex::Regex;
+
+/// Rule for detecting `for` loop counters incremented with checked arithmetic
+///
+/// Since Solidity 0.8 every `i++` carries an overflow check. A counter bounded
+/// by the loop condition cannot overflow, so `unchecked { ++i; }` at the end of
+/// the body skips that check on every iteration. From 0.8.22 the compiler
+/// drops the check on `++i`/`i++` itself, so only `i += 1` is reported there.
+pub struct CheckedLoopIncrementRule;
+
+impl SolidityRule for CheckedLoopIncrementRule {
+    fn name(&self) -> &str {
+        "solidity-checked-loop-increment"
+    }
+
+    fn description(&self) -> &str {
+        "Detects for loops whose counter is incremented with checked i++ / i += 1 on Solidity 0.8 and later."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        // Without a pragma, assume a current compiler
+        let version = Self::minimum_version(&contract.source).unwrap_or((0, 8, 22));
+        // Overflow checks only exist from 0.8
+        if version < (0, 8, 0) {
+            return Vec::new();
+        }
+        let compiler_skips_increment_check = version >= (0, 8, 22);
+
+        let loop_pattern = Regex::new(r"\bfor\s*\(").unwrap();
+        let increment_pattern = Regex::new(r"^(?:(\w+)\s*(?:\+\+|\+=\s*1)|\+\+\s*(\w+))$").unwrap();
+        let mut violations = Vec::new();
+
+        for function in &contract.functions {
+            let Some(body) = &function.body else {
+                continue;
+            };
+            let stripped = strip_comments_and_strings(body);
+
+            for header in loop_pattern.find_iter(&stripped) {
+                let Some(close) = matching_close(&stripped, header.end() - 1) else {
+                    continue;
+                };
+                let clauses: Vec<&str> = stripped[header.end()..close].split(';').collect();
+                let Some(captures) = clauses
+                    .get(2)
+                    .and_then(|inc| increment_pattern.captures(inc.trim()))
+                else {
+                    continue;
+                };
+                let counter = captures
+                    .get(1)
+                    .or(captures.get(2))
+                    .map(|m| m.as_str())
+                    .unwrap_or("");
+                if compiler_skips_increment_check && !clauses[2].contains("+=") {
+                    continue;
+                }
+
+                violations.push(self.create_violation(
+                    function,
+                    counter,
+                    function.body_start_line + line_offset(&stripped, header.start()),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+impl CheckedLoopIncrementRule {
+    /// Lowest compiler version the pragma allows, as `(major, minor, patch)`
+    fn minimum_version(source: &str) -> Option<(u32, u32, u32)> {
+        let pragma_pattern =
+            Regex::new(r"pragma\s+solidity\s+[^;\d]*(\d+)\.(\d+)(?:\.(\d+))?").unwrap();
+        let captures = pragma_pattern.captures(source)?;
+        let part = |idx: usize| {
+            captures
+                .get(idx)
+                .and_then(|m| m.as_str().parse().ok())
+                .unwrap_or(0)
+        };
+        Some((part(1), part(2), part(3)))
+    }
+
+    fn create_violation(
+        &self,
+        function: &SolidityFunction,
+        counter: &str,
+        line_number: usize,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Loop in '{}' increments '{}' with overflow-checked arithmetic.",
+                function.name, counter
+            ),
+            severity: self.severity(),
+            line_number,
+            column_number: 0,
+            variable_name: counter.to_string(),
+            suggestion: format!(
+                "If the loop bound keeps '{}' far below its type's maximum, drop the increment from the header and end the body with 'unchecked {{ ++{}; }}'.",
+                counter, counter
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_increment_is_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Batch {
+    function sum(uint256[] calldata values) external pure returns (uint256 total) {
+        for (uint256 i = 0; i < values.length; i++) {
+            total += values[i];
+        }
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = CheckedLoopIncrementRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "i");
+        assert_eq!(violations[0].line_number, 6);
+    }
+
+    #[test]
+    fn test_unchecked_increment_is_not_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Batch {
+    function sum(uint256[] calldata values) external pure returns (uint256 total) {
+        for (uint256 i = 0; i < values.length; ) {
+            total += values[i];
+            unchecked { ++i; }
+        }
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = CheckedLoopIncrementRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_increment_operators_are_not_flagged_from_0_8_22() {
+        let source = r#"
+pragma solidity >=0.8.22 <0.9.0;
+
+contract Batch {
+    function sum(uint256[] calldata values) external pure returns (uint256 total) {
+        for (uint256 i = 0; i < values.length; i++) {
+            total += values[i];
+        }
+        for (uint256 j = 0; j < values.length; ++j) {
+            total += values[j];
+        }
+        for (uint256 k = 0; k < values.length; k += 1) {
+            total += values[k];
+        }
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = CheckedLoopIncrementRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "k");
+    }
+}
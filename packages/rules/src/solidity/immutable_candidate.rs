@@ -0,0 +1,267 @@
+use crate::rule_engine::{RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::solidity::parser::{SolidityContract, SolidityFunction, SolidityStateVariable};
+use crate::solidity::rule_engine::SolidityRule;
+use crate::soroban::helpers::strip_comments_and_strings;
+use regex::Regex;
+
+/// Rule for detecting state variables that are never changed after deployment
+///
+/// A variable assigned only in the constructor can be `immutable`, which
+/// embeds it in the bytecode instead of costing an SLOAD on every read. One
+/// that is only initialized with a literal at its declaration can be
+/// `constant`.
+pub struct ImmutableCandidateRule;
+
+impl SolidityRule for ImmutableCandidateRule {
+    fn name(&self) -> &str {
+        "solidity-immutable-candidate"
+    }
+
+    fn description(&self) -> &str {
+        "Detects state variables written only in the constructor or only at declaration, which could be immutable or constant."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        let literal_pattern = Regex::new(
+            r"^(?:\d[\w.]*|true|false)(?:\s+(?:wei|gwei|ether|seconds|minutes|hours|days|weeks))?$",
+        )
+        .unwrap();
+
+        contract
+            .state_variables
+            .iter()
+            .filter(|variable| !variable.is_constant && !variable.is_immutable)
+            .filter(|variable| Self::is_value_type(&variable.type_name, contract))
+            .filter_map(|variable| {
+                let owned_by = |function: &&SolidityFunction| {
+                    function.contract.as_deref() == Some(variable.contract.as_str())
+                };
+                // Modifier bodies run inside ordinary functions, so their writes count too
+                let functions: Vec<&SolidityFunction> = contract
+                    .functions
+                    .iter()
+                    .chain(&contract.modifiers)
+                    .filter(owned_by)
+                    .collect();
+                if functions.iter().any(|f| Self::writes(f, &variable.name)) {
+                    return None;
+                }
+
+                let in_constructor = contract
+                    .constructors
+                    .iter()
+                    .filter(owned_by)
+                    .any(|c| Self::writes(c, &variable.name));
+                if in_constructor {
+                    return Some(self.create_violation(variable, "immutable"));
+                }
+                let literal = variable
+                    .value
+                    .as_deref()
+                    .is_some_and(|value| literal_pattern.is_match(value));
+                literal.then(|| self.create_violation(variable, "constant"))
+            })
+            .collect()
+    }
+
+    fn coverage(&self, contract: &SolidityContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.state_variables.len(),
+            uninspected: 0,
+        }
+    }
+}
+
+impl ImmutableCandidateRule {
+    /// Whether `immutable`/`constant` can apply: elementary value types, contracts and enums
+    ///
+    /// Structs, mappings, arrays, `string` and `bytes` cannot be immutable.
+    fn is_value_type(type_name: &str, contract: &SolidityContract) -> bool {
+        let elementary_pattern =
+            Regex::new(r"^(?:address(?: payable)?|bool|u?int\d*|bytes\d+|u?fixed(?:\d+x\d+)?)$")
+                .unwrap();
+
+        elementary_pattern.is_match(type_name)
+            || contract.contracts.iter().any(|name| name == type_name)
+            || contract.enums.iter().any(|name| name == type_name)
+    }
+
+    /// Whether `function` assigns, increments or deletes `name`, or a member or element of it
+    fn writes(function: &SolidityFunction, name: &str) -> bool {
+        let Some(body) = &function.body else {
+            return false;
+        };
+        let stripped = strip_comments_and_strings(body);
+        let name = regex::escape(name);
+        let write_pattern = Regex::new(&format!(
+            r"(?:^|[^.\w])(?:{name}(?:\s*\.\s*\w+|\s*\[[^\]]*\])*\s*(?:=[^=]|[-+*/%|&^]=|<<=|>>=|\+\+|--)|(?:\+\+|--|delete\s+){name}\b)"
+        ))
+        .unwrap();
+
+        write_pattern.is_match(&stripped)
+    }
+
+    fn create_violation(&self, variable: &SolidityStateVariable, keyword: &str) -> RuleViolation {
+        let reason = if keyword == "immutable" {
+            "is only assigned in the constructor"
+        } else {
+            "is only assigned a literal at its declaration"
+        };
+
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "State variable '{}' {} but is read from storage on every access.",
+                variable.name, reason
+            ),
+            severity: self.severity(),
+            line_number: variable.line_number,
+            column_number: 0,
+            variable_name: variable.name.clone(),
+            suggestion: format!(
+                "Declare '{}' as {} to avoid an SLOAD per read.",
+                variable.name, keyword
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constructor_only_variable_is_flagged_immutable() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Vault {
+    address public owner;
+    uint256 public total;
+
+    constructor(address _owner) {
+        owner = _owner;
+    }
+
+    function deposit(uint256 amount) external {
+        total += amount;
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = ImmutableCandidateRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "owner");
+        assert!(violations[0].suggestion.contains("immutable"));
+    }
+
+    #[test]
+    fn test_mutated_variable_is_not_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Vault {
+    address public owner;
+
+    constructor(address _owner) {
+        owner = _owner;
+    }
+
+    function transferOwnership(address next) external {
+        require(msg.sender == owner, "not owner");
+        owner = next;
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = ImmutableCandidateRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_variable_written_in_modifier_is_not_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Vault {
+    uint256 private _status;
+
+    constructor() {
+        _status = 1;
+    }
+
+    modifier nonReentrant() {
+        require(_status != 2, "reentrant");
+        _status = 2;
+        _;
+        _status = 1;
+    }
+
+    function withdraw() external nonReentrant {}
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = ImmutableCandidateRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_struct_variable_is_not_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Vault {
+    struct Config {
+        uint256 fee;
+    }
+
+    enum Mode { Open, Closed }
+
+    Config config;
+    uint256[4] limits;
+    Mode mode;
+
+    constructor(uint256 fee, Mode initial) {
+        config = Config(fee);
+        limits[0] = fee;
+        mode = initial;
+    }
+
+    function setFee(uint256 f) external {
+        config.fee = f;
+    }
+
+    function setLimit(uint256 i, uint256 limit) external {
+        limits[i] = limit;
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = ImmutableCandidateRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "mode");
+    }
+
+    #[test]
+    fn test_member_and_element_assignments_count_as_writes() {
+        let function = SolidityContract::parse(
+            "contract C { function f() external { config.fee = 1; limits[i] = 2; } }",
+        )
+        .unwrap()
+        .functions
+        .remove(0);
+
+        assert!(ImmutableCandidateRule::writes(&function, "config"));
+        assert!(ImmutableCandidateRule::writes(&function, "limits"));
+        assert!(!ImmutableCandidateRule::writes(&function, "fee"));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::rule_engine::{RuleViolation, ViolationSeverity};
+use crate::solidity::parser::{SolidityContract, SolidityFunction};
+use crate::solidity::rule_engine::SolidityRule;
+use crate::soroban::helpers::{line_offset, matching_close, strip_comments_and_strings};
+use regex::Regex;
+
+/// Revert strings up to this many bytes fit in a single word
+const MAX_REVERT_STRING_BYTES: usize = 32;
+
+/// Rule for detecting `require` calls with revert strings longer than one word
+///
+/// Every byte of a revert string is stored in the bytecode, and strings longer
+/// than 32 bytes need extra memory words at revert time. A custom `error` costs
+/// a 4-byte selector instead.
+pub struct LongRevertStringRule;
+
+impl SolidityRule for LongRevertStringRule {
+    fn name(&self) -> &str {
+        "solidity-long-revert-string"
+    }
+
+    fn description(&self) -> &str {
+        "Detects require() calls whose revert string is longer than 32 bytes."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        let require_pattern = Regex::new(r"\brequire\s*\(").unwrap();
+        let mut violations = Vec::new();
+
+        for function in &contract.functions {
+            let Some(body) = &function.body else {
+                continue;
+            };
+            let stripped = strip_comments_and_strings(body);
+
+            for call in require_pattern.find_iter(&stripped) {
+                let open = call.end() - 1;
+                let Some(close) = matching_close(&stripped, open) else {
+                    continue;
+                };
+                // Commas inside the message are blanked in the stripped text
+                let Some(comma) = Self::top_level_comma(&stripped[open + 1..close]) else {
+                    continue;
+                };
+                let Some(message) = Self::string_literal(&body[open + 1 + comma + 1..close]) else {
+                    continue;
+                };
+                if message.len() > MAX_REVERT_STRING_BYTES {
+                    violations.push(self.create_violation(
+                        function,
+                        message,
+                        function.body_start_line + line_offset(body, call.start()),
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl LongRevertStringRule {
+    fn top_level_comma(arguments: &str) -> Option<usize> {
+        let mut depth = 0i32;
+        for (idx, c) in arguments.char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => return Some(idx),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Contents of a single string literal argument
+    fn string_literal(argument: &str) -> Option<&str> {
+        let argument = argument.trim();
+        ['"', '\''].into_iter().find_map(|quote| {
+            argument
+                .strip_prefix(quote)
+                .and_then(|rest| rest.strip_suffix(quote))
+        })
+    }
+
+    fn create_violation(
+        &self,
+        function: &SolidityFunction,
+        message: &str,
+        line_number: usize,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "require() in '{}' uses a {}-byte revert string \"{}\".",
+                function.name,
+                message.len(),
+                message
+            ),
+            severity: self.severity(),
+            line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            suggestion: "Declare a custom error and use 'if (!cond) revert MyError();' (or shorten the message to 32 bytes or less).".to_string(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_long_revert_string_is_flagged() {
+        let source = r#"
+contract Vault {
+    function withdraw(uint256 amount) external {
+        require(amount > 0, "Vault: amount must be greater than zero, got none");
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = LongRevertStringRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("49-byte"));
+        assert_eq!(violations[0].line_number, 4);
+    }
+
+    #[test]
+    fn test_short_revert_string_is_not_flagged() {
+        let source = r#"
+contract Vault {
+    function withdraw(uint256 amount) external {
+        require(amount > 0, "zero amount");
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = LongRevertStringRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::rule_engine::{RuleCategory, RuleViolation, ViolationSeverity};
+use crate::solidity::parser::{SolidityContract, SolidityFunction};
+use crate::solidity::rule_engine::SolidityRule;
+
+/// Rule for detecting contract functions declared without a visibility specifier
+///
+/// Solidity 0.5 and later reject such functions; older compilers silently
+/// make them `public`, exposing what may have been meant as a helper.
+pub struct MissingVisibilityRule;
+
+impl SolidityRule for MissingVisibilityRule {
+    fn name(&self) -> &str {
+        "solidity-missing-visibility"
+    }
+
+    fn description(&self) -> &str {
+        "Detects functions declared without public, external, internal or private."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        contract
+            .functions
+            .iter()
+            // Free functions take no visibility
+            .filter(|function| function.contract.is_some() && function.visibility.is_none())
+            .map(|function| self.create_violation(function))
+            .collect()
+    }
+}
+
+impl MissingVisibilityRule {
+    fn create_violation(&self, function: &SolidityFunction) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Function '{}' has no visibility specifier; modern compilers reject it and older ones make it public.",
+                function.name
+            ),
+            severity: self.severity(),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            suggestion: format!(
+                "Declare '{}' as external or public if it is part of the interface, otherwise internal or private.",
+                function.name
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_function_without_visibility_is_flagged() {
+        let source = r#"
+pragma solidity ^0.4.24;
+
+contract Wallet {
+    function withdrawAll() {
+        msg.sender.transfer(this.balance);
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = MissingVisibilityRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "withdrawAll");
+        assert_eq!(violations[0].line_number, 5);
+    }
+
+    #[test]
+    fn test_explicit_public_function_is_not_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Wallet {
+    function withdrawAll() public {
+        payable(msg.sender).transfer(address(this).balance);
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = MissingVisibilityRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -1,2 +1,21 @@
+pub mod checked_loop_increment;
+pub mod immutable_candidate;
+pub mod long_revert_string;
+pub mod missing_visibility;
 pub mod parser;
+pub mod public_only_internal;
+pub mod rule_engine;
+pub mod struct_packing;
 pub mod uint8_vs_uint256;
+pub mod unbounded_holder_loop;
+
+pub use checked_loop_increment::*;
+pub use immutable_candidate::*;
+pub use long_revert_string::*;
+pub use missing_visibility::*;
+pub use parser::*;
+pub use public_only_internal::*;
+pub use rule_engine::*;
+pub use struct_packing::*;
+pub use uint8_vs_uint256::*;
+pub use unbounded_holder_loop::*;
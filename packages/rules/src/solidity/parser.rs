@@ -0,0 +1,685 @@
+use crate::soroban::helpers::{line_offset, matching_close, strip_comments_and_strings};
+use regex::Regex;
+
+/// Represents a named, typed declaration such as a struct member or function parameter
+#[derive(Debug, Clone)]
+pub struct SolidityVariable {
+    pub name: String,
+    /// Declared type with data location keywords (`memory`, `calldata`, `storage`) removed
+    pub type_name: String,
+    pub line_number: usize,
+}
+
+/// Represents a `struct` definition, inside a contract or at file level
+#[derive(Debug, Clone)]
+pub struct SolidityStruct {
+    pub name: String,
+    /// Contract, library or interface declaring the struct; `None` at file level
+    pub contract: Option<String>,
+    pub fields: Vec<SolidityVariable>,
+    pub line_number: usize,
+}
+
+/// Represents a contract-level storage variable such as `uint256 public totalSupply;`
+#[derive(Debug, Clone)]
+pub struct SolidityStateVariable {
+    pub name: String,
+    pub type_name: String,
+    pub contract: String,
+    /// `public`, `internal` or `private` when written out
+    pub visibility: Option<String>,
+    pub is_constant: bool,
+    pub is_immutable: bool,
+    /// Initializer expression, if any
+    pub value: Option<String>,
+    pub line_number: usize,
+}
+
+/// Represents a `function` definition with its header and body
+#[derive(Debug, Clone)]
+pub struct SolidityFunction {
+    pub name: String,
+    /// Contract, library or interface declaring the function; `None` for free functions
+    pub contract: Option<String>,
+    pub params: Vec<SolidityVariable>,
+    /// `public`, `external`, `internal` or `private` when written out
+    pub visibility: Option<String>,
+    /// `view`, `pure` or `payable` when written out
+    pub state_mutability: Option<String>,
+    /// Remaining header words, e.g. modifier invocations, `virtual` and `override`
+    pub modifiers: Vec<String>,
+    /// Contents of the `returns (...)` list
+    pub returns: Option<String>,
+    /// Source of the body including its braces; `None` for declarations ending in `;`
+    pub body: Option<String>,
+    /// Line number of the body's opening brace
+    pub body_start_line: usize,
+    pub line_number: usize,
+}
+
+/// Represents a call to a function by name from inside a function body
+#[derive(Debug, Clone)]
+pub struct SolidityFunctionCall {
+    pub function_name: String,
+    /// Function containing the call
+    pub caller: String,
+    /// Whether the call goes through `this.`, i.e. is an external call to the same contract
+    pub is_this_call: bool,
+    pub line_number: usize,
+}
+
+/// Parsed Solidity source file representation
+#[derive(Debug, Clone)]
+pub struct SolidityContract {
+    /// Names of the contracts, libraries and interfaces in the file
+    pub contracts: Vec<String>,
+    pub structs: Vec<SolidityStruct>,
+    pub enums: Vec<String>,
+    pub state_variables: Vec<SolidityStateVariable>,
+    pub functions: Vec<SolidityFunction>,
+    /// `constructor` blocks, parsed like functions and named `constructor`
+    pub constructors: Vec<SolidityFunction>,
+    /// `modifier` definitions, parsed like functions
+    pub modifiers: Vec<SolidityFunction>,
+    /// Bare `f()` and `this.f()` calls; member calls on other receivers are not included
+    pub function_calls: Vec<SolidityFunctionCall>,
+    /// Raw contract source code
+    pub source: String,
+}
+
+const VISIBILITIES: [&str; 4] = ["public", "external", "internal", "private"];
+const STATE_MUTABILITIES: [&str; 3] = ["view", "pure", "payable"];
+const DATA_LOCATIONS: [&str; 3] = ["memory", "storage", "calldata"];
+
+/// Contract-level statements that are not storage variables
+const NON_VARIABLE_KEYWORDS: [&str; 9] = [
+    "pragma",
+    "import",
+    "using",
+    "event",
+    "error",
+    "fallback",
+    "receive",
+    "type",
+    "enum",
+];
+
+impl SolidityContract {
+    /// Parse Solidity source code into its contracts, structs, state variables and functions
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut contract = Self {
+            contracts: Vec::new(),
+            structs: Vec::new(),
+            enums: Vec::new(),
+            state_variables: Vec::new(),
+            functions: Vec::new(),
+            constructors: Vec::new(),
+            modifiers: Vec::new(),
+            function_calls: Vec::new(),
+            source: source.to_string(),
+        };
+
+        // Offsets into the stripped text index the original source directly
+        let stripped = strip_comments_and_strings(source);
+        contract.parse_items(&stripped, 0, stripped.len(), None);
+
+        Ok(contract)
+    }
+
+    /// Parse the items between `start` and `end`, declared in `owner` if given
+    fn parse_items(&mut self, stripped: &str, start: usize, end: usize, owner: Option<&str>) {
+        let mut idx = start;
+
+        while idx < end {
+            let rest = &stripped[idx..end];
+            let Some(item_start) = rest.find(|c: char| !c.is_whitespace()).map(|i| idx + i) else {
+                break;
+            };
+            // Anything left without a terminator is incomplete code; stop there
+            let Some((item_end, brace)) = Self::item_end(stripped, item_start, end) else {
+                break;
+            };
+            self.parse_item(stripped, item_start, item_end, brace, owner);
+            idx = item_end + 1;
+        }
+    }
+
+    /// End of the item at `start`: its `;`, or the brace closing its block
+    /// (returned together with the block's opening brace)
+    fn item_end(stripped: &str, start: usize, end: usize) -> Option<(usize, Option<usize>)> {
+        let bytes = stripped.as_bytes();
+        let mut depth = 0i32;
+        let mut idx = start;
+
+        while idx < end {
+            match bytes[idx] {
+                b'(' | b'[' => depth += 1,
+                b')' | b']' => depth -= 1,
+                b';' if depth == 0 => return Some((idx, None)),
+                b'{' if depth == 0 => {
+                    let close = matching_close(stripped, idx)?;
+                    return Some((close, Some(idx)));
+                }
+                // A stray closing brace ends the enclosing block
+                b'}' if depth == 0 => return Some((idx, None)),
+                _ => {}
+            }
+            idx += 1;
+        }
+
+        None
+    }
+
+    fn parse_item(
+        &mut self,
+        stripped: &str,
+        start: usize,
+        end: usize,
+        brace: Option<usize>,
+        owner: Option<&str>,
+    ) {
+        let text = &stripped[start..=end];
+        let keyword = text
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .next()
+            .unwrap_or("");
+        let line_number = Self::line(stripped, start);
+
+        match keyword {
+            "abstract" | "contract" | "library" | "interface" => {
+                let name_re =
+                    Regex::new(r"^(?:abstract\s+)?(?:contract|library|interface)\s+(\w+)").unwrap();
+                let (Some(name), Some(open)) =
+                    (name_re.captures(text).map(|c| c[1].to_string()), brace)
+                else {
+                    return;
+                };
+                self.contracts.push(name.clone());
+                self.parse_items(stripped, open + 1, end, Some(&name));
+            }
+            "struct" => {
+                let name_re = Regex::new(r"^struct\s+(\w+)").unwrap();
+                let (Some(captures), Some(open)) = (name_re.captures(text), brace) else {
+                    return;
+                };
+                let fields = Self::parse_struct_fields(stripped, open + 1, end);
+                self.structs.push(SolidityStruct {
+                    name: captures[1].to_string(),
+                    contract: owner.map(str::to_string),
+                    fields,
+                    line_number,
+                });
+            }
+            "enum" => {
+                let name_re = Regex::new(r"^enum\s+(\w+)").unwrap();
+                if let Some(captures) = name_re.captures(text) {
+                    self.enums.push(captures[1].to_string());
+                }
+            }
+            "function" => {
+                if let Some(function) = self.parse_function(stripped, start, end, brace, owner) {
+                    if let Some(open) = brace {
+                        self.record_calls(stripped, open, end, &function.name);
+                    }
+                    self.functions.push(function);
+                }
+            }
+            "constructor" => {
+                if let Some(constructor) = self.parse_function(stripped, start, end, brace, owner) {
+                    self.constructors.push(constructor);
+                }
+            }
+            "modifier" => {
+                if let Some(modifier) = self.parse_function(stripped, start, end, brace, owner) {
+                    self.modifiers.push(modifier);
+                }
+            }
+            _ if NON_VARIABLE_KEYWORDS.contains(&keyword) || text.trim() == "}" => {}
+            _ => {
+                if let (Some(owner), None) = (owner, brace) {
+                    if let Some(variable) =
+                        Self::parse_state_variable(&text[..text.len() - 1], owner, line_number)
+                    {
+                        self.state_variables.push(variable);
+                    }
+                }
+            }
+        }
+    }
+
+    fn parse_struct_fields(stripped: &str, start: usize, end: usize) -> Vec<SolidityVariable> {
+        let mut fields = Vec::new();
+        let mut field_start = start;
+
+        for (offset, c) in stripped[start..end].char_indices() {
+            if c != ';' {
+                continue;
+            }
+            let declaration = &stripped[field_start..start + offset];
+            let line_number = Self::line(
+                stripped,
+                field_start + declaration.len() - declaration.trim_start().len(),
+            );
+            if let Some(field) = Self::parse_declaration(declaration, line_number) {
+                fields.push(field);
+            }
+            field_start = start + offset + 1;
+        }
+
+        fields
+    }
+
+    fn parse_function(
+        &self,
+        stripped: &str,
+        start: usize,
+        end: usize,
+        brace: Option<usize>,
+        owner: Option<&str>,
+    ) -> Option<SolidityFunction> {
+        let name_re =
+            Regex::new(r"^(?:(?:function|modifier)\s+(\w+)|(constructor))\s*(\()?").unwrap();
+        let text = &stripped[start..=end];
+        let captures = name_re.captures(text)?;
+        // Modifiers without parameters may leave out the parentheses
+        let (params, header_start) = match captures.get(3) {
+            Some(open) => {
+                let params_open = start + open.start();
+                let params_close = matching_close(stripped, params_open)?;
+                let params = Self::split_top_level(&stripped[params_open + 1..params_close])
+                    .into_iter()
+                    .filter_map(|param| {
+                        Self::parse_declaration(param, Self::line(stripped, params_open))
+                    })
+                    .collect();
+                (params, params_close + 1)
+            }
+            None => (Vec::new(), start + captures.get(0)?.end()),
+        };
+
+        let header_end = brace.unwrap_or(end);
+        let mut header = stripped[header_start..header_end].to_string();
+        let mut returns = None;
+        if let Some(returns_idx) = header.find("returns") {
+            let open = header[returns_idx..].find('(').map(|i| returns_idx + i);
+            if let Some((open, close)) =
+                open.and_then(|open| Some((open, matching_close(&header, open)?)))
+            {
+                returns = Some(Self::normalize(&header[open + 1..close]));
+                header.replace_range(returns_idx..=close, " ");
+            }
+        }
+
+        let mut visibility = None;
+        let mut state_mutability = None;
+        let mut modifiers = Vec::new();
+        for word in Self::split_header_words(&header) {
+            if VISIBILITIES.contains(&word.as_str()) {
+                visibility = Some(word);
+            } else if STATE_MUTABILITIES.contains(&word.as_str()) {
+                state_mutability = Some(word);
+            } else {
+                modifiers.push(word);
+            }
+        }
+
+        Some(SolidityFunction {
+            name: captures.get(1).or(captures.get(2))?.as_str().to_string(),
+            contract: owner.map(str::to_string),
+            params,
+            visibility,
+            state_mutability,
+            modifiers,
+            returns,
+            body: brace.map(|open| self.source[open..=end].to_string()),
+            body_start_line: brace.map(|open| Self::line(stripped, open)).unwrap_or(0),
+            line_number: Self::line(stripped, start),
+        })
+    }
+
+    /// Header words, keeping modifier invocations like `onlyRole(ADMIN)` whole
+    fn split_header_words(header: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut current = String::new();
+        let mut depth = 0i32;
+
+        for c in header.chars() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            if c.is_whitespace() && depth == 0 {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            } else {
+                current.push(c);
+            }
+        }
+        if !current.is_empty() {
+            words.push(current);
+        }
+
+        words
+    }
+
+    /// Parse `type [location] name`; unnamed parameters get an empty name
+    fn parse_declaration(declaration: &str, line_number: usize) -> Option<SolidityVariable> {
+        let (type_name, rest) = Self::split_type(declaration.trim())?;
+        let words: Vec<&str> = rest
+            .split_whitespace()
+            .filter(|word| !DATA_LOCATIONS.contains(word) && *word != "indexed")
+            .collect();
+
+        Some(SolidityVariable {
+            name: words.last().map(|w| w.to_string()).unwrap_or_default(),
+            type_name,
+            line_number,
+        })
+    }
+
+    fn parse_state_variable(
+        statement: &str,
+        owner: &str,
+        line_number: usize,
+    ) -> Option<SolidityStateVariable> {
+        let (declaration, value) = match Self::assignment_index(statement) {
+            Some(idx) => (
+                &statement[..idx],
+                Some(Self::normalize(&statement[idx + 1..])),
+            ),
+            None => (statement, None),
+        };
+        let (type_name, rest) = Self::split_type(declaration.trim())?;
+        let words: Vec<&str> = rest.split_whitespace().collect();
+        let name = words.last()?.to_string();
+        if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return None;
+        }
+        let modifiers = &words[..words.len() - 1];
+
+        Some(SolidityStateVariable {
+            name,
+            type_name,
+            contract: owner.to_string(),
+            visibility: modifiers
+                .iter()
+                .find(|word| VISIBILITIES.contains(word))
+                .map(|word| word.to_string()),
+            is_constant: modifiers.contains(&"constant"),
+            is_immutable: modifiers.contains(&"immutable"),
+            value,
+            line_number,
+        })
+    }
+
+    /// Split a declaration into its (normalized) type and the words after it
+    fn split_type(declaration: &str) -> Option<(String, &str)> {
+        if declaration.is_empty() {
+            return None;
+        }
+
+        let mut type_end = if declaration.starts_with("mapping") {
+            let open = declaration.find('(')?;
+            matching_close(declaration, open)? + 1
+        } else {
+            declaration
+                .find(char::is_whitespace)
+                .unwrap_or(declaration.len())
+        };
+        // Array suffixes may be separated from the element type by spaces
+        loop {
+            let rest = declaration[type_end..].trim_start();
+            if !rest.starts_with('[') {
+                break;
+            }
+            let open = declaration.len() - rest.len();
+            type_end = matching_close(declaration, open)? + 1;
+        }
+
+        let mut type_name = Self::normalize(&declaration[..type_end]);
+        let mut rest = &declaration[type_end..];
+        if type_name == "address" {
+            if let Some(after) = rest.trim_start().strip_prefix("payable") {
+                if after.is_empty() || after.starts_with(char::is_whitespace) {
+                    type_name.push_str(" payable");
+                    rest = after;
+                }
+            }
+        }
+
+        Some((type_name, rest))
+    }
+
+    /// Index of a top-level `=` that starts an initializer (not `==`, `=>`, `<=`, ...)
+    fn assignment_index(statement: &str) -> Option<usize> {
+        let bytes = statement.as_bytes();
+        let mut depth = 0i32;
+
+        for (idx, &byte) in bytes.iter().enumerate() {
+            match byte {
+                b'(' | b'[' => depth += 1,
+                b')' | b']' => depth -= 1,
+                b'=' if depth == 0 => {
+                    let next = bytes.get(idx + 1).copied();
+                    let prev = idx.checked_sub(1).map(|i| bytes[i]);
+                    if !matches!(next, Some(b'=') | Some(b'>'))
+                        && !matches!(prev, Some(b'=') | Some(b'!') | Some(b'<') | Some(b'>'))
+                    {
+                        return Some(idx);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    fn split_top_level(list: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0i32;
+        let mut start = 0;
+
+        for (idx, c) in list.char_indices() {
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(&list[start..idx]);
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+        if !list[start..].trim().is_empty() {
+            parts.push(&list[start..]);
+        }
+
+        parts
+    }
+
+    fn normalize(text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn line(text: &str, idx: usize) -> usize {
+        line_offset(text, idx) + 1
+    }
+
+    /// Record the bare and `this.` calls made between `start` and `end`
+    fn record_calls(&mut self, stripped: &str, start: usize, end: usize, caller: &str) {
+        let call_pattern = Regex::new(r"(?:\b(this)\s*\.\s*)?\b(\w+)\s*\(").unwrap();
+        let body = &stripped[start..=end];
+
+        for captures in call_pattern.captures_iter(body) {
+            let Some(whole) = captures.get(0) else {
+                continue;
+            };
+            let is_this_call = captures.get(1).is_some();
+            let before = body[..whole.start()].trim_end();
+            // `token.transfer(...)`, `emit Event(...)` and `new C(...)` are not calls to this contract
+            if !is_this_call
+                && (before.ends_with('.') || before.ends_with("emit") || before.ends_with("new"))
+            {
+                continue;
+            }
+
+            self.function_calls.push(SolidityFunctionCall {
+                function_name: captures[2].to_string(),
+                caller: caller.to_string(),
+                is_this_call,
+                line_number: Self::line(stripped, start + whole.start()),
+            });
+        }
+    }
+
+    /// Find a struct by name
+    pub fn find_struct(&self, name: &str) -> Option<&SolidityStruct> {
+        self.structs.iter().find(|s| s.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_function_calls() {
+        let source = r#"
+contract Token {
+    function transfer(address to, uint256 amount) public {
+        _move(msg.sender, to, amount);
+        this.notify(to);
+        token.transfer(to, amount);
+        emit Transfer(msg.sender, to, amount);
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let calls: Vec<(&str, bool)> = contract
+            .function_calls
+            .iter()
+            .map(|c| (c.function_name.as_str(), c.is_this_call))
+            .collect();
+
+        assert_eq!(calls, vec![("_move", false), ("notify", true)]);
+        assert_eq!(contract.function_calls[0].caller, "transfer");
+        assert_eq!(contract.function_calls[1].line_number, 5);
+    }
+
+    #[test]
+    fn test_parse_contract_items() {
+        let source = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+contract Vault {
+    struct Position {
+        address owner;
+        uint128 amount;
+        bool active;
+    }
+
+    enum Status { Open, Closed }
+
+    uint256 public constant FEE = 30;
+    address immutable admin;
+    mapping(address => Position) private positions;
+
+    event Deposited(address indexed who, uint256 amount);
+
+    modifier onlyOwner {
+        _;
+    }
+
+    modifier onlyRole(bytes32 role) virtual {
+        _;
+    }
+
+    constructor(address _admin) {
+        admin = _admin;
+    }
+
+    function deposit(uint256 amount, bytes calldata data) external payable onlyOwner returns (bool ok) {
+        require(amount > 0, "zero");
+        return true;
+    }
+
+    function peek() public view returns (uint256);
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+
+        assert_eq!(contract.contracts, vec!["Vault"]);
+        assert_eq!(contract.enums, vec!["Status"]);
+
+        let position = contract.find_struct("Position").unwrap();
+        assert_eq!(position.contract.as_deref(), Some("Vault"));
+        let fields: Vec<(&str, &str)> = position
+            .fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.type_name.as_str()))
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                ("owner", "address"),
+                ("amount", "uint128"),
+                ("active", "bool")
+            ]
+        );
+        assert_eq!(position.fields[1].line_number, 8);
+
+        let vars: Vec<&str> = contract
+            .state_variables
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect();
+        assert_eq!(vars, vec!["FEE", "admin", "positions"]);
+        assert!(contract.state_variables[0].is_constant);
+        assert_eq!(
+            contract.state_variables[0].visibility.as_deref(),
+            Some("public")
+        );
+        assert_eq!(contract.state_variables[0].value.as_deref(), Some("30"));
+        assert!(contract.state_variables[1].is_immutable);
+        assert_eq!(contract.state_variables[1].visibility, None);
+        assert_eq!(
+            contract.state_variables[2].type_name,
+            "mapping(address => Position)"
+        );
+
+        assert_eq!(contract.functions.len(), 2);
+        assert_eq!(contract.constructors.len(), 1);
+        assert_eq!(contract.modifiers.len(), 2);
+        assert_eq!(contract.modifiers[0].name, "onlyOwner");
+        assert!(contract.modifiers[0].params.is_empty());
+        assert_eq!(contract.modifiers[1].params[0].name, "role");
+        assert!(contract.modifiers[1].body.as_deref().unwrap().contains("_;"));
+        assert_eq!(contract.constructors[0].params[0].name, "_admin");
+        assert!(contract.constructors[0].body.as_deref().unwrap().contains("admin = _admin"));
+        let deposit = &contract.functions[0];
+        assert_eq!(deposit.name, "deposit");
+        assert_eq!(deposit.visibility.as_deref(), Some("external"));
+        assert_eq!(deposit.state_mutability.as_deref(), Some("payable"));
+        assert_eq!(deposit.modifiers, vec!["onlyOwner"]);
+        assert_eq!(deposit.returns.as_deref(), Some("bool ok"));
+        assert_eq!(deposit.params[1].name, "data");
+        assert_eq!(deposit.params[1].type_name, "bytes");
+        assert!(deposit.body.as_deref().unwrap().contains("\"zero\""));
+        assert_eq!(deposit.line_number, 32);
+
+        let calls: Vec<&str> = contract
+            .function_calls
+            .iter()
+            .map(|c| c.function_name.as_str())
+            .collect();
+        assert_eq!(calls, vec!["require"]);
+
+        let peek = &contract.functions[1];
+        assert!(peek.body.is_none());
+        assert_eq!(peek.state_mutability.as_deref(), Some("view"));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::rule_engine::{RuleViolation, ViolationSeverity};
+use crate::solidity::parser::{SolidityContract, SolidityFunction};
+use crate::solidity::rule_engine::SolidityRule;
+
+/// Rule for detecting `public` Solidity functions that are only called from inside the contract
+///
+/// A `public` function gets an ABI entry and a dispatcher branch. Helpers that
+/// the contract only calls directly are cheaper as `internal` or `private`.
+pub struct PublicOnlyInternalRule;
+
+impl SolidityRule for PublicOnlyInternalRule {
+    fn name(&self) -> &str {
+        "solidity-public-only-internal"
+    }
+
+    fn description(&self) -> &str {
+        "Detects public functions that look like helpers and are only ever called internally."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        contract
+            .functions
+            .iter()
+            .filter(|function| function.visibility.as_deref() == Some("public"))
+            .filter(|function| Self::is_only_called_internally(function, contract))
+            .map(|function| self.create_violation(function))
+            .collect()
+    }
+}
+
+impl PublicOnlyInternalRule {
+    fn is_only_called_internally(function: &SolidityFunction, contract: &SolidityContract) -> bool {
+        // Overrides implement an interface or base contract that fixes their visibility
+        if function.modifiers.iter().any(|m| m.starts_with("override")) {
+            return false;
+        }
+
+        let calls: Vec<_> = contract
+            .function_calls
+            .iter()
+            .filter(|call| call.function_name == function.name && call.caller != function.name)
+            .collect();
+        if calls.is_empty() || calls.iter().any(|call| call.is_this_call) {
+            return false;
+        }
+
+        Self::looks_like_helper_function(&function.name)
+    }
+
+    /// Heuristic to determine if a function looks like a helper/utility function
+    fn looks_like_helper_function(name: &str) -> bool {
+        let helper_patterns = [
+            "helper",
+            "util",
+            "compute",
+            "calculate",
+            "validate",
+            "check",
+        ];
+
+        let lower_name = name.to_lowercase();
+        name.starts_with('_')
+            || helper_patterns
+                .iter()
+                .any(|pattern| lower_name.contains(pattern))
+    }
+
+    fn create_violation(&self, function: &SolidityFunction) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Function '{}' is public but is only called from inside the contract.",
+                function.name
+            ),
+            severity: self.severity(),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            suggestion: format!(
+                "Make '{}' internal (or private) to drop its ABI entry and dispatcher branch.",
+                function.name
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_internally_called_public_helper_is_flagged() {
+        let source = r#"
+contract Pool {
+    function calculateFee(uint256 amount) public pure returns (uint256) {
+        return amount * 3 / 1000;
+    }
+
+    function swap(uint256 amount) external {
+        uint256 fee = calculateFee(amount);
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = PublicOnlyInternalRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "calculateFee");
+    }
+
+    #[test]
+    fn test_external_entry_point_is_not_flagged() {
+        let source = r#"
+contract Pool {
+    function calculateFee(uint256 amount) public pure returns (uint256) {
+        return amount * 3 / 1000;
+    }
+
+    function quote(uint256 amount) public view returns (uint256) {
+        return this.calculateFee(amount);
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = PublicOnlyInternalRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::rule_engine::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::solidity::checked_loop_increment::CheckedLoopIncrementRule;
+use crate::solidity::immutable_candidate::ImmutableCandidateRule;
+use crate::solidity::long_revert_string::LongRevertStringRule;
+use crate::solidity::missing_visibility::MissingVisibilityRule;
+use crate::solidity::parser::SolidityContract;
+use crate::solidity::public_only_internal::PublicOnlyInternalRule;
+use crate::solidity::struct_packing::StructPackingRule;
+use crate::solidity::uint8_vs_uint256::Uint8VsUint256Rule;
+use crate::solidity::unbounded_holder_loop::UnboundedHolderLoopRule;
+
+use std::collections::HashSet;
+
+/// Trait for Solidity-specific rules
+pub trait SolidityRule: Send + Sync {
+    fn name(&self) -> &str;
+    fn description(&self) -> &str;
+    /// Severity the rule reports its findings at
+    fn severity(&self) -> ViolationSeverity;
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Gas
+    }
+    /// Whether `gasguard fix` can apply the suggestion without review
+    fn auto_fixable(&self) -> bool {
+        false
+    }
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation>;
+    /// Count what the rule inspected; most rules look at functions
+    fn coverage(&self, contract: &SolidityContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.functions.len(),
+            uninspected: 0,
+        }
+    }
+}
+
+/// Solidity rule engine for running Solidity-specific rules
+pub struct SolidityRuleEngine {
+    rules: Vec<Box<dyn SolidityRule>>,
+    /// Names of rules skipped by `analyze`
+    disabled: HashSet<String>,
+}
+
+impl SolidityRuleEngine {
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            disabled: HashSet::new(),
+        }
+    }
+
+    pub fn with_default_rules() -> Self {
+        let mut engine = Self::new();
+        engine.add_rule(Box::new(Uint8VsUint256Rule));
+        engine.add_rule(Box::new(StructPackingRule));
+        engine.add_rule(Box::new(LongRevertStringRule));
+        engine.add_rule(Box::new(PublicOnlyInternalRule));
+        engine.add_rule(Box::new(CheckedLoopIncrementRule));
+        engine.add_rule(Box::new(ImmutableCandidateRule));
+        engine.add_rule(Box::new(MissingVisibilityRule));
+        engine.add_rule(Box::new(UnboundedHolderLoopRule));
+        engine
+    }
+
+    pub fn add_rule(&mut self, rule: Box<dyn SolidityRule>) {
+        self.rules.push(rule);
+    }
+
+    pub fn rules(&self) -> impl Iterator<Item = &dyn SolidityRule> {
+        self.rules.iter().map(|r| r.as_ref())
+    }
+
+    /// Enable or disable a rule by name; unknown names are ignored
+    pub fn set_rule_enabled(&mut self, name: &str, enabled: bool) {
+        if enabled {
+            self.disabled.remove(name);
+        } else if self.rules.iter().any(|r| r.name() == name) {
+            self.disabled.insert(name.to_string());
+        }
+    }
+
+    pub fn is_rule_enabled(&self, name: &str) -> bool {
+        !self.disabled.contains(name)
+    }
+
+    pub fn analyze(&self, source: &str) -> Result<Vec<RuleViolation>, String> {
+        let contract = SolidityContract::parse(source)?;
+
+        let mut violations = Vec::new();
+        for rule in self.rules.iter().filter(|r| !self.disabled.contains(r.name())) {
+            violations.extend(rule.check(&contract));
+        }
+
+        Ok(violations)
+    }
+
+    /// Count, per enabled rule, what it inspected in `source`
+    pub fn coverage(&self, source: &str) -> Result<Vec<(String, RuleCoverage)>, String> {
+        let contract = SolidityContract::parse(source)?;
+
+        Ok(self
+            .rules
+            .iter()
+            .filter(|r| !self.disabled.contains(r.name()))
+            .map(|r| (r.name().to_string(), r.coverage(&contract)))
+            .collect())
+    }
+}
+
+impl Default for SolidityRuleEngine {
+    fn default() -> Self {
+        Self::with_default_rules()
+    }
+}
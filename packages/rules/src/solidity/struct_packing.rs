@@ -0,0 +1,221 @@
+use crate::rule_engine::{EstimatedSaving, RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::solidity::parser::{SolidityContract, SolidityStruct};
+use crate::solidity::rule_engine::SolidityRule;
+
+/// EVM storage slot size in bytes
+const SLOT_BYTES: u32 = 32;
+
+/// Rule for detecting struct field orders that use more storage slots than necessary
+///
+/// The EVM packs consecutive fields smaller than 32 bytes into one slot, so
+/// `uint128, uint256, uint128` takes three slots where `uint128, uint128,
+/// uint256` takes two. Every slot saved is one less SSTORE/SLOAD.
+pub struct StructPackingRule;
+
+impl SolidityRule for StructPackingRule {
+    fn name(&self) -> &str {
+        "solidity-struct-packing"
+    }
+
+    fn description(&self) -> &str {
+        "Detects structs whose field order wastes storage slots that a reordering would pack together."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        contract
+            .structs
+            .iter()
+            .filter_map(|definition| {
+                let sizes: Vec<Option<u32>> = definition
+                    .fields
+                    .iter()
+                    .map(|field| Self::packed_size(&field.type_name, contract))
+                    .collect();
+                let current = Self::slot_count(&sizes);
+                let order = Self::optimal_order(&sizes);
+                let ordered: Vec<Option<u32>> = order.iter().map(|&i| sizes[i]).collect();
+                let optimal = Self::slot_count(&ordered);
+
+                (optimal < current)
+                    .then(|| self.create_violation(definition, &order, current, optimal))
+            })
+            .collect()
+    }
+
+    fn coverage(&self, contract: &SolidityContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.structs.len(),
+            uninspected: 0,
+        }
+    }
+}
+
+impl StructPackingRule {
+    /// Bytes a field occupies when packed, or `None` if it always takes whole slots
+    fn packed_size(type_name: &str, contract: &SolidityContract) -> Option<u32> {
+        let bits = |digits: &str| -> Option<u32> {
+            if digits.is_empty() {
+                return Some(SLOT_BYTES);
+            }
+            digits.parse::<u32>().ok().map(|bits| bits / 8)
+        };
+
+        let size = match type_name {
+            "bool" => Some(1),
+            "address" | "address payable" => Some(20),
+            _ if type_name.starts_with("uint") => bits(&type_name[4..]),
+            _ if type_name.starts_with("int") => bits(&type_name[3..]),
+            _ if type_name.starts_with("bytes") && type_name.len() > 5 => {
+                type_name[5..].parse().ok()
+            }
+            // Enums are stored as uint8; contract and interface types as addresses
+            _ if contract.enums.iter().any(|e| e == type_name) => Some(1),
+            _ if contract.contracts.iter().any(|c| c == type_name) => Some(20),
+            _ => None,
+        };
+
+        size.filter(|&bytes| bytes > 0 && bytes < SLOT_BYTES)
+    }
+
+    /// Slots used by fields laid out in the given order
+    fn slot_count(sizes: &[Option<u32>]) -> u32 {
+        let mut slots = 0;
+        // Bytes used in the current slot; a full slot forces the next field onward
+        let mut used = SLOT_BYTES;
+
+        for size in sizes {
+            match size {
+                Some(bytes) if used + bytes <= SLOT_BYTES => used += bytes,
+                Some(bytes) => {
+                    slots += 1;
+                    used = *bytes;
+                }
+                None => {
+                    slots += 1;
+                    used = SLOT_BYTES;
+                }
+            }
+        }
+
+        slots
+    }
+
+    /// Field indices in packing order: whole-slot fields first, then small
+    /// fields grouped into slots by first-fit decreasing
+    fn optimal_order(sizes: &[Option<u32>]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..sizes.len()).filter(|&i| sizes[i].is_none()).collect();
+
+        let mut small: Vec<(usize, u32)> = sizes
+            .iter()
+            .enumerate()
+            .filter_map(|(i, size)| size.map(|bytes| (i, bytes)))
+            .collect();
+        small.sort_by_key(|&(_, bytes)| std::cmp::Reverse(bytes));
+
+        let mut slots: Vec<(u32, Vec<usize>)> = Vec::new();
+        for (index, bytes) in small {
+            match slots
+                .iter_mut()
+                .find(|(used, _)| used + bytes <= SLOT_BYTES)
+            {
+                Some((used, fields)) => {
+                    *used += bytes;
+                    fields.push(index);
+                }
+                None => slots.push((bytes, vec![index])),
+            }
+        }
+        order.extend(slots.into_iter().flat_map(|(_, fields)| fields));
+
+        order
+    }
+
+    fn create_violation(
+        &self,
+        definition: &SolidityStruct,
+        order: &[usize],
+        current: u32,
+        optimal: u32,
+    ) -> RuleViolation {
+        let suggested: Vec<String> = order
+            .iter()
+            .map(|&i| {
+                let field = &definition.fields[i];
+                format!("{} {}", field.type_name, field.name)
+            })
+            .collect();
+
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Struct '{}' uses {} storage slots; reordering its fields would use {}.",
+                definition.name, current, optimal
+            ),
+            severity: self.severity(),
+            line_number: definition.line_number,
+            column_number: 0,
+            variable_name: definition.name.clone(),
+            suggestion: format!("Declare the fields in this order: {}", suggested.join("; ")),
+            estimated_saving: Some(EstimatedSaving::bytes(
+                u64::from(current - optimal) * u64::from(SLOT_BYTES),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poorly_ordered_struct_is_flagged() {
+        let source = r#"
+contract Vault {
+    struct Position {
+        bool active;
+        uint256 shares;
+        address owner;
+        uint256 total;
+        uint64 openedAt;
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = StructPackingRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("uses 5 storage slots"));
+        assert!(violations[0].description.contains("would use 3"));
+        assert_eq!(
+            violations[0].suggestion,
+            "Declare the fields in this order: uint256 shares; uint256 total; address owner; uint64 openedAt; bool active"
+        );
+        assert_eq!(
+            violations[0].estimated_saving,
+            Some(EstimatedSaving::bytes(64))
+        );
+    }
+
+    #[test]
+    fn test_packed_struct_is_not_flagged() {
+        let source = r#"
+contract Vault {
+    struct Position {
+        address owner;
+        uint64 openedAt;
+        bool active;
+        uint256 shares;
+        uint128 amount;
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = StructPackingRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -1,40 +1,122 @@
-use crate::rule_engine::{Rule, RuleResult};
-use crate::solidity::parser::SolidityASTNode;
+use crate::rule_engine::{RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::solidity::parser::SolidityContract;
+use crate::solidity::rule_engine::SolidityRule;
+use crate::soroban::helpers::{line_offset, strip_comments_and_strings};
+use regex::Regex;
 
+/// Rule for detecting `uint8` declarations outside structs
+///
+/// The EVM operates on 32-byte words, so a lone `uint8` is masked on every
+/// read and write. Small types only pay off when packed together in a struct.
 pub struct Uint8VsUint256Rule;
 
-impl Rule for Uint8VsUint256Rule {
-    fn id(&self) -> &'static str {
+impl SolidityRule for Uint8VsUint256Rule {
+    fn name(&self) -> &str {
         "uint8-vs-uint256"
     }
 
-    fn description(&self) -> &'static str {
+    fn description(&self) -> &str {
         "Using uint8 outside structs is often more gas-expensive than uint256 on EVM chains."
     }
 
-    fn analyze(&self, ast: &SolidityASTNode) -> Vec<RuleResult> {
-        let mut results = Vec::new();
-
-        ast.walk(|node, parent| {
-            // Match variable declarations
-            if let SolidityASTNode::VariableDeclaration { type_name, location } = node {
-                // Only uint8
-                if type_name == "uint8" {
-                    // Ignore struct members
-                    if matches!(parent, Some(SolidityASTNode::StructDefinition { .. })) {
-                        return;
-                    }
-
-                    results.push(RuleResult {
-                        rule_id: self.id(),
-                        message: "uint8 used outside a struct. Consider using uint256 for better gas efficiency.".to_string(),
-                        location: location.clone(),
-                        severity: "LOW",
-                    });
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        let local_pattern = Regex::new(r"\buint8\s+(?:memory\s+|storage\s+)?(\w+)\s*[=;]").unwrap();
+        let mut violations = Vec::new();
+
+        for variable in &contract.state_variables {
+            if variable.type_name == "uint8" {
+                violations.push(self.create_violation(&variable.name, variable.line_number));
+            }
+        }
+
+        for function in &contract.functions {
+            for param in &function.params {
+                if param.type_name == "uint8" && !param.name.is_empty() {
+                    violations.push(self.create_violation(&param.name, param.line_number));
                 }
             }
-        });
 
-        results
+            let Some(body) = &function.body else {
+                continue;
+            };
+            let stripped = strip_comments_and_strings(body);
+            for captures in local_pattern.captures_iter(&stripped) {
+                let offset = captures.get(0).map(|m| m.start()).unwrap_or(0);
+                violations.push(self.create_violation(
+                    &captures[1],
+                    function.body_start_line + line_offset(&stripped, offset),
+                ));
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SolidityContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.state_variables.len() + contract.functions.len(),
+            uninspected: 0,
+        }
+    }
+}
+
+impl Uint8VsUint256Rule {
+    fn create_violation(&self, variable_name: &str, line_number: usize) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "uint8 '{}' used outside a struct. Consider using uint256 for better gas efficiency.",
+                variable_name
+            ),
+            severity: self.severity(),
+            line_number,
+            column_number: 0,
+            variable_name: variable_name.to_string(),
+            suggestion: format!(
+                "Declare '{}' as uint256, or pack it with other small fields in a struct.",
+                variable_name
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uint8_state_variable_is_flagged() {
+        let source = r#"
+contract Counter {
+    uint8 count;
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = Uint8VsUint256Rule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "count");
+        assert_eq!(violations[0].line_number, 3);
+    }
+
+    #[test]
+    fn test_uint8_struct_member_is_not_flagged() {
+        let source = r#"
+contract Counter {
+    struct Packed {
+        uint8 a;
+        uint8 b;
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = Uint8VsUint256Rule.check(&contract);
+
+        assert!(violations.is_empty());
     }
 }
@@ -0,0 +1,211 @@
+use crate::rule_engine::{RuleCategory, RuleViolation, ViolationSeverity};
+use crate::solidity::parser::{SolidityContract, SolidityFunction, SolidityStateVariable};
+use crate::solidity::rule_engine::SolidityRule;
+use crate::soroban::helpers::{line_offset, matching_close, strip_comments_and_strings};
+use regex::Regex;
+
+/// Rule for detecting loops over a storage array kept to enumerate a mapping
+///
+/// Contracts often keep `address[] holders` next to `mapping(address => ...)`
+/// and walk the array to visit every key. Anyone who can grow the array can
+/// push the loop past the block gas limit, locking the function for good.
+pub struct UnboundedHolderLoopRule;
+
+impl SolidityRule for UnboundedHolderLoopRule {
+    fn name(&self) -> &str {
+        "solidity-unbounded-holder-loop"
+    }
+
+    fn description(&self) -> &str {
+        "Detects for loops running to the length of a storage array used to iterate a mapping, with no cap on the array's growth."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn check(&self, contract: &SolidityContract) -> Vec<RuleViolation> {
+        let loop_pattern = Regex::new(r"\bfor\s*\(").unwrap();
+        let stripped_source = strip_comments_and_strings(&contract.source);
+        let mut violations = Vec::new();
+
+        for function in &contract.functions {
+            let Some(body) = &function.body else {
+                continue;
+            };
+            let state = |var: &&SolidityStateVariable| {
+                function.contract.as_deref() == Some(var.contract.as_str())
+            };
+            let arrays: Vec<&SolidityStateVariable> = contract
+                .state_variables
+                .iter()
+                .filter(state)
+                .filter(|var| var.type_name.ends_with("[]") && !var.is_constant)
+                .filter(|var| !Self::is_capped(&stripped_source, &var.name))
+                .collect();
+            let mappings: Vec<&SolidityStateVariable> = contract
+                .state_variables
+                .iter()
+                .filter(state)
+                .filter(|var| var.type_name.starts_with("mapping"))
+                .collect();
+            if arrays.is_empty() || mappings.is_empty() {
+                continue;
+            }
+
+            let stripped = strip_comments_and_strings(body);
+            for header in loop_pattern.find_iter(&stripped) {
+                let Some(close) = matching_close(&stripped, header.end() - 1) else {
+                    continue;
+                };
+                let Some(condition) = stripped[header.end()..close].split(';').nth(1) else {
+                    continue;
+                };
+                let loop_body = Self::loop_body(&stripped, close + 1);
+
+                let iterated = arrays.iter().find(|array| {
+                    let length = Regex::new(&format!(r"\b{}\s*\.\s*length\b", array.name)).unwrap();
+                    let element = Regex::new(&format!(r"\b{}\s*\[", array.name)).unwrap();
+                    length.is_match(condition) && element.is_match(loop_body)
+                });
+                let Some(array) = iterated else {
+                    continue;
+                };
+                let Some(mapping) = mappings.iter().find(|mapping| {
+                    Regex::new(&format!(r"\b{}\s*\[", mapping.name))
+                        .unwrap()
+                        .is_match(loop_body)
+                }) else {
+                    continue;
+                };
+
+                violations.push(self.create_violation(
+                    function,
+                    &array.name,
+                    &mapping.name,
+                    function.body_start_line + line_offset(&stripped, header.start()),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+impl UnboundedHolderLoopRule {
+    /// Whether a `require`/`if` anywhere in the contract bounds the array's length
+    fn is_capped(stripped_source: &str, array: &str) -> bool {
+        Regex::new(&format!(
+            r"\b(?:require|if)\s*\([^;{{]*\b{}\s*\.\s*length\s*(?:<|<=)",
+            array
+        ))
+        .unwrap()
+        .is_match(stripped_source)
+    }
+
+    /// Statement or block following a loop header that ends just before `start`
+    fn loop_body(stripped: &str, start: usize) -> &str {
+        let rest = &stripped[start..];
+        let trimmed = rest.trim_start();
+        let open = start + rest.len() - trimmed.len();
+
+        let end = if trimmed.starts_with('{') {
+            matching_close(stripped, open).unwrap_or(stripped.len())
+        } else {
+            trimmed
+                .find(';')
+                .map(|i| open + i)
+                .unwrap_or(stripped.len())
+        };
+        &stripped[open..end]
+    }
+
+    fn create_violation(
+        &self,
+        function: &SolidityFunction,
+        array: &str,
+        mapping: &str,
+        line_number: usize,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Loop in '{}' iterates every entry of storage array '{}' to visit mapping '{}'; the array can grow until the loop exceeds the block gas limit.",
+                function.name, array, mapping
+            ),
+            severity: self.severity(),
+            line_number,
+            column_number: 0,
+            variable_name: array.to_string(),
+            suggestion: format!(
+                "Process '{}' in pages with start/limit arguments, or cap its length where entries are added (e.g. an enumerable set with a maximum size).",
+                array
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_loop_over_holder_array_is_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Dividends {
+    address[] public holders;
+    mapping(address => uint256) public balances;
+
+    function deposit() external payable {
+        holders.push(msg.sender);
+        balances[msg.sender] += msg.value;
+    }
+
+    function distribute(uint256 amount) external {
+        for (uint256 i = 0; i < holders.length; i++) {
+            balances[holders[i]] += amount;
+        }
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = UnboundedHolderLoopRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "holders");
+        assert_eq!(violations[0].line_number, 14);
+    }
+
+    #[test]
+    fn test_paginated_holder_access_is_not_flagged() {
+        let source = r#"
+pragma solidity ^0.8.20;
+
+contract Dividends {
+    address[] public holders;
+    mapping(address => uint256) public balances;
+
+    function distribute(uint256 amount, uint256 start, uint256 limit) external {
+        uint256 end = start + limit;
+        if (end > holders.length) {
+            end = holders.length;
+        }
+        for (uint256 i = start; i < end; i++) {
+            balances[holders[i]] += amount;
+        }
+    }
+}
+"#;
+        let contract = SolidityContract::parse(source).unwrap();
+        let violations = UnboundedHolderLoopRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,263 @@
+//! Address stored as String detection
+//!
+//! Serializing an `Address` with `.to_string()` before writing it to storage
+//! throws away type safety and produces a larger ledger entry than the native
+//! `Address` encoding.
+
+use crate::soroban::helpers::contains_identifier;
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanFunction, SorobanRule,
+};
+use crate::{RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::HashSet;
+
+/// Rule for detecting `Address` values converted to `String` before being stored
+pub struct AddressAsStringRule {
+    enabled: bool,
+}
+
+impl Default for AddressAsStringRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for AddressAsStringRule {
+    fn id(&self) -> &str {
+        "soroban-address-as-string"
+    }
+
+    fn name(&self) -> &str {
+        "Address Stored As String"
+    }
+
+    fn description(&self) -> &str {
+        "Detects Address values converted to String before being written to storage"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        let address_fields: HashSet<&str> = contract
+            .contract_types
+            .iter()
+            .flat_map(|ct| ct.fields.iter())
+            .filter(|f| f.type_name.contains("Address"))
+            .map(|f| f.name.as_str())
+            .collect();
+        let string_fields: HashSet<&str> = contract
+            .contract_types
+            .iter()
+            .flat_map(|ct| ct.fields.iter())
+            .filter(|f| f.type_name == "String")
+            .map(|f| f.name.as_str())
+            .collect();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                let addresses = Self::address_identifiers(function, &address_fields);
+                if addresses.is_empty() {
+                    continue;
+                }
+                violations.extend(self.check_function(function, &addresses, &string_fields));
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl AddressAsStringRule {
+    /// Collect identifiers in scope of the function that hold an `Address`
+    fn address_identifiers(
+        function: &SorobanFunction,
+        address_fields: &HashSet<&str>,
+    ) -> HashSet<String> {
+        let mut addresses: HashSet<String> = function
+            .params
+            .iter()
+            .filter(|p| p.type_name.contains("Address"))
+            .map(|p| p.name.trim_start_matches("mut ").trim().to_string())
+            .collect();
+        addresses.extend(address_fields.iter().map(|f| f.to_string()));
+
+        let typed_local_re = Regex::new(r"let\s+(?:mut\s+)?(\w+)\s*:\s*&?Address\b").unwrap();
+        for captures in typed_local_re.captures_iter(&function.raw_definition) {
+            addresses.insert(captures[1].to_string());
+        }
+
+        addresses
+    }
+
+    fn check_function(
+        &self,
+        function: &SorobanFunction,
+        addresses: &HashSet<String>,
+        string_fields: &HashSet<&str>,
+    ) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+        let to_string_re = Regex::new(r"(?:self\.)?(\w+)\.to_string\(\)").unwrap();
+        let local_re = Regex::new(
+            r"let\s+(?:mut\s+)?(\w+)\s*(?::\s*String\s*)?=\s*(?:self\.)?(\w+)\.to_string\(\)",
+        )
+        .unwrap();
+        // A field initializer opens its line or follows `{`/`,` in a one-line struct literal
+        let field_init_re =
+            Regex::new(r"(?:^|[{,])\s*(\w+)\s*:\s*(?:self\.)?(\w+)\.to_string\(\)").unwrap();
+
+        // Locals holding a stringified address, mapped to the address they came from
+        let mut stringified: Vec<(String, String)> = Vec::new();
+
+        for (offset, line) in function.raw_definition.lines().enumerate() {
+            if let Some(captures) = local_re.captures(line) {
+                if addresses.contains(&captures[2]) {
+                    stringified.push((captures[1].to_string(), captures[2].to_string()));
+                }
+                continue;
+            }
+
+            // Contract struct fields of type String initialized from an address
+            if let Some(captures) = field_init_re.captures(line) {
+                if string_fields.contains(&captures[1]) && addresses.contains(&captures[2]) {
+                    violations.push(self.violation(function, &captures[2], offset));
+                    continue;
+                }
+            }
+
+            if !line.contains(".set(") {
+                continue;
+            }
+
+            let direct = to_string_re
+                .captures_iter(line)
+                .map(|c| c[1].to_string())
+                .find(|name| addresses.contains(name));
+            let via_local = stringified
+                .iter()
+                .find(|(local, _)| contains_identifier(line, local));
+
+            if let Some(address) = direct.or_else(|| via_local.map(|(_, address)| address.clone()))
+            {
+                violations.push(self.violation(function, &address, offset));
+            }
+        }
+
+        violations
+    }
+
+    fn violation(&self, function: &SorobanFunction, address: &str, offset: usize) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' stores Address '{}' as a String, losing type safety and increasing storage size",
+                function.name, address
+            ),
+            suggestion: format!("Store '{}' directly as an Address instead of calling .to_string()", address),
+            line_number: function.line_number + offset,
+            column_number: 0,
+            variable_name: address.to_string(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_flags_stringified_address_written_to_storage() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+#[contractimpl]
+impl Registry {
+    pub fn set_admin(env: Env, admin: Address) {
+        let admin_str = admin.to_string();
+        env.storage().instance().set(&Symbol::short("admin"), &admin_str);
+    }
+
+    pub fn set_owner(env: Env, owner: Address) {
+        env.storage().instance().set(&Symbol::short("owner"), &owner.to_string());
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = AddressAsStringRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].variable_name, "admin");
+        assert_eq!(violations[1].variable_name, "owner");
+    }
+
+    #[test]
+    fn test_address_stored_directly_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+#[contractimpl]
+impl Registry {
+    pub fn set_admin(env: Env, admin: Address) {
+        env.storage().instance().set(&Symbol::short("admin"), &admin);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = AddressAsStringRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_string_field_initialized_from_address_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contracttype, contractimpl, Address, Env, String};
+
+#[contracttype]
+pub struct Registry {
+    pub admin: Address,
+    pub admin_label: String,
+}
+
+#[contractimpl]
+impl Registry {
+    pub fn new(env: Env, admin: Address) -> Self {
+        Self {
+            admin_label: admin.to_string(),
+            admin,
+        }
+    }
+
+    pub fn inline(env: Env, admin: Address) -> Self {
+        Self { admin_label: admin.to_string(), admin }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = AddressAsStringRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].variable_name, "admin");
+        assert_eq!(violations[0].line_number, 14);
+        assert_eq!(violations[1].line_number, 20);
+    }
+}
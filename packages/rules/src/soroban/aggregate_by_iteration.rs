@@ -0,0 +1,206 @@
+//! Aggregate-by-iteration detection
+//!
+//! Summing or counting a storage-backed `Map`/`Vec` by walking every entry
+//! costs one read per element, so the call grows with the data until it no
+//! longer fits the budget. Keeping the aggregate in its own entry, updated
+//! on every write, makes the read constant-cost.
+
+use crate::soroban::helpers::{
+    block_open, function_body, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanFunction, SorobanRule,
+};
+use crate::{RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting loops over a storage-backed collection that accumulate a total
+pub struct AggregateByIterationRule {
+    enabled: bool,
+}
+
+impl Default for AggregateByIterationRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for AggregateByIterationRule {
+    fn id(&self) -> &str {
+        "soroban-aggregate-by-iteration"
+    }
+
+    fn name(&self) -> &str {
+        "Aggregate by Iteration"
+    }
+
+    fn description(&self) -> &str {
+        "Detects functions that iterate a whole storage-backed Map/Vec to accumulate a sum or count"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let collection_fields: Vec<&str> = contract
+            .contract_types
+            .iter()
+            .flat_map(|t| t.fields.iter())
+            .filter(|f| f.type_name.starts_with("Vec<") || f.type_name.starts_with("Map<"))
+            .map(|f| f.name.as_str())
+            .collect();
+
+        let mut violations = Vec::new();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function, &collection_fields));
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl AggregateByIterationRule {
+    fn check_function(
+        &self,
+        function: &SorobanFunction,
+        collection_fields: &[&str],
+    ) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let read_re = Regex::new(
+            r"\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]*)?=[^;]*\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*get\b",
+        )
+        .unwrap();
+        let loop_re = Regex::new(
+            r"\bfor\b[^{;]*?\bin\s+&?\s*([\w.]+?)\s*\.\s*(?:iter|values|keys|into_iter)\s*\(\s*\)",
+        )
+        .unwrap();
+        let accumulate_re = Regex::new(
+            r"\b(\w+)\s*(?:\+=|=\s*\w+\s*(?:\+|\.\s*(?:checked_add|saturating_add)\s*\())",
+        )
+        .unwrap();
+
+        let stored: Vec<&str> = read_re
+            .captures_iter(&stripped)
+            .filter_map(|c| c.get(1).map(|m| m.as_str()))
+            .collect();
+
+        let mut violations = Vec::new();
+        for header in loop_re.captures_iter(&stripped) {
+            let (Some(whole), Some(receiver)) = (header.get(0), header.get(1)) else {
+                continue;
+            };
+            let receiver = receiver.as_str();
+            let is_stored = stored.contains(&receiver)
+                || receiver
+                    .rsplit_once('.')
+                    .is_some_and(|(_, field)| collection_fields.contains(&field));
+            if !is_stored {
+                continue;
+            }
+
+            let Some(open) = block_open(&stripped, whole.end()) else {
+                continue;
+            };
+            let Some(close) = matching_close(&stripped, open) else {
+                continue;
+            };
+            let Some(total) = accumulate_re
+                .captures(&stripped[open + 1..close])
+                .and_then(|c| c.get(1))
+            else {
+                continue;
+            };
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' iterates every entry of storage-backed '{}' to accumulate '{}'",
+                    function.name,
+                    receiver,
+                    total.as_str()
+                ),
+                suggestion: format!(
+                    "Store '{}' in its own entry and update it whenever '{}' changes, so reading it costs one storage access",
+                    total.as_str(),
+                    receiver
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + whole.start()),
+                column_number: 0,
+                variable_name: receiver.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_sum_over_stored_map_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Map};
+
+#[contractimpl]
+impl Token {
+    pub fn total_supply(env: Env) -> i128 {
+        let balances: Map<Address, i128> = env.storage().persistent().get(&DataKey::Balances).unwrap();
+        let mut total = 0;
+        for (_, balance) in balances.iter() {
+            total += balance;
+        }
+        total
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = AggregateByIterationRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "balances");
+        assert!(violations[0].description.contains("'total'"));
+    }
+
+    #[test]
+    fn test_cached_total_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn total_supply(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = AggregateByIterationRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
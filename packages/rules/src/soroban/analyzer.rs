@@ -4,12 +4,56 @@
 //! detecting gas optimization opportunities, security issues, and best practices.
 
 use super::*;
-use crate::{RuleViolation, ViolationSeverity};
+use crate::cost_model::type_size_bytes;
+use crate::soroban::helpers::identifier_count;
+use crate::{EstimatedSaving, RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
 
 /// Analyzes Soroban contracts for various issues
 pub struct SorobanAnalyzer;
 
 impl SorobanAnalyzer {
+    /// Rule name, category, description and severity of every check `analyze_contract` can report
+    pub const CHECKS: [(&'static str, RuleCategory, &'static str, ViolationSeverity); 13] = [
+        ("missing-constructor", RuleCategory::Correctness, "Contract types without a constructor function", ViolationSeverity::Warning),
+        ("missing-admin-pattern", RuleCategory::Security, "Contracts with no admin or owner field for access control", ViolationSeverity::Info),
+        ("unused-state-variable", RuleCategory::Gas, "Contract type fields never referenced by the contract", ViolationSeverity::Warning),
+        ("inefficient-integer-type", RuleCategory::Gas, "Integer fields wider than their values need", ViolationSeverity::Info),
+        ("string-instead-of-symbol", RuleCategory::Gas, "String fields that could be Symbols", ViolationSeverity::Info),
+        ("private-contract-field", RuleCategory::Style, "Contract type fields without pub visibility", ViolationSeverity::Warning),
+        ("expensive-string-operation", RuleCategory::Gas, "String allocations such as to_string() inside contract functions", ViolationSeverity::Medium),
+        ("vec-without-capacity", RuleCategory::Gas, "Vectors created without a preallocated capacity", ViolationSeverity::Medium),
+        ("unnecessary-clone", RuleCategory::Gas, "clone() calls that may be avoidable", ViolationSeverity::Medium),
+        ("missing-address-validation", RuleCategory::Security, "Address parameters used without validation", ViolationSeverity::Medium),
+        ("missing-error-handling", RuleCategory::Correctness, "Public functions that return no Result for their failure cases", ViolationSeverity::Medium),
+        ("unbounded-loop", RuleCategory::Gas, "Loops without a fixed bound that can exhaust the budget", ViolationSeverity::High),
+        ("inefficient-storage-access", RuleCategory::Gas, "Repeated storage reads that could be cached in a local", ViolationSeverity::Medium),
+    ];
+
+    /// Checks that look at contract types; the rest look at impl functions
+    const TYPE_CHECKS: [&'static str; 6] = [
+        "missing-constructor",
+        "missing-admin-pattern",
+        "unused-state-variable",
+        "inefficient-integer-type",
+        "string-instead-of-symbol",
+        "private-contract-field",
+    ];
+
+    /// Count, per check, the contract types or functions it inspected
+    pub fn coverage(contract: &SorobanContract) -> Vec<(String, RuleCoverage)> {
+        Self::CHECKS
+            .iter()
+            .map(|(name, ..)| {
+                let coverage = if Self::TYPE_CHECKS.contains(name) {
+                    type_coverage(contract)
+                } else {
+                    function_coverage(contract)
+                };
+                (name.to_string(), coverage)
+            })
+            .collect()
+    }
+    
     /// Analyze a parsed Soroban contract
     pub fn analyze_contract(contract: &SorobanContract) -> Vec<RuleViolation> {
         let mut violations = Vec::new();
@@ -95,6 +139,7 @@ impl SorobanAnalyzer {
                 column_number: 0,
                 variable_name: contract.name.clone(),
                 severity: ViolationSeverity::Warning,
+                estimated_saving: None,
             });
         }
         
@@ -116,6 +161,7 @@ impl SorobanAnalyzer {
                 column_number: 0,
                 variable_name: contract.name.clone(),
                 severity: ViolationSeverity::Info,
+                estimated_saving: None,
             });
         }
         
@@ -127,12 +173,12 @@ impl SorobanAnalyzer {
         let mut violations = Vec::new();
         
         for field in &contract_type.fields {
-            // Count occurrences of field name in the source (excluding struct definition)
-            let field_usage_count = source.matches(&field.name).count();
+            // Count the field name as an identifier, excluding the struct definition
+            let field_usage_count = identifier_count(source, &field.name)
+                .saturating_sub(identifier_count(&contract_type.raw_definition, &field.name));
             
-            // Heuristic: Definition + Initialization = 2 occurrences.
-            // If it's <= 2, it's likely defined and initialized but never accessed again.
-            if field_usage_count <= 2 {
+            // A field that is initialized but never accessed again appears once
+            if field_usage_count <= 1 {
                 violations.push(RuleViolation {
                     rule_name: "unused-state-variable".to_string(),
                     description: format!("State variable '{}' appears to be unused", field.name),
@@ -141,6 +187,7 @@ impl SorobanAnalyzer {
                     column_number: 0,
                     variable_name: field.name.clone(),
                     severity: ViolationSeverity::Warning,
+                    estimated_saving: Some(EstimatedSaving::bytes(type_size_bytes(&field.type_name))),
                 });
             }
         }
@@ -163,6 +210,7 @@ impl SorobanAnalyzer {
                     column_number: 0,
                     variable_name: field.name.clone(),
                     severity: ViolationSeverity::Info,
+                    estimated_saving: None,
                 });
             }
             
@@ -176,6 +224,7 @@ impl SorobanAnalyzer {
                     column_number: 0,
                     variable_name: field.name.clone(),
                     severity: ViolationSeverity::Info,
+                    estimated_saving: None,
                 });
             }
         }
@@ -197,6 +246,7 @@ impl SorobanAnalyzer {
                     column_number: 0,
                     variable_name: field.name.clone(),
                     severity: ViolationSeverity::Warning,
+                    estimated_saving: None,
                 });
             }
         }
@@ -219,6 +269,7 @@ impl SorobanAnalyzer {
                 column_number: 0,
                 variable_name: function.name.clone(),
                 severity: ViolationSeverity::Medium,
+                estimated_saving: None,
             });
         }
         
@@ -232,6 +283,7 @@ impl SorobanAnalyzer {
                 column_number: 0,
                 variable_name: function.name.clone(),
                 severity: ViolationSeverity::Medium,
+                estimated_saving: None,
             });
         }
         
@@ -245,6 +297,7 @@ impl SorobanAnalyzer {
                 column_number: 0,
                 variable_name: function.name.clone(),
                 severity: ViolationSeverity::Medium,
+                estimated_saving: None,
             });
         }
         
@@ -268,6 +321,7 @@ impl SorobanAnalyzer {
                         column_number: 0,
                         variable_name: function.name.clone(),
                         severity: ViolationSeverity::Medium,
+                        estimated_saving: None,
                     });
                 }
             }
@@ -281,21 +335,21 @@ impl SorobanAnalyzer {
         let mut violations = Vec::new();
         
         // Check for functions that should return Result but don't
-        if function.name.contains("transfer") || 
-           function.name.contains("mint") || 
-           function.name.contains("burn") {
-            if function.return_type.is_none() || 
-               !function.return_type.as_ref().unwrap().contains("Result") {
-                violations.push(RuleViolation {
-                    rule_name: "missing-error-handling".to_string(),
-                    description: format!("Function '{}' should return Result for error handling", function.name),
-                    suggestion: "Return Result<(), Error> to properly handle operation failures".to_string(),
-                    line_number: function.line_number,
-                    column_number: 0,
-                    variable_name: function.name.clone(),
-                    severity: ViolationSeverity::Medium,
-                });
-            }
+        if (function.name.contains("transfer") || 
+            function.name.contains("mint") || 
+            function.name.contains("burn")) &&
+           (function.return_type.is_none() || 
+            !function.return_type.as_ref().unwrap().contains("Result")) {
+            violations.push(RuleViolation {
+                rule_name: "missing-error-handling".to_string(),
+                description: format!("Function '{}' should return Result for error handling", function.name),
+                suggestion: "Return Result<(), Error> to properly handle operation failures".to_string(),
+                line_number: function.line_number,
+                column_number: 0,
+                variable_name: function.name.clone(),
+                severity: ViolationSeverity::Medium,
+                estimated_saving: None,
+            });
         }
         
         violations
@@ -320,6 +374,7 @@ impl SorobanAnalyzer {
                     column_number: 0,
                     variable_name: function.name.clone(),
                     severity: ViolationSeverity::High,
+                    estimated_saving: None,
                 });
             }
         }
@@ -356,6 +411,7 @@ impl SorobanAnalyzer {
                 column_number: 0,
                 variable_name: function.name.clone(),
                 severity: ViolationSeverity::Medium,
+                estimated_saving: None,
             });
         }
         
@@ -0,0 +1,281 @@
+//! Asymmetric conditional write detection
+//!
+//! When the branches of an `if`/`match` each update contract storage but only
+//! some of them write a particular key, the other paths leave that key at its
+//! old value. In migration and upgrade code this is a common source of
+//! half-applied state.
+
+use crate::soroban::helpers::{
+    block_open, function_body, line_offset, matching_close, split_top_level,
+    strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeSet;
+
+/// Rule for detecting storage keys written in only some branches of a conditional
+pub struct AsymmetricConditionalWriteRule {
+    enabled: bool,
+}
+
+impl Default for AsymmetricConditionalWriteRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for AsymmetricConditionalWriteRule {
+    fn id(&self) -> &str {
+        "soroban-asymmetric-conditional-write"
+    }
+
+    fn name(&self) -> &str {
+        "Asymmetric Conditional Write"
+    }
+
+    fn description(&self) -> &str {
+        "Detects storage keys written in only some branches of an if/match whose other branches also write storage"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+/// Byte range of a branch within the stripped body
+type Branch = (usize, usize);
+
+impl AsymmetricConditionalWriteRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let conditional_re = Regex::new(r"\b(if|match)\b").unwrap();
+        let else_re = Regex::new(r"\belse\s*$").unwrap();
+
+        let mut violations = Vec::new();
+        for m in conditional_re.find_iter(&stripped) {
+            let branches = if m.as_str() == "if" {
+                // `else if` arms belong to the chain started by the first `if`
+                if else_re.is_match(&stripped[..m.start()]) {
+                    continue;
+                }
+                Self::if_branches(&stripped, m.end())
+            } else {
+                Self::match_arms(&stripped, m.end())
+            };
+
+            let writes: Vec<BTreeSet<String>> = branches
+                .iter()
+                .map(|&(start, end)| Self::written_keys(body, &stripped, start, end))
+                .filter(|keys| !keys.is_empty())
+                .collect();
+            if writes.len() < 2 {
+                continue;
+            }
+
+            let all_keys: BTreeSet<&String> = writes.iter().flatten().collect();
+            for key in all_keys {
+                let written_in = writes.iter().filter(|keys| keys.contains(key)).count();
+                if written_in == writes.len() {
+                    continue;
+                }
+                violations.push(RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Function '{}' writes storage key '{}' in {} of {} branches of this {} that write storage",
+                        function.name,
+                        key,
+                        written_in,
+                        writes.len(),
+                        m.as_str()
+                    ),
+                    suggestion: format!(
+                        "Write '{}' in every branch, or move the write out of the {} if it applies to all paths",
+                        key,
+                        m.as_str()
+                    ),
+                    line_number: function.line_number
+                        + line_offset(&function.raw_definition, body_start + m.start()),
+                    column_number: 0,
+                    variable_name: key.clone(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                });
+            }
+        }
+
+        violations
+    }
+
+    /// Blocks of an `if` / `else if` / `else` chain whose condition starts at `start`
+    fn if_branches(stripped: &str, start: usize) -> Vec<Branch> {
+        let mut branches = Vec::new();
+        let mut idx = start;
+
+        while let Some(open) = block_open(stripped, idx) {
+            let Some(close) = matching_close(stripped, open) else {
+                break;
+            };
+            branches.push((open + 1, close));
+
+            let rest = stripped[close + 1..].trim_start();
+            let Some(after_else) = rest.strip_prefix("else") else {
+                break;
+            };
+            idx = stripped.len() - after_else.len();
+            let after_else = after_else.trim_start();
+            if let Some(condition) = after_else.strip_prefix("if") {
+                idx = stripped.len() - condition.len();
+            }
+        }
+
+        branches
+    }
+
+    /// Arm bodies of a `match` whose scrutinee starts at `start`
+    fn match_arms(stripped: &str, start: usize) -> Vec<Branch> {
+        let Some(open) = block_open(stripped, start) else {
+            return Vec::new();
+        };
+        let Some(close) = matching_close(stripped, open) else {
+            return Vec::new();
+        };
+
+        let mut arms = Vec::new();
+        let mut idx = open + 1;
+        while let Some(arrow) = stripped[idx..close].find("=>").map(|i| idx + i) {
+            let expr_start = arrow + 2;
+            let trimmed = stripped[expr_start..close].trim_start();
+            let expr_start = close - trimmed.len();
+
+            let expr_end = if trimmed.starts_with('{') {
+                matching_close(stripped, expr_start)
+                    .map(|c| c + 1)
+                    .unwrap_or(close)
+            } else {
+                let first = split_top_level(&stripped[expr_start..close])
+                    .first()
+                    .map(|arm| arm.len())
+                    .unwrap_or(0);
+                expr_start + first
+            };
+            arms.push((expr_start, expr_end));
+
+            idx = expr_end;
+            if idx >= close {
+                break;
+            }
+        }
+
+        arms
+    }
+
+    /// Normalized first argument of every storage `.set(` between `start` and `end`
+    fn written_keys(body: &str, stripped: &str, start: usize, end: usize) -> BTreeSet<String> {
+        let set_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*set(?:::<[^>]*>)?\(",
+        )
+        .unwrap();
+
+        set_re
+            .find_iter(&stripped[start..end])
+            .filter_map(|call| {
+                let open = start + call.end() - 1;
+                let close = matching_close(stripped, open)?;
+                let key = split_top_level(&body[open + 1..close]).first().copied()?;
+                Some(
+                    key.trim_start_matches('&')
+                        .chars()
+                        .filter(|c| !c.is_whitespace())
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_key_written_in_one_branch_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Upgrade {
+    pub fn migrate(env: Env, full: bool, data: u32) {
+        if full {
+            env.storage().instance().set(&DataKey::Version, &2u32);
+            env.storage().instance().set(&DataKey::Data, &data);
+        } else {
+            env.storage().instance().set(&DataKey::Data, &0u32);
+        }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = AsymmetricConditionalWriteRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "DataKey::Version");
+        assert!(violations[0].description.contains("1 of 2 branches"));
+    }
+
+    #[test]
+    fn test_symmetric_writes_are_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Upgrade {
+    pub fn migrate(env: Env, full: bool, data: u32) {
+        match full {
+            true => {
+                env.storage().instance().set(&DataKey::Version, &2u32);
+                env.storage().instance().set(&DataKey::Data, &data);
+            }
+            false => {
+                env.storage().instance().set(&DataKey::Version, &1u32);
+                env.storage().instance().set(&DataKey::Data, &0u32);
+            }
+        }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = AsymmetricConditionalWriteRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,269 @@
+//! Blind composite write detection
+//!
+//! Writing a whole struct to a storage key without reading the stored value
+//! first replaces every field. Fields the function did not receive from its
+//! caller are reset to whatever the literal says, clobbering anything other
+//! functions stored there. Reading the entry and changing only the intended
+//! fields avoids it.
+
+use crate::soroban::helpers::{
+    block_open, contains_identifier, function_body, line_offset, matching_close, split_top_level,
+    strip_comments_and_strings,
+};
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanFunction, SorobanRule, SorobanStruct,
+};
+use crate::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting struct literals written to storage without reading the key first
+pub struct BlindStructWriteRule {
+    enabled: bool,
+}
+
+impl Default for BlindStructWriteRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for BlindStructWriteRule {
+    fn id(&self) -> &str {
+        "soroban-blind-struct-write"
+    }
+
+    fn name(&self) -> &str {
+        "Blind Struct Write"
+    }
+
+    fn description(&self) -> &str {
+        "Detects struct literals written to a storage key that is never read, whose fields are not all supplied by parameters"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        if contract.contract_types.is_empty() {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function, &contract.contract_types));
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl BlindStructWriteRule {
+    fn check_function(
+        &self,
+        function: &SorobanFunction,
+        types: &[SorobanStruct],
+    ) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let call_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*(get|set)(?:::<[^>]*>)?\(",
+        )
+        .unwrap();
+
+        let mut reads = Vec::new();
+        let mut writes = Vec::new();
+        for call in call_re.captures_iter(&stripped) {
+            let whole = call.get(0).unwrap();
+            let open = whole.end() - 1;
+            let Some(close) = matching_close(&stripped, open) else {
+                continue;
+            };
+            let args = split_top_level(&stripped[open + 1..close]);
+            let Some(key) = args.first().map(|k| Self::normalize(k)) else {
+                continue;
+            };
+            match &call[1] {
+                "get" => reads.push(key),
+                _ => {
+                    if let Some(value) = args.get(1) {
+                        writes.push((whole.start(), key, value.trim_start_matches('&').trim()));
+                    }
+                }
+            }
+        }
+
+        let params: Vec<&str> = function.params.iter().map(|p| p.name.as_str()).collect();
+        let mut violations = Vec::new();
+        for (offset, key, value) in writes {
+            if reads.contains(&key) {
+                continue;
+            }
+            let Some((ty, fields)) = Self::struct_literal(&stripped, value, types) else {
+                continue;
+            };
+            // Fields whose value comes from neither a parameter nor anything read
+            let reset: Vec<&str> = fields
+                .iter()
+                .filter(|(_, init)| !params.iter().any(|p| contains_identifier(init, p)))
+                .map(|(name, _)| *name)
+                .collect();
+            if reset.is_empty() {
+                continue;
+            }
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' overwrites storage key '{}' with a new '{}' without reading it, resetting field(s) {}",
+                    function.name,
+                    key,
+                    ty,
+                    reset.join(", ")
+                ),
+                suggestion: format!(
+                    "Read the stored '{}' under '{}', update only the fields this function owns, and write it back",
+                    ty, key
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + offset),
+                column_number: 0,
+                variable_name: key,
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Type name and `(field, initializer)` pairs of the struct literal `value` is, or is bound to
+    fn struct_literal<'a>(
+        stripped: &'a str,
+        value: &'a str,
+        types: &'a [SorobanStruct],
+    ) -> Option<(&'a str, Vec<(&'a str, &'a str)>)> {
+        let literal_re = Regex::new(r"^([A-Z]\w*)\s*\{").unwrap();
+        let literal = if literal_re.is_match(value) {
+            value
+        } else {
+            let binding_re = Regex::new(&format!(
+                r"\blet\s+(?:mut\s+)?{}\s*(?::[^=;]*)?=\s*([A-Z]\w*\s*\{{)",
+                regex::escape(value)
+            ))
+            .ok()?;
+            let start = binding_re.captures(stripped)?.get(1)?.start();
+            let open = block_open(stripped, start)?;
+            &stripped[start..=matching_close(stripped, open)?]
+        };
+
+        let name = literal_re.captures(literal)?.get(1)?.as_str();
+        let ty = types.iter().find(|t| t.name == name)?;
+        let open = literal.find('{')?;
+        let close = matching_close(literal, open)?;
+        let fields = split_top_level(&literal[open + 1..close])
+            .into_iter()
+            .filter(|field| !field.starts_with(".."))
+            .map(|field| match field.split_once(':') {
+                Some((name, init)) => (name.trim(), init.trim()),
+                // Shorthand `field` initializes from a binding of the same name
+                None => (field, field),
+            })
+            .collect();
+
+        Some((ty.name.as_str(), fields))
+    }
+
+    fn normalize(key: &str) -> String {
+        key.trim_start_matches('&')
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    const TYPES: &str = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+pub struct Profile {
+    pub name: u32,
+    pub score: u32,
+    pub banned: bool,
+}
+"#;
+
+    #[test]
+    fn test_blind_overwrite_is_flagged() {
+        let source = format!(
+            "{}{}",
+            TYPES,
+            r#"
+#[contractimpl]
+impl Registry {
+    pub fn set_name(env: Env, user: Address, name: u32) {
+        user.require_auth();
+        let profile = Profile { name, score: 0, banned: false };
+        env.storage().persistent().set(&DataKey::Profile(user), &profile);
+    }
+}
+"#
+        );
+        let contract = SorobanParser::parse_contract(&source, "test.rs").unwrap();
+        let violations = BlindStructWriteRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "DataKey::Profile(user)");
+        assert!(violations[0].description.ends_with("score, banned"));
+    }
+
+    #[test]
+    fn test_read_merge_write_is_not_flagged() {
+        let source = format!(
+            "{}{}",
+            TYPES,
+            r#"
+#[contractimpl]
+impl Registry {
+    pub fn set_name(env: Env, user: Address, name: u32) {
+        user.require_auth();
+        let key = DataKey::Profile(user);
+        let mut profile: Profile = env.storage().persistent().get(&key).unwrap();
+        profile.name = name;
+        env.storage().persistent().set(&key, &profile);
+    }
+}
+"#
+        );
+        let contract = SorobanParser::parse_contract(&source, "test.rs").unwrap();
+        let violations = BlindStructWriteRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,245 @@
+//! Checks-effects-interactions ordering detection
+//!
+//! A cross-contract call made while the caller still has pending storage
+//! writes lets the callee observe (and re-enter against) stale state.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting cross-contract calls made before the function's own storage writes
+pub struct CallBeforeStateUpdateRule {
+    enabled: bool,
+}
+
+impl Default for CallBeforeStateUpdateRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for CallBeforeStateUpdateRule {
+    fn id(&self) -> &str {
+        "soroban-call-before-state-update"
+    }
+
+    fn name(&self) -> &str {
+        "Cross-Contract Call Before State Update"
+    }
+
+    fn description(&self) -> &str {
+        "Detects cross-contract calls made before the function finishes writing its own storage (checks-effects-interactions)"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                if let Some(violation) = self.check_function(function) {
+                    violations.push(violation);
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl CallBeforeStateUpdateRule {
+    fn check_function(&self, function: &SorobanFunction) -> Option<RuleViolation> {
+        let body = function_body(&function.raw_definition)?;
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+
+        // Only writes on a later line count, so `set(&key, &client.get())` is not reported
+        let call = Self::first_external_call(&stripped)?;
+        let next_line = call + stripped[call..].find('\n')?;
+        let write = next_line
+            + Regex::new(r"\.(?:set|remove)\(")
+                .unwrap()
+                .find(&stripped[next_line..])?
+                .start();
+
+        let call_offset = line_offset(&function.raw_definition, body_start + call);
+        let write_offset = line_offset(&function.raw_definition, body_start + write);
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' makes a cross-contract call (line {}) before writing storage (line {})",
+                function.name,
+                function.line_number + call_offset,
+                function.line_number + write_offset
+            ),
+            suggestion: "Update contract state before invoking other contracts (checks-effects-interactions) to avoid reentrancy-like issues".to_string(),
+            line_number: function.line_number + call_offset,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+
+    /// Offset of the first `invoke_contract` or method call on a generated client
+    ///
+    /// Building a client with `XClient::new(..)` does not call the other
+    /// contract; calling a method on it, bound or chained, does.
+    fn first_external_call(stripped: &str) -> Option<usize> {
+        let invoke_re = Regex::new(r"\b(?:try_)?invoke_contract\b").unwrap();
+        let client_re = Regex::new(
+            r"(?:\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]*)?=\s*)?\b(?:\w+::)*\w*Client::new\(",
+        )
+        .unwrap();
+
+        let mut calls: Vec<usize> = invoke_re.find_iter(stripped).map(|m| m.start()).collect();
+        let mut clients = Vec::new();
+        for client in client_re.captures_iter(stripped) {
+            let open = client.get(0).unwrap().end() - 1;
+            if let Some(close) = matching_close(stripped, open) {
+                let rest = &stripped[close + 1..];
+                if rest.trim_start().starts_with('.') {
+                    calls.push(close + 1 + rest.len() - rest.trim_start().len());
+                }
+            }
+            if let Some(name) = client.get(1) {
+                clients.push(name.as_str());
+            }
+        }
+
+        if !clients.is_empty() {
+            let method_re = Regex::new(&format!(
+                r"\b(?:{})\s*\.\s*\w+\s*(?:::<[^>]*>)?\(",
+                clients.join("|")
+            ))
+            .unwrap();
+            calls.extend(method_re.find_iter(stripped).map(|m| m.start()));
+        }
+
+        calls.into_iter().min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_call_then_write_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) {
+        let balance: i128 = env.storage().persistent().get(&to).unwrap_or(0);
+        env.invoke_contract::<()>(&token, &Symbol::new(&env, "transfer"), (to.clone(), amount).into_val(&env));
+        env.storage().persistent().set(&to, &(balance - amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = CallBeforeStateUpdateRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "withdraw");
+        assert!(matches!(violations[0].severity, ViolationSeverity::High));
+    }
+
+    #[test]
+    fn test_write_then_call_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) {
+        let balance: i128 = env.storage().persistent().get(&to).unwrap_or(0);
+        env.storage().persistent().set(&to, &(balance - amount));
+        env.invoke_contract::<()>(&token, &Symbol::new(&env, "transfer"), (to.clone(), amount).into_val(&env));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = CallBeforeStateUpdateRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_commented_out_call_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) {
+        let balance: i128 = env.storage().persistent().get(&to).unwrap_or(0);
+        // env.invoke_contract::<()>(&token, &Symbol::new(&env, "transfer"), (to.clone(), amount).into_val(&env));
+        env.storage().persistent().set(&to, &(balance - amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = CallBeforeStateUpdateRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_client_method_calls_are_flagged_not_their_construction() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, token, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) {
+        let client = token::Client::new(&env, &token);
+        let balance: i128 = env.storage().persistent().get(&to).unwrap_or(0);
+        client.transfer(&env.current_contract_address(), &to, &amount);
+        env.storage().persistent().set(&to, &(balance - amount));
+    }
+
+    pub fn prepare(env: Env, token: Address, to: Address) {
+        let client = token::Client::new(&env, &token);
+        env.storage().persistent().set(&to, &token);
+        client.transfer(&env.current_contract_address(), &to, &1);
+    }
+
+    pub fn pay(env: Env, token: Address, to: Address) {
+        token::Client::new(&env, &token)
+            .transfer(&env.current_contract_address(), &to, &1);
+        env.storage().persistent().remove(&to);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = CallBeforeStateUpdateRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].variable_name, "withdraw");
+        assert_eq!(violations[0].line_number, 9);
+        assert_eq!(violations[1].variable_name, "pay");
+        assert_eq!(violations[1].line_number, 21);
+    }
+}
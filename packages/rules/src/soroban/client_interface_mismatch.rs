@@ -0,0 +1,221 @@
+//! Contract client interface consistency checks
+//!
+//! A `#[contractclient]` trait describes how other contracts call this one.
+//! When it drifts from the `#[contractimpl]` functions, generated clients
+//! encode calls the contract cannot decode.
+
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanInterface, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use std::collections::HashMap;
+
+/// Rule for detecting client interface methods that collide or disagree with the implementation
+pub struct ClientInterfaceMismatchRule {
+    enabled: bool,
+}
+
+impl Default for ClientInterfaceMismatchRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for ClientInterfaceMismatchRule {
+    fn id(&self) -> &str {
+        "soroban-client-interface-mismatch"
+    }
+
+    fn name(&self) -> &str {
+        "Client Interface Mismatch"
+    }
+
+    fn description(&self) -> &str {
+        "Detects #[contractclient] interface methods that collide or whose signatures differ from the #[contractimpl] functions"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        let implemented: HashMap<&str, &SorobanFunction> = contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .map(|f| (f.name.as_str(), f))
+            .collect();
+
+        for interface in &contract.interfaces {
+            violations.extend(self.check_collisions(interface));
+
+            for method in &interface.methods {
+                if let Some(function) = implemented.get(method.name.as_str()) {
+                    if let Some(reason) = Self::signature_mismatch(method, function) {
+                        violations.push(self.violation(
+                            interface,
+                            method,
+                            format!(
+                                "Interface '{}' method '{}' does not match the contract implementation: {}",
+                                interface.name, method.name, reason
+                            ),
+                            format!(
+                                "Align the '{}' signature in '{}' with the #[contractimpl] function at line {}",
+                                method.name, interface.name, function.line_number
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl ClientInterfaceMismatchRule {
+    fn check_collisions(&self, interface: &SorobanInterface) -> Vec<RuleViolation> {
+        let mut seen: HashMap<&str, &SorobanFunction> = HashMap::new();
+        let mut violations = Vec::new();
+
+        for method in &interface.methods {
+            if let Some(first) = seen.get(method.name.as_str()) {
+                violations.push(self.violation(
+                    interface,
+                    method,
+                    format!(
+                        "Interface '{}' declares method '{}' more than once (first at line {})",
+                        interface.name, method.name, first.line_number
+                    ),
+                    "Give each interface method a unique name; contract function names are the dispatch key".to_string(),
+                ));
+            } else {
+                seen.insert(&method.name, method);
+            }
+        }
+
+        violations
+    }
+
+    /// Describe how two signatures differ, if they do
+    fn signature_mismatch(method: &SorobanFunction, function: &SorobanFunction) -> Option<String> {
+        if method.params.len() != function.params.len() {
+            return Some(format!(
+                "{} argument(s) declared, {} implemented",
+                method.params.len(),
+                function.params.len()
+            ));
+        }
+
+        for (declared, implemented) in method.params.iter().zip(&function.params) {
+            if Self::normalize(&declared.type_name) != Self::normalize(&implemented.type_name) {
+                return Some(format!(
+                    "argument '{}' is '{}' but the implementation takes '{}'",
+                    declared.name, declared.type_name, implemented.type_name
+                ));
+            }
+        }
+
+        let declared_return = method.return_type.as_deref().map(Self::normalize);
+        let implemented_return = function.return_type.as_deref().map(Self::normalize);
+        if declared_return != implemented_return {
+            return Some(format!(
+                "returns '{}' but the implementation returns '{}'",
+                method.return_type.as_deref().unwrap_or("()"),
+                function.return_type.as_deref().unwrap_or("()")
+            ));
+        }
+
+        None
+    }
+
+    fn normalize(type_name: &str) -> String {
+        type_name.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+
+    fn violation(
+        &self,
+        interface: &SorobanInterface,
+        method: &SorobanFunction,
+        description: String,
+        suggestion: String,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description,
+            suggestion,
+            line_number: method.line_number,
+            column_number: 0,
+            variable_name: format!("{}::{}", interface.name, method.name),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    const IMPL: &str = r#"
+#[contractimpl]
+impl Token {
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+}
+"#;
+
+    #[test]
+    fn test_mismatched_client_is_flagged() {
+        let source = format!(
+            r#"
+use soroban_sdk::{{contractclient, contractimpl, Address, Env}};
+
+#[contractclient(name = "TokenClient")]
+pub trait TokenInterface {{
+    fn balance(env: Env, id: Address) -> u64;
+}}
+{}"#,
+            IMPL
+        );
+        let contract = SorobanParser::parse_contract(&source, "test.rs").unwrap();
+        assert_eq!(contract.interfaces.len(), 1);
+
+        let violations = ClientInterfaceMismatchRule::default().apply(&contract);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "TokenInterface::balance");
+    }
+
+    #[test]
+    fn test_matching_client_is_not_flagged() {
+        let source = format!(
+            r#"
+use soroban_sdk::{{contractclient, contractimpl, Address, Env}};
+
+#[contractclient(name = "TokenClient")]
+pub trait TokenInterface {{
+    fn balance(env: Env, id: Address) -> i128;
+}}
+{}"#,
+            IMPL
+        );
+        let contract = SorobanParser::parse_contract(&source, "test.rs").unwrap();
+        let violations = ClientInterfaceMismatchRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+//! Authorization of constructed addresses
+//!
+//! `require_auth` asks the host to check that an account signed the
+//! invocation. That only gates anything when the address comes from the
+//! caller or from storage. Authorizing `current_contract_address()` or an
+//! address built from a literal either always succeeds or always fails, so
+//! the check does not protect what it appears to.
+
+use crate::soroban::helpers::{
+    contains_identifier, function_body, line_offset, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `require_auth` on addresses built in code rather than passed in or stored
+pub struct ConstructedAuthAddressRule {
+    enabled: bool,
+}
+
+impl Default for ConstructedAuthAddressRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for ConstructedAuthAddressRule {
+    fn id(&self) -> &str {
+        "soroban-constructed-auth-address"
+    }
+
+    fn name(&self) -> &str {
+        "Constructed Auth Address"
+    }
+
+    fn description(&self) -> &str {
+        "Detects require_auth on the contract's own address or one built from a literal, which gates nothing"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl ConstructedAuthAddressRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let auth_re = Regex::new(r"\.\s*require_auth(?:_for_args)?\s*\(").unwrap();
+
+        let mut violations = Vec::new();
+        for call in auth_re.find_iter(&stripped) {
+            let statement_start = stripped[..call.start()]
+                .rfind([';', '{', '}'])
+                .map(|idx| idx + 1)
+                .unwrap_or(0);
+            let receiver = stripped[statement_start..call.start()].trim();
+            let Some(origin) =
+                Self::constructed_origin(function, &stripped[..statement_start], receiver)
+            else {
+                continue;
+            };
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' calls require_auth on '{}', an address built from {} rather than taken from the caller or storage",
+                    function.name, receiver, origin
+                ),
+                suggestion: "Authorize an address passed in as an argument or loaded from storage, such as the stored admin".to_string(),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + call.start()),
+                column_number: 0,
+                variable_name: receiver.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// What `receiver` was constructed from, following local bindings declared in `before`
+    ///
+    /// Parameters and anything else whose origin is unclear are trusted.
+    fn constructed_origin(
+        function: &SorobanFunction,
+        before: &str,
+        receiver: &str,
+    ) -> Option<&'static str> {
+        let let_re = Regex::new(r"\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]*)?=([^;]*);").unwrap();
+        let literal_re = Regex::new(r"\bAddress\s*::\s*from_\w+\s*\(").unwrap();
+        // Every constructor takes `env`, so it says nothing about where the address came from
+        let params: Vec<&str> = function
+            .params
+            .iter()
+            .filter(|p| p.type_name.trim_start_matches('&').trim() != "Env")
+            .map(|p| p.name.as_str())
+            .collect();
+        let mut expression = receiver.to_string();
+
+        // Bindings are searched latest first, so shadowing resolves to the nearest `let`
+        for _ in 0..8 {
+            if expression.contains("current_contract_address") {
+                return Some("current_contract_address()");
+            }
+            if literal_re.is_match(&expression) {
+                return Some("a literal");
+            }
+            let name = expression.trim_start_matches('&').trim();
+            if !name.chars().all(|c| c.is_alphanumeric() || c == '_') || params.contains(&name) {
+                return None;
+            }
+            let init = let_re
+                .captures_iter(before)
+                .filter(|binding| &binding[1] == name)
+                .last()?[2]
+                .trim()
+                .to_string();
+            if params.iter().any(|param| contains_identifier(&init, param)) {
+                return None;
+            }
+            expression = init;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_auth_on_hardcoded_address_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+#[contractimpl]
+impl Vault {
+    pub fn sweep(env: Env, amount: i128) {
+        let treasury = Address::from_string(&String::from_str(&env, "GTREASURY"));
+        treasury.require_auth();
+        env.current_contract_address().require_auth();
+        env.storage().instance().set(&DataKey::Swept, &amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = ConstructedAuthAddressRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].variable_name, "treasury");
+        assert!(violations[0].description.contains("a literal"));
+        assert!(violations[1]
+            .description
+            .contains("current_contract_address()"));
+    }
+
+    #[test]
+    fn test_auth_on_parameter_or_stored_address_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, user: Address, amount: i128) {
+        user.require_auth();
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let owner = user.clone();
+        owner.require_auth_for_args((amount,).into_val(&env));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = ConstructedAuthAddressRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+//! Default-derived collection field detection
+//!
+//! `Map` and `Vec` are host objects created with `Map::new(&env)` /
+//! `Vec::new(&env)`, so they have no env-free default. A `#[contracttype]`
+//! struct deriving `Default` over such fields suggests a constructor path
+//! that cannot exist; build the struct from an `&Env` instead.
+
+use crate::soroban::{type_coverage, SorobanContract, SorobanRule, SorobanStruct};
+use crate::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+
+/// Rule for detecting `Default`-derived contract types that hold `Map`/`Vec` fields
+pub struct DefaultCollectionDeriveRule {
+    enabled: bool,
+}
+
+impl Default for DefaultCollectionDeriveRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for DefaultCollectionDeriveRule {
+    fn id(&self) -> &str {
+        "soroban-default-collection-derive"
+    }
+
+    fn name(&self) -> &str {
+        "Default Derive on Collection Fields"
+    }
+
+    fn description(&self) -> &str {
+        "Detects #[contracttype] structs deriving Default while holding Map/Vec fields, which need an Env to construct"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        contract
+            .contract_types
+            .iter()
+            .filter(|t| t.derives.iter().any(|d| d == "Default"))
+            .flat_map(|t| self.check_struct(t))
+            .collect()
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract)
+    }
+}
+
+impl DefaultCollectionDeriveRule {
+    fn check_struct(&self, contract_type: &SorobanStruct) -> Vec<RuleViolation> {
+        contract_type
+            .fields
+            .iter()
+            .filter(|field| {
+                let ty = field.type_name.trim_start_matches("soroban_sdk::");
+                ty.starts_with("Map<") || ty.starts_with("Vec<")
+            })
+            .map(|field| RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Struct '{}' derives Default but field '{}: {}' can only be created from an Env",
+                    contract_type.name, field.name, field.type_name
+                ),
+                suggestion: format!(
+                    "Drop the Default derive and add a constructor such as `fn new(env: &Env) -> Self` that builds '{}' with `{}::new(env)`",
+                    field.name,
+                    if field.type_name.contains("Map<") { "Map" } else { "Vec" }
+                ),
+                line_number: field.line_number,
+                column_number: 0,
+                variable_name: field.name.clone(),
+                severity: self.severity(),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_default_struct_with_map_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contracttype, Address, Map};
+
+#[contracttype]
+#[derive(Clone, Default)]
+pub struct Registry {
+    pub count: u32,
+    pub members: Map<Address, u32>,
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = DefaultCollectionDeriveRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "members");
+    }
+
+    #[test]
+    fn test_default_struct_without_collections_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone, Default)]
+pub struct Settings {
+    pub fee_bps: u32,
+    pub paused: bool,
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = DefaultCollectionDeriveRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,155 @@
+//! Duplicate contract function detection
+//!
+//! Every `#[contractimpl]` block exports its functions under their bare
+//! names, so two impls of the same contract type that both define `init`
+//! generate conflicting exports and the contract fails to build.
+
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use std::collections::BTreeMap;
+
+/// Rule for detecting the same function name defined in several impls of one contract type
+pub struct DuplicateFunctionRule {
+    enabled: bool,
+}
+
+impl Default for DuplicateFunctionRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for DuplicateFunctionRule {
+    fn id(&self) -> &str {
+        "soroban-duplicate-function"
+    }
+
+    fn name(&self) -> &str {
+        "Duplicate Contract Function"
+    }
+
+    fn description(&self) -> &str {
+        "Detects functions defined more than once across the #[contractimpl] blocks of a contract type"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Error
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut definitions: BTreeMap<(&str, &str), Vec<&SorobanFunction>> = BTreeMap::new();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                definitions
+                    .entry((implementation.target.as_str(), function.name.as_str()))
+                    .or_default()
+                    .push(function);
+            }
+        }
+
+        definitions
+            .into_iter()
+            .filter(|(_, functions)| functions.len() > 1)
+            .map(|((target, name), functions)| self.violation(target, name, &functions))
+            .collect()
+    }
+}
+
+impl DuplicateFunctionRule {
+    fn violation(&self, target: &str, name: &str, functions: &[&SorobanFunction]) -> RuleViolation {
+        let lines: Vec<String> = functions
+            .iter()
+            .map(|f| f.line_number.to_string())
+            .collect();
+
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' is defined {} times across the impls of '{}' (lines {})",
+                name,
+                functions.len(),
+                target,
+                lines.join(", ")
+            ),
+            suggestion: format!(
+                "Keep a single '{}' or rename the others; contract exports must be unique",
+                name
+            ),
+            line_number: functions[1].line_number,
+            column_number: 0,
+            variable_name: name.to_string(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_function_defined_in_two_impls_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn init(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for Token {
+    fn init(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = DuplicateFunctionRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "init");
+        assert!(violations[0].description.contains("defined 2 times"));
+    }
+
+    #[test]
+    fn test_unique_function_names_are_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn init(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for Token {
+    fn admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = DuplicateFunctionRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
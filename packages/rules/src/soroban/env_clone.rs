@@ -0,0 +1,152 @@
+//! `Env` clone detection
+//!
+//! `Env` is a handle to the host and is meant to be passed around as `&env`
+//! or moved. Cloning it has no effect on the contract's behaviour and usually
+//! means a helper takes `Env` by value where `&Env` would do.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `.clone()` called on an `Env` parameter
+pub struct EnvCloneRule {
+    enabled: bool,
+}
+
+impl Default for EnvCloneRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for EnvCloneRule {
+    fn id(&self) -> &str {
+        "soroban-env-clone"
+    }
+
+    fn name(&self) -> &str {
+        "Env Clone"
+    }
+
+    fn description(&self) -> &str {
+        "Detects .clone() calls on Env parameters, which should be passed as &env instead"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl EnvCloneRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let env_params: Vec<&str> = function
+            .params
+            .iter()
+            .filter(|p| p.type_name.trim_start_matches('&').trim() == "Env")
+            .map(|p| p.name.as_str())
+            .collect();
+        if env_params.is_empty() {
+            return Vec::new();
+        }
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let clone_re = Regex::new(r"\b([A-Za-z_]\w*)\s*\.\s*clone\s*\(\s*\)").unwrap();
+
+        clone_re
+            .captures_iter(&stripped)
+            .filter(|c| env_params.contains(&&c[1]))
+            .map(|c| {
+                let call = c.get(0).unwrap();
+                RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Function '{}' clones its Env parameter '{}'",
+                        function.name, &c[1]
+                    ),
+                    suggestion: format!(
+                        "Pass '&{}' to helpers that only need a reference, or move '{}' if it is not used again",
+                        &c[1], &c[1]
+                    ),
+                    line_number: function.line_number
+                        + line_offset(&function.raw_definition, body_start + call.start()),
+                    column_number: 0,
+                    variable_name: c[1].to_string(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_env_clone_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        write_balance(env.clone(), to, amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = EnvCloneRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "env");
+    }
+
+    #[test]
+    fn test_address_clone_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        write_balance(&env, to.clone(), amount);
+        env.events().publish((to,), amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = EnvCloneRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
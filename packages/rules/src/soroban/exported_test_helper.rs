@@ -0,0 +1,186 @@
+//! Exported test helper detection
+//!
+//! Every public function in a `#[contractimpl]` block becomes a contract
+//! entry point, so a `mock_balance` or `fake_price` written for tests ships
+//! in the wasm unless it sits behind `#[cfg(test)]`. Such helpers usually
+//! write state without any authorization.
+
+use crate::soroban::helpers::{
+    block_open, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{FunctionVisibility, SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Name fragments that mark a function as written for tests
+const TEST_ONLY_MARKERS: [&str; 3] = ["mock", "fake", "test_helper"];
+
+/// Rule for detecting test-only helpers exported from `#[contractimpl]` outside `#[cfg(test)]`
+pub struct ExportedTestHelperRule {
+    enabled: bool,
+}
+
+impl Default for ExportedTestHelperRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for ExportedTestHelperRule {
+    fn id(&self) -> &str {
+        "soroban-exported-test-helper"
+    }
+
+    fn name(&self) -> &str {
+        "Exported Test Helper"
+    }
+
+    fn description(&self) -> &str {
+        "Detects contract functions named like test helpers (mock, fake, test_helper) that are not gated by #[cfg(test)]"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let gated = Self::cfg_test_lines(&contract.source);
+
+        contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .filter(|f| {
+                !gated
+                    .iter()
+                    .any(|&(start, end)| (start..=end).contains(&f.line_number))
+            })
+            .filter_map(|function| self.check_function(function))
+            .collect()
+    }
+}
+
+impl ExportedTestHelperRule {
+    fn check_function(&self, function: &SorobanFunction) -> Option<RuleViolation> {
+        if function.visibility != FunctionVisibility::Public {
+            return None;
+        }
+        let lowered = function.name.to_ascii_lowercase();
+        let marker = TEST_ONLY_MARKERS
+            .iter()
+            .find(|marker| lowered.contains(*marker))?;
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' looks like a test helper ('{}') but is exported from the contract",
+                function.name, marker
+            ),
+            suggestion: format!(
+                "Move '{}' into a #[cfg(test)] module or gate it with #[cfg(test)] so it is not compiled into the wasm",
+                function.name
+            ),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+
+    /// One-based, inclusive line spans of every item gated by `#[cfg(test)]`
+    fn cfg_test_lines(source: &str) -> Vec<(usize, usize)> {
+        let stripped = strip_comments_and_strings(source);
+        let cfg_re = Regex::new(r"#\s*\[\s*cfg\s*\(\s*test\s*\)\s*\]").unwrap();
+
+        cfg_re
+            .find_iter(&stripped)
+            .filter_map(|m| {
+                let open = block_open(&stripped, m.end())?;
+                let close = matching_close(&stripped, open)?;
+                Some((
+                    line_offset(&stripped, m.start()) + 1,
+                    line_offset(&stripped, close) + 1,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_exported_mock_function_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn mock_balance(env: Env, id: Address, amount: i128) {
+        env.storage().persistent().set(&id, &amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = ExportedTestHelperRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "mock_balance");
+    }
+
+    #[test]
+    fn test_mock_function_inside_cfg_test_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[contract]
+    pub struct MockToken;
+
+    #[contractimpl]
+    impl MockToken {
+        pub fn mock_balance(env: Env, id: Address, amount: i128) {
+            env.storage().persistent().set(&id, &amount);
+        }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        assert!(contract
+            .implementations
+            .iter()
+            .any(|imp| imp.functions.iter().any(|f| f.name == "mock_balance")));
+        let violations = ExportedTestHelperRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
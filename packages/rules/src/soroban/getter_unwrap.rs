@@ -0,0 +1,152 @@
+//! Unwrapped reads in getter detection
+//!
+//! Clients call getters to find out whether a value exists. A getter that
+//! unwraps `.get(key)` traps on a missing key instead of answering, which
+//! surfaces to the caller as an opaque host error rather than a `None`.
+
+use crate::soroban::helpers::{function_body, strip_comments_and_strings};
+use crate::soroban::{MapGetUnwrapRule, SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+
+/// Name prefixes that mark a function as a read-only accessor
+const GETTER_PREFIXES: [&str; 5] = ["get_", "view_", "query_", "read_", "fetch_"];
+
+/// Rule for detecting `.get(key).unwrap()` inside getter-named view functions
+pub struct GetterUnwrapRule {
+    enabled: bool,
+}
+
+impl Default for GetterUnwrapRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for GetterUnwrapRule {
+    fn id(&self) -> &str {
+        "soroban-getter-unwrap"
+    }
+
+    fn name(&self) -> &str {
+        "Unwrapped Read in Getter"
+    }
+
+    fn description(&self) -> &str {
+        "Detects getter functions that unwrap a Map or storage read, trapping instead of reporting a missing key"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                if !Self::is_view_getter(function) {
+                    continue;
+                }
+                for offset in MapGetUnwrapRule::unwrapped_get_offsets(&function.raw_definition) {
+                    violations.push(self.violation(function, offset));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl GetterUnwrapRule {
+    /// Whether `function` is named like a getter and writes no storage
+    fn is_view_getter(function: &SorobanFunction) -> bool {
+        let name = function.name.as_str();
+        let getter_named = name == "get" || GETTER_PREFIXES.iter().any(|p| name.starts_with(p));
+        if !getter_named {
+            return false;
+        }
+
+        let Some(body) = function_body(&function.raw_definition) else {
+            return false;
+        };
+        let stripped = strip_comments_and_strings(body);
+        ![".set(", ".remove(", ".update(", ".extend_ttl("]
+            .iter()
+            .any(|write| stripped.contains(write))
+    }
+
+    fn violation(&self, function: &SorobanFunction, offset: usize) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Getter '{}' unwraps a Map/storage read, so querying a missing key traps instead of returning an answer",
+                function.name
+            ),
+            suggestion: format!(
+                "Return Option from '{}' so clients can tell a missing key apart, or use unwrap_or_default() when absence has a natural default",
+                function.name
+            ),
+            line_number: function.line_number + offset,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_getter_unwrapping_storage_read_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Registry {
+    pub fn get_owner(env: Env, id: u32) -> Address {
+        env.storage().persistent().get(&DataKey::Owner(id)).unwrap()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = GetterUnwrapRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "get_owner");
+        assert_eq!(violations[0].line_number, 7);
+    }
+
+    #[test]
+    fn test_getter_returning_option_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Registry {
+    pub fn get_owner(env: Env, id: u32) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::Owner(id))
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = GetterUnwrapRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
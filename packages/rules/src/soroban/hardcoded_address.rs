@@ -0,0 +1,191 @@
+//! Hardcoded address detection
+//!
+//! An admin or fee recipient written into the contract body as a literal can
+//! only be changed by redeploying, and the same wasm cannot be reused across
+//! networks. Such addresses belong in configurable contract state.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting Stellar address literals embedded in function bodies
+pub struct HardcodedAddressRule {
+    enabled: bool,
+}
+
+impl Default for HardcodedAddressRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for HardcodedAddressRule {
+    fn id(&self) -> &str {
+        "soroban-hardcoded-address"
+    }
+
+    fn name(&self) -> &str {
+        "Hardcoded Address"
+    }
+
+    fn description(&self) -> &str {
+        "Detects account/contract address literals and Address::from_string calls on literals inside function bodies"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl HardcodedAddressRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        // Literal contents are blanked in `stripped`, so quotes delimit them reliably
+        let literal_re = Regex::new(r#""[^"]*""#).unwrap();
+        let address_re = Regex::new(r"^[GC][A-Z2-7]{55}$").unwrap();
+        let from_string_re = Regex::new(r"\bAddress\s*::\s*from_str(?:ing)?\s*\(").unwrap();
+
+        let literals: Vec<(usize, usize)> = literal_re
+            .find_iter(&stripped)
+            .map(|m| (m.start(), m.end()))
+            .collect();
+        let mut reported = vec![false; literals.len()];
+        let mut violations = Vec::new();
+
+        for call in from_string_re.find_iter(&stripped) {
+            let Some(close_idx) = matching_close(&stripped, call.end() - 1) else {
+                continue;
+            };
+            let inside: Vec<usize> = (0..literals.len())
+                .filter(|&i| literals[i].0 > call.end() && literals[i].1 <= close_idx)
+                .collect();
+            let Some(&first) = inside.first() else {
+                continue;
+            };
+            inside.iter().for_each(|&i| reported[i] = true);
+
+            let (start, end) = literals[first];
+            violations.push(self.violation(
+                function,
+                &body[start + 1..end - 1],
+                "builds an Address from a literal",
+                body_start + call.start(),
+            ));
+        }
+
+        for (i, &(start, end)) in literals.iter().enumerate() {
+            let content = &body[start + 1..end - 1];
+            if reported[i] || !address_re.is_match(content) {
+                continue;
+            }
+            violations.push(self.violation(
+                function,
+                content,
+                "embeds an address literal",
+                body_start + start,
+            ));
+        }
+
+        violations.sort_by_key(|v| v.line_number);
+        violations
+    }
+
+    fn violation(
+        &self,
+        function: &SorobanFunction,
+        literal: &str,
+        what: &str,
+        offset: usize,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!("Function '{}' {} \"{}\"", function.name, what, literal),
+            suggestion: "Store the address in contract state during initialization (or pass it as an argument) so it can be configured per deployment"
+                .to_string(),
+            line_number: function.line_number + line_offset(&function.raw_definition, offset),
+            column_number: 0,
+            variable_name: literal.to_string(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_hardcoded_address_literal_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, String};
+
+#[contractimpl]
+impl Fees {
+    pub fn collect(env: Env, amount: i128) {
+        let recipient = Address::from_string(&String::from_str(
+            &env,
+            "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7",
+        ));
+        Self::pay(&env, &recipient, amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = HardcodedAddressRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("from a literal"));
+    }
+
+    #[test]
+    fn test_parameterized_address_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Fees {
+    pub fn collect(env: Env, recipient: Address, amount: i128) {
+        recipient.require_auth();
+        Self::pay(&env, &recipient, amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = HardcodedAddressRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
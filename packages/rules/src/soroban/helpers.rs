@@ -0,0 +1,279 @@
+//! Text helpers shared by the Soroban rules
+//!
+//! The Soroban parser hands rules the raw source of each function, so most
+//! rules work on text. These helpers keep the bracket matching and line
+//! bookkeeping in one place.
+
+/// Find the index of the bracket closing the one at `open_idx`
+pub(crate) fn matching_close(text: &str, open_idx: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let open = *bytes.get(open_idx)?;
+    let close = match open {
+        b'(' => b')',
+        b'[' => b']',
+        b'{' => b'}',
+        _ => return None,
+    };
+
+    let mut depth = 0usize;
+    for (idx, &byte) in bytes.iter().enumerate().skip(open_idx) {
+        if byte == open {
+            depth += 1;
+        } else if byte == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(idx);
+            }
+        }
+    }
+
+    None
+}
+
+/// Replace comments and the contents of string/char literals with spaces
+///
+/// Byte offsets and line breaks are preserved so positions found in the
+/// stripped text map straight back onto the original source.
+pub(crate) fn strip_comments_and_strings(source: &str) -> String {
+    blank(source, true)
+}
+
+/// Replace the contents of string/char literals with spaces, keeping comments
+///
+/// Lets comment text be searched without matching look-alikes inside strings.
+pub(crate) fn strip_strings(source: &str) -> String {
+    blank(source, false)
+}
+
+fn blank(source: &str, comments: bool) -> String {
+    let bytes = source.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    if comments {
+                        out[i] = b' ';
+                    }
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let mut depth = 0usize;
+                while i < bytes.len() {
+                    let blanked = if bytes[i] == b'/' && bytes.get(i + 1) == Some(&b'*') {
+                        depth += 1;
+                        2
+                    } else if bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/') {
+                        depth -= 1;
+                        2
+                    } else {
+                        1
+                    };
+                    if comments {
+                        for byte in &mut out[i..i + blanked] {
+                            if *byte != b'\n' {
+                                *byte = b' ';
+                            }
+                        }
+                    }
+                    i += blanked;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    if bytes[i] == b'\\' && i + 1 < bytes.len() {
+                        out[i] = b' ';
+                        i += 1;
+                    }
+                    if bytes[i] != b'\n' {
+                        out[i] = b' ';
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'\''
+                if bytes.get(i + 2) == Some(&b'\'')
+                    || (bytes.get(i + 1) == Some(&b'\\') && bytes.get(i + 3) == Some(&b'\'')) =>
+            {
+                // Char literal; lifetimes ('a) never close with a quote this quickly
+                let end = if bytes[i + 1] == b'\\' { i + 3 } else { i + 2 };
+                for byte in out.iter_mut().take(end).skip(i + 1) {
+                    *byte = b' ';
+                }
+                i = end + 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    // Blanked regions always cover whole characters, so the output is still UTF-8
+    String::from_utf8(out).expect("blanking replaces whole characters with ASCII spaces")
+}
+
+/// Split a comma separated list, ignoring commas nested in brackets
+pub(crate) fn split_top_level(list: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (idx, c) in list.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(list[start..idx].trim());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(list[start..].trim());
+
+    parts.into_iter().filter(|part| !part.is_empty()).collect()
+}
+
+/// Body of a function: everything from the opening brace of its block
+pub(crate) fn function_body(raw_definition: &str) -> Option<&str> {
+    raw_definition.find('{').map(|idx| &raw_definition[idx..])
+}
+
+/// Whether `ident` occurs in `text` as a whole identifier
+pub(crate) fn contains_identifier(text: &str, ident: &str) -> bool {
+    let is_ident_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    text.match_indices(ident).any(|(idx, _)| {
+        let before = idx.checked_sub(1).map(|i| text.as_bytes()[i]);
+        let after = text.as_bytes().get(idx + ident.len()).copied();
+        !before.is_some_and(is_ident_byte) && !after.is_some_and(is_ident_byte)
+    })
+}
+
+/// Number of identifier tokens in `source` spelled exactly `ident`
+///
+/// Comments, string literals and longer identifiers containing `ident` are not
+/// counted. Source that does not lex as Rust falls back to matching whole
+/// identifiers in the text with comments and strings blanked out.
+pub(crate) fn identifier_count(source: &str, ident: &str) -> usize {
+    fn count(tokens: proc_macro2::TokenStream, ident: &str) -> usize {
+        tokens
+            .into_iter()
+            .map(|token| match token {
+                proc_macro2::TokenTree::Ident(found) => usize::from(found == ident),
+                proc_macro2::TokenTree::Group(group) => count(group.stream(), ident),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    match source.parse::<proc_macro2::TokenStream>() {
+        Ok(tokens) => count(tokens, ident),
+        Err(_) => {
+            let stripped = strip_comments_and_strings(source);
+            let is_ident_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+            stripped
+                .match_indices(ident)
+                .filter(|&(idx, _)| {
+                    let before = idx.checked_sub(1).map(|i| stripped.as_bytes()[i]);
+                    let after = stripped.as_bytes().get(idx + ident.len()).copied();
+                    !before.is_some_and(is_ident_byte) && !after.is_some_and(is_ident_byte)
+                })
+                .count()
+        }
+    }
+}
+
+/// Index of the `{` opening the block after a condition or loop header at `start`
+///
+/// Returns `None` when the statement ends before any block opens.
+pub(crate) fn block_open(stripped: &str, start: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    for (offset, c) in stripped[start..].char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            '{' if depth == 0 => return Some(start + offset),
+            ';' | '}' if depth == 0 => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Byte ranges of every `for`/`while`/`loop` body in stripped source, braces excluded
+pub(crate) fn loop_bodies(stripped: &str) -> Vec<(usize, usize)> {
+    let loop_re = regex::Regex::new(r"\b(for|while|loop)\b").unwrap();
+
+    loop_re
+        .find_iter(stripped)
+        .filter_map(|m| {
+            let open = block_open(stripped, m.end())?;
+            let close = matching_close(stripped, open)?;
+            Some((open + 1, close))
+        })
+        .collect()
+}
+
+/// Zero-based line offset of a byte index within `text`
+pub(crate) fn line_offset(text: &str, byte_idx: usize) -> usize {
+    text[..byte_idx.min(text.len())].matches('\n').count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_close_handles_nesting() {
+        let text = "get(&DataKey::Balance(addr)).unwrap()";
+        assert_eq!(matching_close(text, 3), Some(27));
+        assert_eq!(matching_close("foo(", 3), None);
+    }
+
+    #[test]
+    fn test_strip_comments_and_strings_preserves_offsets() {
+        let source = "let a = \"x.get(\"; // b.get(\n/* c */ d.get(k)";
+        let stripped = strip_comments_and_strings(source);
+
+        assert_eq!(stripped.len(), source.len());
+        assert_eq!(stripped.matches(".get(").count(), 1);
+        assert_eq!(line_offset(&stripped, stripped.find(".get(").unwrap()), 1);
+    }
+
+    #[test]
+    fn test_strip_strings_keeps_comments() {
+        let source = "let a = \"// TODO\"; // TODO: b\n/* \"c\" */";
+        let stripped = strip_strings(source);
+
+        assert_eq!(stripped.len(), source.len());
+        assert_eq!(stripped.matches("// TODO").count(), 1);
+        assert!(stripped.contains("/* \"c\" */"));
+    }
+
+    #[test]
+    fn test_contains_identifier() {
+        assert!(contains_identifier("amount + 1", "amount"));
+        assert!(!contains_identifier("total_amount + 1", "amount"));
+        assert!(!contains_identifier("amounts", "amount"));
+    }
+
+    #[test]
+    fn test_identifier_count_skips_comments_strings_and_longer_names() {
+        let source = "fn f(id: u32) { let valid_id = id; /* id */ log(\"id\"); }";
+        assert_eq!(identifier_count(source, "id"), 2);
+        assert_eq!(identifier_count("let x = \"unterminated", "x"), 1);
+    }
+
+    #[test]
+    fn test_line_offset() {
+        let text = "a\nb\nc";
+        assert_eq!(line_offset(text, 0), 0);
+        assert_eq!(line_offset(text, 4), 2);
+    }
+}
@@ -0,0 +1,240 @@
+//! Ignored constructor parameter detection
+//!
+//! A constructor that zero-initializes a field while an unused parameter of
+//! the same type sits in its signature almost always meant to store that
+//! parameter. The contract compiles and deploys with the wrong initial state.
+
+use crate::soroban::helpers::{
+    contains_identifier, function_body, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanParam, SorobanRule, SorobanStruct};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting constructors that default a field instead of storing a matching parameter
+pub struct IgnoredConstructorParamRule {
+    enabled: bool,
+}
+
+impl Default for IgnoredConstructorParamRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for IgnoredConstructorParamRule {
+    fn id(&self) -> &str {
+        "soroban-ignored-constructor-param"
+    }
+
+    fn name(&self) -> &str {
+        "Ignored Constructor Parameter"
+    }
+
+    fn description(&self) -> &str {
+        "Detects constructors that initialize a field to zero/Default while an unused parameter of the same type is available"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                if !Self::is_constructor(function) {
+                    continue;
+                }
+                for contract_type in &contract.contract_types {
+                    violations.extend(self.check_constructor(function, contract_type));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl IgnoredConstructorParamRule {
+    fn is_constructor(function: &SorobanFunction) -> bool {
+        function.is_constructor || function.name == "__constructor" || function.name == "initialize"
+    }
+
+    fn check_constructor(
+        &self,
+        function: &SorobanFunction,
+        contract_type: &SorobanStruct,
+    ) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+
+        let literal_re = Regex::new(&format!(
+            r"\b(?:{}|Self)\s*\{{",
+            regex::escape(&contract_type.name)
+        ))
+        .unwrap();
+        let Some(literal) = literal_re.find(&stripped) else {
+            return Vec::new();
+        };
+        let open_idx = literal.end() - 1;
+        let Some(close_idx) = matching_close(&stripped, open_idx) else {
+            return Vec::new();
+        };
+
+        let unused_params: Vec<&SorobanParam> = function
+            .params
+            .iter()
+            .filter(|p| {
+                let name = p.name.trim_start_matches("mut ").trim();
+                !name.starts_with('_') && !contains_identifier(&stripped, name)
+            })
+            .collect();
+        if unused_params.is_empty() {
+            return Vec::new();
+        }
+
+        let line_number = function.line_number
+            + line_offset(&function.raw_definition, body_start + literal.start());
+
+        Self::initializers(&stripped[open_idx + 1..close_idx])
+            .into_iter()
+            .filter(|(_, value)| Self::is_default_value(value))
+            .filter_map(|(field_name, _)| {
+                let field = contract_type.fields.iter().find(|f| f.name == field_name)?;
+                let param = unused_params
+                    .iter()
+                    .find(|p| Self::normalize(&p.type_name) == Self::normalize(&field.type_name))?;
+                let param_name = param.name.trim_start_matches("mut ").trim();
+
+                Some(RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Constructor '{}' initializes '{}' to a default value while parameter '{}: {}' is never used",
+                        function.name, field.name, param_name, param.type_name
+                    ),
+                    suggestion: format!("Initialize '{}' from '{}' or remove the unused parameter", field.name, param_name),
+                    line_number,
+                    column_number: 0,
+                    variable_name: field.name.clone(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                })
+            })
+            .collect()
+    }
+
+    /// `field: value` pairs of a struct literal body; shorthand fields are skipped
+    fn initializers(literal_body: &str) -> Vec<(String, String)> {
+        let mut pairs = Vec::new();
+        let mut depth = 0i32;
+        let mut start = 0;
+        let mut parts = Vec::new();
+
+        for (idx, c) in literal_body.char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(&literal_body[start..idx]);
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&literal_body[start..]);
+
+        for part in parts {
+            if let Some((name, value)) = part.split_once(':') {
+                pairs.push((name.trim().to_string(), value.trim().to_string()));
+            }
+        }
+
+        pairs
+    }
+
+    fn is_default_value(value: &str) -> bool {
+        let zero_re = Regex::new(r"^0+(?:_?[iu](?:8|16|32|64|128|size))?$").unwrap();
+        zero_re.is_match(value) || value == "Default::default()" || value.ends_with("::default()")
+    }
+
+    fn normalize(type_name: &str) -> String {
+        type_name
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '&')
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_zeroed_field_with_ignored_param_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+pub struct Token {
+    pub admin: Address,
+    pub supply: i128,
+}
+
+#[contractimpl]
+impl Token {
+    pub fn new(env: Env, admin: Address, initial_supply: i128) -> Self {
+        Token { admin, supply: 0 }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = IgnoredConstructorParamRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "supply");
+        assert!(violations[0].description.contains("initial_supply"));
+    }
+
+    #[test]
+    fn test_constructor_using_its_params_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+pub struct Token {
+    pub admin: Address,
+    pub supply: i128,
+    pub fees: i128,
+}
+
+#[contractimpl]
+impl Token {
+    pub fn new(env: Env, admin: Address, initial_supply: i128) -> Self {
+        Token { admin, supply: initial_supply, fees: 0 }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = IgnoredConstructorParamRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
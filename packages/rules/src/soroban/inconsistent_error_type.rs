@@ -0,0 +1,172 @@
+//! Inconsistent error type detection
+//!
+//! Callers of a contract match on one error enum. When some entry points
+//! return `Result<_, Error>` and others `Result<_, OtherError>` or
+//! `Result<_, ()>`, clients need a different handling path per function.
+
+use crate::soroban::helpers::split_top_level;
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use std::collections::BTreeMap;
+
+/// Rule for detecting contracts whose functions return more than one error type
+pub struct InconsistentErrorTypeRule {
+    enabled: bool,
+}
+
+impl Default for InconsistentErrorTypeRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for InconsistentErrorTypeRule {
+    fn id(&self) -> &str {
+        "soroban-inconsistent-error-type"
+    }
+
+    fn name(&self) -> &str {
+        "Inconsistent Error Type"
+    }
+
+    fn description(&self) -> &str {
+        "Detects contracts whose functions return Result with more than one distinct error type"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        // error type -> functions returning it, in declaration order
+        let mut error_types: BTreeMap<String, Vec<&SorobanFunction>> = BTreeMap::new();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                if let Some(error_type) = function.return_type.as_deref().and_then(Self::error_type)
+                {
+                    error_types.entry(error_type).or_default().push(function);
+                }
+            }
+        }
+
+        if error_types.len() < 2 {
+            return Vec::new();
+        }
+
+        let usages: Vec<String> = error_types
+            .iter()
+            .map(|(error_type, functions)| {
+                let names: Vec<&str> = functions.iter().map(|f| f.name.as_str()).collect();
+                format!("{} ({})", error_type, names.join(", "))
+            })
+            .collect();
+        // Point at the first function that strays from the most common type
+        let (common, _) = error_types
+            .iter()
+            .max_by_key(|(_, functions)| functions.len())
+            .expect("at least two error types");
+        let line_number = error_types
+            .iter()
+            .filter(|(error_type, _)| *error_type != common)
+            .flat_map(|(_, functions)| functions.iter().map(|f| f.line_number))
+            .min()
+            .unwrap_or(0);
+
+        vec![RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Contract '{}' returns {} different error types: {}",
+                contract.name,
+                error_types.len(),
+                usages.join("; ")
+            ),
+            suggestion:
+                "Define a single #[contracterror] enum and return it from every fallible function"
+                    .to_string(),
+            line_number,
+            column_number: 0,
+            variable_name: contract.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }]
+    }
+}
+
+impl InconsistentErrorTypeRule {
+    /// The `E` of a `Result<T, E>` return type, whitespace-normalized
+    fn error_type(return_type: &str) -> Option<String> {
+        let inner = return_type
+            .trim()
+            .strip_prefix("Result<")
+            .and_then(|rest| rest.strip_suffix('>'))?;
+        let args = split_top_level(inner);
+        // A single-argument alias already fixes the error type
+        let error_type = args.get(1)?;
+
+        Some(error_type.chars().filter(|c| !c.is_whitespace()).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_two_error_types_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn burn(env: Env, amount: i128) -> Result<(), BurnError> {
+        Ok(())
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = InconsistentErrorTypeRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("BurnError (burn)"));
+        assert!(violations[0].description.contains("Error (transfer)"));
+    }
+
+    #[test]
+    fn test_single_error_type_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        Ok(())
+    }
+
+    pub fn balance(env: Env, id: Address) -> Result<i128, Error> {
+        Ok(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = InconsistentErrorTypeRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
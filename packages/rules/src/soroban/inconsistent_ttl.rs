@@ -0,0 +1,257 @@
+//! Inconsistent TTL extension detection
+//!
+//! When one function extends an entry by a few hundred ledgers and another
+//! extends the same entry by millions, how long the data survives depends on
+//! which function ran last, and the short extension risks the entry being
+//! archived. TTLs for one entry belong in a shared constant.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Default smallest `extend_to` value, in ledgers, not reported as too small (about one day)
+pub const DEFAULT_MIN_TTL: u64 = 17_280;
+
+/// Ratio between the largest and smallest extension of one entry above which they are reported
+const INCONSISTENT_TTL_RATIO: u64 = 10;
+
+/// Rule for detecting TTL extensions that disagree per entry or fall below a minimum
+pub struct InconsistentTtlRule {
+    enabled: bool,
+    min_ttl: u64,
+}
+
+impl Default for InconsistentTtlRule {
+    fn default() -> Self {
+        Self::with_min_ttl(DEFAULT_MIN_TTL)
+    }
+}
+
+/// A literal TTL extension found in a function
+struct Extension {
+    function: String,
+    extend_to: u64,
+    line_number: usize,
+}
+
+impl SorobanRule for InconsistentTtlRule {
+    fn id(&self) -> &str {
+        "soroban-inconsistent-ttl"
+    }
+
+    fn name(&self) -> &str {
+        "Inconsistent TTL Extension"
+    }
+
+    fn description(&self) -> &str {
+        "Detects extend_ttl calls whose literal TTLs differ widely for the same entry or fall below a minimum"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let extensions = Self::extensions(contract);
+        let mut violations = Vec::new();
+
+        for (key, calls) in &extensions {
+            for call in calls.iter().filter(|call| call.extend_to < self.min_ttl) {
+                violations.push(RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Function '{}' extends '{}' to only {} ledgers (minimum {})",
+                        call.function, key, call.extend_to, self.min_ttl
+                    ),
+                    suggestion: format!(
+                        "Extend '{}' to at least {} ledgers so it is not archived between uses",
+                        key, self.min_ttl
+                    ),
+                    line_number: call.line_number,
+                    column_number: 0,
+                    variable_name: key.clone(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                });
+            }
+
+            let (Some(shortest), Some(longest)) = (
+                calls.iter().min_by_key(|call| call.extend_to),
+                calls.iter().max_by_key(|call| call.extend_to),
+            ) else {
+                continue;
+            };
+            if longest.extend_to < shortest.extend_to.saturating_mul(INCONSISTENT_TTL_RATIO) {
+                continue;
+            }
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "'{}' is extended to {} ledgers in '{}' but {} ledgers in '{}'",
+                    key, shortest.extend_to, shortest.function, longest.extend_to, longest.function
+                ),
+                suggestion: format!(
+                    "Define one TTL constant for '{}' and use it in every extend_ttl call",
+                    key
+                ),
+                line_number: shortest.line_number,
+                column_number: 0,
+                variable_name: key.clone(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+}
+
+impl InconsistentTtlRule {
+    /// Rule reporting literal extensions shorter than `min_ttl` ledgers
+    pub fn with_min_ttl(min_ttl: u64) -> Self {
+        Self {
+            enabled: true,
+            min_ttl,
+        }
+    }
+
+    /// Literal extensions grouped by entry; `instance` extensions share one group
+    fn extensions(contract: &SorobanContract) -> BTreeMap<String, Vec<Extension>> {
+        let call_re = Regex::new(r"\.\s*(extend_ttl|extend_instance_ttl)\s*\(").unwrap();
+        let mut extensions: BTreeMap<String, Vec<Extension>> = BTreeMap::new();
+
+        for function in contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+        {
+            let Some(body) = function_body(&function.raw_definition) else {
+                continue;
+            };
+            let body_start = function.raw_definition.len() - body.len();
+            let stripped = strip_comments_and_strings(body);
+
+            for call in call_re.captures_iter(&stripped) {
+                let Some(whole) = call.get(0) else {
+                    continue;
+                };
+                let open = whole.end() - 1;
+                let Some(close) = matching_close(&stripped, open) else {
+                    continue;
+                };
+                let args = split_top_level(&body[open + 1..close]);
+                let receiver = stripped[..whole.start()].trim_end();
+                let key = if &call[1] == "extend_instance_ttl" || receiver.ends_with("instance()") {
+                    "instance".to_string()
+                } else if args.len() == 3 {
+                    // Key payloads vary per call; group by the key's variant or constant
+                    let key = args[0].trim_start_matches('&');
+                    key.split('(').next().unwrap_or(key).trim().to_string()
+                } else {
+                    continue;
+                };
+                let Some(extend_to) = args.last().and_then(|arg| Self::literal(arg)) else {
+                    continue;
+                };
+
+                extensions.entry(key).or_default().push(Extension {
+                    function: function.name.clone(),
+                    extend_to,
+                    line_number: function.line_number
+                        + line_offset(&function.raw_definition, body_start + whole.start()),
+                });
+            }
+        }
+
+        extensions
+    }
+
+    /// Value of an integer literal such as `518_400` or `100u32`
+    fn literal(arg: &str) -> Option<u64> {
+        let literal_re = Regex::new(r"^(\d[\d_]*)(?:[iu](?:8|16|32|64|128|size))?$").unwrap();
+        literal_re
+            .captures(arg.trim())
+            .and_then(|captures| captures[1].replace('_', "").parse().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_inconsistent_extensions_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn deposit(env: Env, user: Address, amount: i128) {
+        env.storage().persistent().set(&DataKey::Balance(user.clone()), &amount);
+        env.storage().persistent().extend_ttl(&DataKey::Balance(user), 100_000, 200_000);
+    }
+
+    pub fn claim(env: Env, to: Address) {
+        env.storage().persistent().extend_ttl(&DataKey::Balance(to), 1_000_000, 3_000_000);
+        env.storage().instance().extend_ttl(100, 500);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = InconsistentTtlRule::default().apply(&contract);
+
+        let keys: Vec<&str> = violations
+            .iter()
+            .map(|v| v.variable_name.as_str())
+            .collect();
+        assert_eq!(keys, vec!["DataKey::Balance", "instance"]);
+        assert!(violations[0]
+            .description
+            .contains("200000 ledgers in 'deposit'"));
+        assert!(violations[1].description.contains("only 500 ledgers"));
+
+        let lenient = InconsistentTtlRule::with_min_ttl(100).apply(&contract);
+        assert_eq!(lenient.len(), 1);
+    }
+
+    #[test]
+    fn test_consistent_extensions_are_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn deposit(env: Env, user: Address, amount: i128) {
+        env.storage().persistent().set(&DataKey::Balance(user.clone()), &amount);
+        env.storage().persistent().extend_ttl(&DataKey::Balance(user), 259_200, 518_400);
+    }
+
+    pub fn claim(env: Env, to: Address) {
+        env.storage().persistent().extend_ttl(&DataKey::Balance(to), 259_200, 518_400);
+        env.storage().instance().extend_ttl(BUMP_THRESHOLD, BUMP_AMOUNT);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = InconsistentTtlRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
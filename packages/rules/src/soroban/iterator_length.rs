@@ -0,0 +1,162 @@
+//! Iterator length anti-pattern detection
+//!
+//! `v.iter().count()` walks every element to learn a length `v.len()` already
+//! knows, `.clone().len()` copies a collection just to measure it, and
+//! `.collect::<Vec<_>>().len()` allocates a vector only to count it.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Patterns flagged by this rule with the replacement suggested for each
+const PATTERNS: [(&str, &str, &str); 3] = [
+    (
+        r"\.iter\(\)\s*\.\s*count\(\)",
+        ".iter().count()",
+        "Call .len() on the collection instead of counting its iterator",
+    ),
+    (
+        r"\.clone\(\)\s*\.\s*len\(\)",
+        ".clone().len()",
+        "Call .len() on the original; cloning it only to measure the length is wasted work",
+    ),
+    (
+        r"\.collect\s*::\s*<\s*Vec\s*<[^>]*>\s*>\s*\(\)\s*\.\s*len\(\)",
+        ".collect::<Vec<_>>().len()",
+        "Call .count() on the iterator (or .len() on an unfiltered source) instead of collecting it",
+    ),
+];
+
+/// Rule for detecting lengths computed through iterators or copies
+pub struct IteratorLengthRule {
+    enabled: bool,
+}
+
+impl Default for IteratorLengthRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for IteratorLengthRule {
+    fn id(&self) -> &str {
+        "soroban-iterator-length"
+    }
+
+    fn name(&self) -> &str {
+        "Iterator Length"
+    }
+
+    fn description(&self) -> &str {
+        "Detects .iter().count(), .clone().len() and .collect::<Vec<_>>().len() where .len() or .count() suffices"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let patterns: Vec<(Regex, &str, &str)> = PATTERNS
+            .iter()
+            .map(|(pattern, label, suggestion)| (Regex::new(pattern).unwrap(), *label, *suggestion))
+            .collect();
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function, &patterns));
+            }
+        }
+
+        violations
+    }
+}
+
+impl IteratorLengthRule {
+    fn check_function(
+        &self,
+        function: &SorobanFunction,
+        patterns: &[(Regex, &str, &str)],
+    ) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+
+        let mut violations = Vec::new();
+        for (re, label, suggestion) in patterns {
+            for m in re.find_iter(&stripped) {
+                violations.push(RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Function '{}' computes a length with '{}'",
+                        function.name, label
+                    ),
+                    suggestion: suggestion.to_string(),
+                    line_number: function.line_number
+                        + line_offset(&function.raw_definition, body_start + m.start()),
+                    column_number: 0,
+                    variable_name: function.name.clone(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                });
+            }
+        }
+
+        violations.sort_by_key(|v| v.line_number);
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_iter_count_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env, Vec};
+
+#[contractimpl]
+impl Registry {
+    pub fn size(env: Env, v: Vec<u32>) -> usize {
+        v.iter().count()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = IteratorLengthRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains(".iter().count()"));
+    }
+
+    #[test]
+    fn test_len_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env, Vec};
+
+#[contractimpl]
+impl Registry {
+    pub fn size(env: Env, v: Vec<u32>) -> u32 {
+        v.len()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = IteratorLengthRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! Large event payload detection
+//!
+//! Event data is written to the ledger's event stream and paid for per byte.
+//! Publishing a whole `Vec`, `Map` or wide struct on every call bloats that
+//! stream; indexers can usually work from an id or a short summary instead.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanFunction, SorobanRule,
+};
+use crate::{RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Structs with at least this many fields count as large event payloads
+const LARGE_STRUCT_FIELDS: usize = 4;
+
+/// Rule for detecting `events().publish` calls whose data is a collection or wide struct
+pub struct LargeEventPayloadRule {
+    enabled: bool,
+}
+
+impl Default for LargeEventPayloadRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for LargeEventPayloadRule {
+    fn id(&self) -> &str {
+        "soroban-large-event-payload"
+    }
+
+    fn name(&self) -> &str {
+        "Large Event Payload"
+    }
+
+    fn description(&self) -> &str {
+        "Detects events published with a whole Vec, Map or wide struct as their data"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(contract, function));
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl LargeEventPayloadRule {
+    fn check_function(
+        &self,
+        contract: &SorobanContract,
+        function: &SorobanFunction,
+    ) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let publish_re = Regex::new(r"\bevents\(\)\s*\.\s*publish\s*\(").unwrap();
+        let types = Self::binding_types(contract, function, &stripped);
+
+        let mut violations = Vec::new();
+        for call in publish_re.find_iter(&stripped) {
+            let open = call.end() - 1;
+            let Some(close) = matching_close(&stripped, open) else {
+                continue;
+            };
+            let Some(data) = split_top_level(&body[open + 1..close]).get(1).copied() else {
+                continue;
+            };
+            let Some(kind) = Self::payload_kind(contract, &types, data) else {
+                continue;
+            };
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' publishes an event whose data '{}' is a whole {}",
+                    function.name, data, kind
+                ),
+                suggestion: "Publish an id, count or other summary and let indexers read the full value from storage".to_string(),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + call.start()),
+                column_number: 0,
+                variable_name: data.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Declared type, or failing that the initializer, of every parameter, local and field
+    fn binding_types(
+        contract: &SorobanContract,
+        function: &SorobanFunction,
+        stripped: &str,
+    ) -> HashMap<String, String> {
+        let mut types: HashMap<String, String> = contract
+            .contract_types
+            .iter()
+            .flat_map(|t| t.fields.iter())
+            .map(|f| (f.name.clone(), f.type_name.clone()))
+            .collect();
+        types.extend(
+            function
+                .params
+                .iter()
+                .map(|p| (p.name.clone(), p.type_name.clone())),
+        );
+
+        let let_re =
+            Regex::new(r"\blet\s+(?:mut\s+)?([A-Za-z_]\w*)\s*(?::\s*([^=;]+?))?\s*=\s*([^;]+);")
+                .unwrap();
+        for binding in let_re.captures_iter(stripped) {
+            let declared = binding.get(2).or(binding.get(3)).unwrap();
+            types.insert(binding[1].to_string(), declared.as_str().trim().to_string());
+        }
+
+        types
+    }
+
+    /// `Vec`, `Map` or `struct Name` when the data argument is one of those
+    fn payload_kind(
+        contract: &SorobanContract,
+        types: &HashMap<String, String>,
+        data: &str,
+    ) -> Option<String> {
+        let data = data.trim_start_matches('&').trim();
+        let data = data.strip_suffix(".clone()").unwrap_or(data);
+
+        // Bindings and fields resolve to their type; anything else is classified as written
+        let path_re = Regex::new(r"^(?:[A-Za-z_]\w*\s*\.\s*)*([A-Za-z_]\w*)$").unwrap();
+        let written = match path_re.captures(data) {
+            Some(path) => types.get(&path[1])?.as_str(),
+            None => data,
+        };
+        let written = written.trim_start_matches('&').trim();
+
+        if written.starts_with("Vec") || written.starts_with("vec!") {
+            return Some("Vec".to_string());
+        }
+        if written.starts_with("Map") || written.starts_with("map!") {
+            return Some("Map".to_string());
+        }
+        let type_name = written.split(['{', '(', ':', '<']).next()?.trim();
+        contract
+            .contract_types
+            .iter()
+            .find(|t| t.name == type_name && t.fields.len() >= LARGE_STRUCT_FIELDS)
+            .map(|t| format!("struct {} ({} fields)", t.name, t.fields.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_publishing_whole_vec_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Vec};
+
+#[contractimpl]
+impl Registry {
+    pub fn add_member(env: Env, member: Address) {
+        let mut members: Vec<Address> = env.storage().instance().get(&DataKey::Members).unwrap();
+        members.push_back(member);
+        env.storage().instance().set(&DataKey::Members, &members);
+        env.events().publish((symbol_short!("members"),), members);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LargeEventPayloadRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "members");
+        assert!(violations[0].description.contains("whole Vec"));
+    }
+
+    #[test]
+    fn test_publishing_single_id_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+#[contractimpl]
+impl Registry {
+    pub fn add_member(env: Env, member: Address, id: u32) {
+        env.storage().persistent().set(&DataKey::Member(id), &member);
+        env.events().publish((symbol_short!("added"), member), id);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LargeEventPayloadRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
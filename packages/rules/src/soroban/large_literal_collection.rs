@@ -0,0 +1,202 @@
+//! Large literal collection detection
+//!
+//! Building a long list of constants inside a contract function re-runs the
+//! construction on every invocation. Data that never changes belongs in a
+//! module-level `const` or `&'static` slice.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Number of literal elements tolerated in a single function
+const MAX_LITERAL_ELEMENTS: usize = 16;
+
+/// Rule for detecting large literal arrays and Vecs constructed at runtime
+pub struct LargeLiteralCollectionRule {
+    enabled: bool,
+}
+
+impl Default for LargeLiteralCollectionRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for LargeLiteralCollectionRule {
+    fn id(&self) -> &str {
+        "soroban-large-literal-collection"
+    }
+
+    fn name(&self) -> &str {
+        "Large Literal Collection"
+    }
+
+    fn description(&self) -> &str {
+        "Detects vec![...], array literals and literal .push() sequences with many constant elements built inside contract functions"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let numeric_re =
+            Regex::new(r"^-?(0x[0-9a-fA-F_]+|[0-9][0-9_]*)([iu](8|16|32|64|128|size))?$").unwrap();
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function, &numeric_re));
+            }
+        }
+
+        violations
+    }
+}
+
+impl LargeLiteralCollectionRule {
+    fn check_function(&self, function: &SorobanFunction, numeric_re: &Regex) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let line_of = |idx: usize| {
+            function.line_number + line_offset(&function.raw_definition, body_start + idx)
+        };
+        let mut violations = Vec::new();
+
+        // vec![...] and `= [...]` array literals
+        let literal_re = Regex::new(r"\bvec!\s*\[|=\s*&?\s*\[").unwrap();
+        for m in literal_re.find_iter(&stripped) {
+            let open_idx = m.end() - 1;
+            let Some(close_idx) = matching_close(&stripped, open_idx) else {
+                continue;
+            };
+            let count = split_top_level(&stripped[open_idx + 1..close_idx])
+                .into_iter()
+                .filter(|element| is_literal(element, numeric_re))
+                .count();
+
+            if count > MAX_LITERAL_ELEMENTS {
+                violations.push(self.violation(
+                    function,
+                    line_of(m.start()),
+                    format!(
+                        "Function '{}' builds a literal collection of {} constant elements",
+                        function.name, count
+                    ),
+                ));
+            }
+        }
+
+        // Collections filled one literal at a time
+        let push_re = Regex::new(r"\.push(?:_back|_front)?\(([^()]*)\)").unwrap();
+        let pushes: Vec<usize> = push_re
+            .captures_iter(&stripped)
+            .filter(|captures| is_literal(&captures[1], numeric_re))
+            .filter_map(|captures| captures.get(0).map(|m| m.start()))
+            .collect();
+
+        if pushes.len() > MAX_LITERAL_ELEMENTS {
+            violations.push(self.violation(
+                function,
+                line_of(pushes[0]),
+                format!(
+                    "Function '{}' pushes {} constant elements one at a time",
+                    function.name,
+                    pushes.len()
+                ),
+            ));
+        }
+
+        violations
+    }
+
+    fn violation(
+        &self,
+        function: &SorobanFunction,
+        line_number: usize,
+        description: String,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description,
+            suggestion: "Move the constant data to a module-level const or &'static slice"
+                .to_string(),
+            line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+/// Whether an element is a numeric, string, char or boolean literal
+///
+/// Expects stripped text, where string contents are already blanked, and
+/// `numeric_re` matching a whole integer literal.
+fn is_literal(element: &str, numeric_re: &Regex) -> bool {
+    let element = element.trim();
+
+    numeric_re.is_match(element)
+        || (element.len() >= 2 && element.starts_with('"') && element.ends_with('"'))
+        || (element.len() >= 2 && element.starts_with('\'') && element.ends_with('\''))
+        || element == "true"
+        || element == "false"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_twenty_element_vec_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, vec, Env, Vec};
+
+#[contractimpl]
+impl Pricing {
+    pub fn tiers(env: Env) -> Vec<u32> {
+        vec![&env, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20]
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LargeLiteralCollectionRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("20 constant elements"));
+    }
+
+    #[test]
+    fn test_small_vec_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, vec, Env, Vec};
+
+#[contractimpl]
+impl Pricing {
+    pub fn tiers(env: Env) -> Vec<u32> {
+        vec![&env, 10, 50, 100]
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LargeLiteralCollectionRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
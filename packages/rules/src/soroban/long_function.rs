@@ -0,0 +1,139 @@
+//! Long function detection
+//!
+//! Contract functions that run to many dozens of lines are hard to audit and
+//! tend to mix several storage operations whose costs would be easier to
+//! reason about in separate helpers.
+
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+
+/// Default number of lines a function may span before it is reported
+pub const DEFAULT_MAX_FUNCTION_LINES: usize = 60;
+
+/// Rule for detecting functions longer than a configurable number of lines
+pub struct LongFunctionRule {
+    enabled: bool,
+    max_lines: usize,
+}
+
+impl Default for LongFunctionRule {
+    fn default() -> Self {
+        Self::with_max_lines(DEFAULT_MAX_FUNCTION_LINES)
+    }
+}
+
+impl SorobanRule for LongFunctionRule {
+    fn id(&self) -> &str {
+        "soroban-long-function"
+    }
+
+    fn name(&self) -> &str {
+        "Long Function"
+    }
+
+    fn description(&self) -> &str {
+        "Detects contract functions whose definition spans more lines than the configured maximum"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .filter_map(|function| self.check_function(function))
+            .collect()
+    }
+}
+
+impl LongFunctionRule {
+    /// Rule reporting functions longer than `max_lines`
+    pub fn with_max_lines(max_lines: usize) -> Self {
+        Self {
+            enabled: true,
+            max_lines,
+        }
+    }
+
+    fn check_function(&self, function: &SorobanFunction) -> Option<RuleViolation> {
+        let length = function.raw_definition.lines().count();
+        if length <= self.max_lines {
+            return None;
+        }
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' is {} lines long (maximum {})",
+                function.name, length, self.max_lines
+            ),
+            suggestion: "Split the function into smaller helpers, e.g. one per storage operation, so each can be audited and costed on its own".to_string(),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    fn contract_with_function_of(body_lines: usize) -> SorobanContract {
+        let body: String = (0..body_lines)
+            .map(|i| format!("        total += {};\n", i))
+            .collect();
+        let source = format!(
+            "use soroban_sdk::{{contract, contractimpl, Env}};\n\n#[contractimpl]\nimpl Batch {{\n    pub fn run(env: Env) -> u32 {{\n        let mut total = 0u32;\n{}        total\n    }}\n}}\n",
+            body
+        );
+        SorobanParser::parse_contract(&source, "test.rs").unwrap()
+    }
+
+    #[test]
+    fn test_long_function_is_flagged() {
+        // Signature, `let`, 66 statements, tail expression and closing brace
+        let contract = contract_with_function_of(66);
+        let violations = LongFunctionRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("70 lines long"));
+    }
+
+    #[test]
+    fn test_short_function_is_not_flagged() {
+        let contract = contract_with_function_of(5);
+        let violations = LongFunctionRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_max_lines_can_be_lowered() {
+        let contract = contract_with_function_of(5);
+        let violations = LongFunctionRule::with_max_lines(5).apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0]
+            .description
+            .contains("9 lines long (maximum 5)"));
+    }
+}
@@ -0,0 +1,211 @@
+//! Growing `Bytes`/`Vec` inside loops
+//!
+//! Host collections are immutable objects behind the scenes: every
+//! `.append(`/`.push_back(` on a `Bytes` or `Vec` builds a new host object.
+//! Doing it once per loop iteration repeats that host work for every element.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, loop_bodies, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap};
+
+/// Rule for detecting `Bytes`/`Vec` grown one element at a time inside a loop
+pub struct LoopAppendRule {
+    enabled: bool,
+}
+
+impl Default for LoopAppendRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for LoopAppendRule {
+    fn id(&self) -> &str {
+        "soroban-loop-append"
+    }
+
+    fn name(&self) -> &str {
+        "Collection Append in Loop"
+    }
+
+    fn description(&self) -> &str {
+        "Detects Bytes/Vec values grown with append or push_back inside a loop body"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl LoopAppendRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let receivers = Self::growable_receivers(function, &stripped);
+        if receivers.is_empty() {
+            return Vec::new();
+        }
+        let call_re = Regex::new(r"\b([A-Za-z_]\w*)\s*\.\s*(append|push_back)\s*\(").unwrap();
+
+        // Nested loops overlap, so collect call sites before reporting them
+        let mut calls = BTreeSet::new();
+        for (start, end) in loop_bodies(&stripped) {
+            for call in call_re.captures_iter(&stripped[start..end]) {
+                let receiver = call.get(1).unwrap();
+                if receivers.contains_key(receiver.as_str()) {
+                    calls.insert((
+                        start + receiver.start(),
+                        receiver.as_str(),
+                        call.get(2).unwrap().as_str(),
+                    ));
+                }
+            }
+        }
+
+        calls
+            .into_iter()
+            .map(|(offset, receiver, method)| RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' calls {}.{}() on {} '{}' once per loop iteration",
+                    function.name, receiver, method, receivers[receiver], receiver
+                ),
+                suggestion: format!(
+                    "Build '{}' in one call (e.g. from_array/from_slice over the collected data) instead of growing it inside the loop",
+                    receiver
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + offset),
+                column_number: 0,
+                variable_name: receiver.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+
+    /// Parameters and locals typed as `Bytes` or `Vec`, mapped to that type
+    ///
+    /// Locals created with `with_capacity` are already pre-sized and left out.
+    fn growable_receivers<'a>(
+        function: &'a SorobanFunction,
+        stripped: &'a str,
+    ) -> HashMap<&'a str, &'static str> {
+        let type_of = |text: &str| {
+            let text = text
+                .trim_start_matches('&')
+                .trim_start_matches("mut ")
+                .trim();
+            if text.starts_with("Bytes") || text.starts_with("bytes!") {
+                Some("Bytes")
+            } else if text.starts_with("Vec") || text.starts_with("vec!") {
+                Some("Vec")
+            } else {
+                None
+            }
+        };
+
+        let mut receivers: HashMap<&str, &'static str> = function
+            .params
+            .iter()
+            .filter_map(|p| Some((p.name.as_str(), type_of(&p.type_name)?)))
+            .collect();
+
+        let let_re =
+            Regex::new(r"\blet\s+(?:mut\s+)?([A-Za-z_]\w*)\s*(?::\s*([^=;]+?))?\s*=\s*([^;]+);")
+                .unwrap();
+        for binding in let_re.captures_iter(stripped) {
+            let name = binding.get(1).unwrap().as_str();
+            let init = binding.get(3).unwrap().as_str();
+            let declared = binding.get(2).and_then(|t| type_of(t.as_str()));
+            match declared.or_else(|| type_of(init)) {
+                Some(type_name) if !init.contains("with_capacity") => {
+                    receivers.insert(name, type_name);
+                }
+                _ => {
+                    receivers.remove(name);
+                }
+            }
+        }
+
+        receivers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_bytes_grown_in_loop_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Bytes, Env, Vec};
+
+#[contractimpl]
+impl Encoder {
+    pub fn encode(env: Env, values: Vec<u32>) -> Bytes {
+        let mut out = Bytes::new(&env);
+        for value in values.iter() {
+            out.append(&Bytes::from_array(&env, &value.to_be_bytes()));
+        }
+        out
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LoopAppendRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "out");
+        assert!(violations[0].description.contains("out.append()"));
+    }
+
+    #[test]
+    fn test_single_construction_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Bytes, Env};
+
+#[contractimpl]
+impl Encoder {
+    pub fn encode(env: Env, a: u32, b: u32) -> Bytes {
+        let mut buf = [0u8; 8];
+        buf[..4].copy_from_slice(&a.to_be_bytes());
+        buf[4..].copy_from_slice(&b.to_be_bytes());
+        Bytes::from_slice(&env, &buf)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LoopAppendRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+//! Lost storage update detection
+//!
+//! A storage `.get()` returns an owned copy of the stored value. Updating a
+//! field of that copy changes nothing on the ledger until the copy is
+//! written back with `.set()`; forgetting the write silently drops the update.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting storage reads whose fields are modified but never written back
+pub struct LostStorageUpdateRule {
+    enabled: bool,
+}
+
+impl Default for LostStorageUpdateRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for LostStorageUpdateRule {
+    fn id(&self) -> &str {
+        "soroban-lost-storage-update"
+    }
+
+    fn name(&self) -> &str {
+        "Lost Storage Update"
+    }
+
+    fn description(&self) -> &str {
+        "Detects values read from storage whose fields are modified without the value being written back"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl LostStorageUpdateRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let read_re = Regex::new(
+            r"\blet\s+mut\s+(\w+)\s*(?::[^=;]*)?=[^;]*\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*get\b[^;]*;",
+        )
+        .unwrap();
+
+        let mut violations = Vec::new();
+        for read in read_re.captures_iter(&stripped) {
+            let (Some(whole), Some(name)) = (read.get(0), read.get(1)) else {
+                continue;
+            };
+            let name = name.as_str();
+            let rest = &stripped[whole.end()..];
+            let mutation_re = Regex::new(&format!(
+                r"\b{}\s*\.\s*(\w+)\s*(?:[-+*/%]=|=[^=])",
+                regex::escape(name)
+            ))
+            .unwrap();
+            let Some(mutation) = mutation_re.captures(rest) else {
+                continue;
+            };
+            let (Some(assignment), Some(field)) = (mutation.get(0), mutation.get(1)) else {
+                continue;
+            };
+            if Self::is_handed_off(&rest[assignment.start()..], name) {
+                continue;
+            }
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' updates '{}.{}' on a value read from storage but never writes '{}' back",
+                    function.name,
+                    name,
+                    field.as_str(),
+                    name
+                ),
+                suggestion: format!(
+                    "Write the updated value back with env.storage().<bucket>().set(&key, &{}) before returning",
+                    name
+                ),
+                line_number: function.line_number
+                    + line_offset(
+                        &function.raw_definition,
+                        body_start + whole.end() + assignment.start(),
+                    ),
+                column_number: 0,
+                variable_name: name.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Whether `name` is used in `text` other than through a field access
+    ///
+    /// Passing the value to `.set(`, a helper or a method, or returning it,
+    /// may persist it, so any such use counts as a write-back.
+    fn is_handed_off(text: &str, name: &str) -> bool {
+        let field_re = Regex::new(r"^\s*\.\s*\w+\s*(?:[^\w\s(]|$)").unwrap();
+        let is_ident_byte = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+
+        text.match_indices(name).any(|(idx, _)| {
+            let before = idx.checked_sub(1).map(|i| text.as_bytes()[i]);
+            let end = idx + name.len();
+            let after = text.as_bytes().get(end).copied();
+            if before.is_some_and(|b| is_ident_byte(b) || b == b'.')
+                || after.is_some_and(is_ident_byte)
+            {
+                return false;
+            }
+            !field_re.is_match(&text[end..])
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_update_without_write_back_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn deposit(env: Env, amount: i128) {
+        let mut state: State = env.storage().instance().get(&DataKey::State).unwrap();
+        state.total += amount;
+        state.deposits = state.deposits + 1;
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LostStorageUpdateRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "state");
+        assert_eq!(violations[0].line_number, 8);
+    }
+
+    #[test]
+    fn test_read_modify_write_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn deposit(env: Env, amount: i128) {
+        let mut state: State = env.storage().instance().get(&DataKey::State).unwrap();
+        state.total += amount;
+        env.storage().instance().set(&DataKey::State, &state);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = LostStorageUpdateRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
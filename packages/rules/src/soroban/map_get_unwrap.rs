@@ -0,0 +1,166 @@
+//! Unhandled `Map`/storage read detection
+//!
+//! `get` on a `Map` or a storage bucket returns `Option`; unwrapping it
+//! directly traps the whole invocation when the key is absent.
+
+use crate::soroban::helpers::{line_offset, matching_close, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+
+/// Rule for detecting `.get(key).unwrap()` on maps and storage
+pub struct MapGetUnwrapRule {
+    enabled: bool,
+}
+
+impl Default for MapGetUnwrapRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for MapGetUnwrapRule {
+    fn id(&self) -> &str {
+        "soroban-map-get-unwrap"
+    }
+
+    fn name(&self) -> &str {
+        "Unhandled Map Read"
+    }
+
+    fn description(&self) -> &str {
+        "Detects Map or storage reads that unwrap the Option result instead of handling a missing key"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                for offset in Self::unwrapped_get_offsets(&function.raw_definition) {
+                    violations.push(self.violation(function, offset));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl MapGetUnwrapRule {
+    /// Line offsets of every `.get(...)` immediately followed by `.unwrap()`
+    ///
+    /// Comments and string literals are ignored.
+    pub(crate) fn unwrapped_get_offsets(source: &str) -> Vec<usize> {
+        let stripped = strip_comments_and_strings(source);
+        let mut offsets = Vec::new();
+
+        for (idx, _) in stripped.match_indices(".get(") {
+            let open_idx = idx + ".get".len();
+            let Some(close_idx) = matching_close(&stripped, open_idx) else {
+                continue;
+            };
+
+            let rest = stripped[close_idx + 1..].trim_start();
+            if rest.starts_with(".unwrap()") {
+                offsets.push(line_offset(&stripped, idx));
+            }
+        }
+
+        offsets
+    }
+
+    fn violation(&self, function: &SorobanFunction, offset: usize) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' unwraps a Map/storage read, which panics when the key is missing",
+                function.name
+            ),
+            suggestion: "Use unwrap_or/unwrap_or_default for an explicit default, or return a contract error when the key is required".to_string(),
+            line_number: function.line_number + offset,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_get_unwrap_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Map};
+
+#[contractimpl]
+impl Ledger {
+    pub fn balance(env: Env, balances: Map<Address, i128>, who: Address) -> i128 {
+        balances.get(who).unwrap()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MapGetUnwrapRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "balance");
+    }
+
+    #[test]
+    fn test_get_unwrap_or_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Map};
+
+#[contractimpl]
+impl Ledger {
+    pub fn balance(env: Env, balances: Map<Address, i128>, who: Address) -> i128 {
+        balances.get(who).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MapGetUnwrapRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_get_unwrap_in_comment_or_string_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Map};
+
+#[contractimpl]
+impl Ledger {
+    pub fn balance(env: Env, balances: Map<Address, i128>, who: Address) -> i128 {
+        // balances.get(who.clone()).unwrap()
+        log!(&env, "never call balances.get(who).unwrap()");
+        balances.get(who).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MapGetUnwrapRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+//! Missing authorization detection
+//!
+//! A contract function that writes storage is callable by anyone unless it
+//! asks the host to authorize a caller. Privileged entry points such as
+//! `set_admin`, `mint` or `withdraw` that never call `require_auth` let any
+//! account take over the contract or move its funds.
+
+use crate::soroban::helpers::{function_body, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Name segments marking a function as a privileged action
+const PRIVILEGED_SEGMENTS: [&str; 9] = [
+    "set", "mint", "burn", "transfer", "withdraw", "admin", "upgrade", "pause", "unpause",
+];
+
+/// Rule for detecting privileged, storage-mutating functions that never call `require_auth`
+pub struct MissingAuthCheckRule {
+    enabled: bool,
+}
+
+impl Default for MissingAuthCheckRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for MissingAuthCheckRule {
+    fn id(&self) -> &str {
+        "soroban-missing-auth-check"
+    }
+
+    fn name(&self) -> &str {
+        "Missing Auth Check"
+    }
+
+    fn description(&self) -> &str {
+        "Detects privileged functions such as set_admin, mint or withdraw that write storage without calling require_auth"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl MissingAuthCheckRule {
+    fn check_function(&self, function: &SorobanFunction) -> Option<RuleViolation> {
+        // One-time setup runs before there is anyone to authorize
+        if function.is_constructor
+            || matches!(
+                function.name.as_str(),
+                "init" | "initialize" | "__constructor"
+            )
+        {
+            return None;
+        }
+        let action = function
+            .name
+            .split('_')
+            .find(|segment| PRIVILEGED_SEGMENTS.contains(segment))?;
+
+        let body = function_body(&function.raw_definition)?;
+        let stripped = strip_comments_and_strings(body);
+        let write_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*(?:set|update|remove)\b|\.\s*store\s*\(",
+        )
+        .unwrap();
+        // Auth is often delegated to a helper such as `require_admin(&env)`
+        let auth_re = Regex::new(
+            r"\w*auth\w*\s*\(|\b(?:require|ensure|check|only)_\w*(?:admin|owner)\w*\s*\(",
+        )
+        .unwrap();
+        if !write_re.is_match(&stripped) || auth_re.is_match(&stripped) {
+            return None;
+        }
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' performs a privileged '{}' action that writes storage without calling require_auth",
+                function.name, action
+            ),
+            suggestion: "Load the admin or owner address and call require_auth() on it before changing storage".to_string(),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_set_admin_without_auth_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn set_admin(env: Env, new_admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingAuthCheckRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "set_admin");
+        assert_eq!(violations[0].severity, ViolationSeverity::High);
+    }
+
+    #[test]
+    fn test_authorized_set_admin_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+    }
+
+    pub fn deposit(env: Env, amount: i128) {
+        env.storage().instance().set(&DataKey::Total, &amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingAuthCheckRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,134 @@
+//! Missing contract documentation detection
+//!
+//! Auditors start from what a contract promises: who may call what, and
+//! which relations between stored values always hold. A `///` comment on the
+//! `#[contract]` struct is the natural home for that description. This rule
+//! is a style check, so only the strict profile runs it.
+
+use crate::soroban::{SorobanContract, SorobanRule};
+use crate::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+
+/// Rule for detecting `#[contract]` structs without a doc comment
+pub struct MissingContractDocRule {
+    enabled: bool,
+}
+
+impl Default for MissingContractDocRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for MissingContractDocRule {
+    fn id(&self) -> &str {
+        "soroban-missing-contract-doc"
+    }
+
+    fn name(&self) -> &str {
+        "Missing Contract Documentation"
+    }
+
+    fn description(&self) -> &str {
+        "Detects #[contract] structs with no doc comment describing the contract's purpose and invariants"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let Some(contract_struct) = &contract.contract_struct else {
+            return Vec::new();
+        };
+        if contract_struct.doc.iter().any(|line| !line.is_empty()) {
+            return Vec::new();
+        }
+
+        vec![RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Contract struct '{}' has no doc comment describing its purpose or invariants",
+                contract_struct.name
+            ),
+            suggestion: "Add a `///` comment above the #[contract] struct stating what the contract does, who may call its privileged functions and which invariants its storage keeps".to_string(),
+            line_number: contract_struct.line_number,
+            column_number: 0,
+            variable_name: contract_struct.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }]
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: usize::from(contract.contract_struct.is_some()),
+            uninspected: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_undocumented_contract_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct Escrow;
+
+#[contractimpl]
+impl Escrow {
+    pub fn version(env: Env) -> u32 {
+        1
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingContractDocRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "Escrow");
+        assert_eq!(violations[0].line_number, 5);
+    }
+
+    #[test]
+    fn test_documented_contract_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+/// Holds a buyer's deposit until the seller delivers.
+///
+/// Invariant: the escrowed balance equals the sum of open deposits.
+#[contract]
+pub struct Escrow;
+
+#[contractimpl]
+impl Escrow {
+    pub fn version(env: Env) -> u32 {
+        1
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingContractDocRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+        assert_eq!(contract.contract_struct.unwrap().doc.len(), 3);
+    }
+}
@@ -0,0 +1,137 @@
+//! Missing contract metadata detection
+//!
+//! `contractmeta!(key = "...", val = "...")` embeds key/value metadata in the
+//! compiled Wasm, which explorers and registries read to describe a published
+//! contract. Without it the contract ships with no description at all.
+
+use crate::soroban::helpers::{line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanRule};
+use crate::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `#[contract]` sources that declare no contract metadata
+pub struct MissingContractMetaRule {
+    enabled: bool,
+}
+
+impl Default for MissingContractMetaRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for MissingContractMetaRule {
+    fn id(&self) -> &str {
+        "soroban-missing-contract-meta"
+    }
+
+    fn name(&self) -> &str {
+        "Missing Contract Metadata"
+    }
+
+    fn description(&self) -> &str {
+        "Detects contracts that declare no contractmeta! metadata for publishing"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let stripped = strip_comments_and_strings(&contract.source);
+        let contract_re = Regex::new(r"#\[\s*contract\s*\]").unwrap();
+        let meta_re = Regex::new(r"\bcontractmeta\s*!|#\[\s*contractmeta\b").unwrap();
+
+        let Some(attribute) = contract_re.find(&stripped) else {
+            return Vec::new();
+        };
+        if meta_re.is_match(&stripped) {
+            return Vec::new();
+        }
+
+        vec![RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Contract '{}' declares no contractmeta! metadata",
+                contract.name
+            ),
+            suggestion: "Add contractmeta!(key = \"Description\", val = \"...\") so explorers can describe the published contract".to_string(),
+            line_number: 1 + line_offset(&contract.source, attribute.start()),
+            column_number: 0,
+            variable_name: contract.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }]
+    }
+
+    fn coverage(&self, _contract: &SorobanContract) -> RuleCoverage {
+        // The whole source is searched, so there is nothing the parser can miss
+        RuleCoverage {
+            inspected: 1,
+            uninspected: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_contract_without_meta_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct Token;
+
+#[contractimpl]
+impl Token {
+    pub fn decimals(env: Env) -> u32 {
+        7
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingContractMetaRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line_number, 4);
+    }
+
+    #[test]
+    fn test_contract_with_meta_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contractmeta, Env};
+
+contractmeta!(key = "Description", val = "Fixed-supply token");
+
+#[contract]
+pub struct Token;
+
+#[contractimpl]
+impl Token {
+    pub fn decimals(env: Env) -> u32 {
+        7
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingContractMetaRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,184 @@
+//! Missing `#[contracttype]` enum detection
+//!
+//! Values written to contract storage must implement the SDK's conversion
+//! traits, which `#[contracttype]` derives. A plain `enum` used as a storage
+//! key or value, or inside a stored struct, does not serialize.
+
+use crate::soroban::helpers::{
+    contains_identifier, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{function_coverage, type_coverage, SorobanContract, SorobanEnum, SorobanRule};
+use crate::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting enums used in storage positions without `#[contracttype]`
+pub struct MissingContractTypeEnumRule {
+    enabled: bool,
+}
+
+impl Default for MissingContractTypeEnumRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for MissingContractTypeEnumRule {
+    fn id(&self) -> &str {
+        "soroban-missing-contracttype-enum"
+    }
+
+    fn name(&self) -> &str {
+        "Missing contracttype on Enum"
+    }
+
+    fn description(&self) -> &str {
+        "Detects locally defined enums used as storage keys, values or stored fields without #[contracttype]"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Error
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        contract
+            .enums
+            .iter()
+            .filter(|e| !e.is_contract_type)
+            .filter_map(|e| {
+                let usage = Self::storage_usage(contract, e)?;
+                Some(self.violation(e, &usage))
+            })
+            .collect()
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl MissingContractTypeEnumRule {
+    /// Where the enum first appears in a storage position, if anywhere
+    fn storage_usage(contract: &SorobanContract, enum_def: &SorobanEnum) -> Option<String> {
+        for contract_type in &contract.contract_types {
+            if let Some(field) = contract_type
+                .fields
+                .iter()
+                .find(|f| contains_identifier(&f.type_name, &enum_def.name))
+            {
+                return Some(format!(
+                    "field '{}.{}: {}'",
+                    contract_type.name, field.name, field.type_name
+                ));
+            }
+        }
+
+        let access_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*(?:get|try_get|set|has|remove|update|extend_ttl)(?:::<[^>]*>)?\(",
+        )
+        .unwrap();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                let raw = &function.raw_definition;
+                let stripped = strip_comments_and_strings(raw);
+
+                for call in access_re.find_iter(&stripped) {
+                    let Some(close_idx) = matching_close(&stripped, call.end() - 1) else {
+                        continue;
+                    };
+                    if contains_identifier(&stripped[call.start()..close_idx], &enum_def.name) {
+                        return Some(format!(
+                            "storage access in '{}' at line {}",
+                            function.name,
+                            function.line_number + line_offset(raw, call.start())
+                        ));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn violation(&self, enum_def: &SorobanEnum, usage: &str) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Enum '{}' is used in storage ({}) but is not marked #[contracttype] and will not serialize",
+                enum_def.name, usage
+            ),
+            suggestion: format!("Add #[contracttype] to enum '{}'", enum_def.name),
+            line_number: enum_def.line_number,
+            column_number: 0,
+            variable_name: enum_def.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_plain_enum_used_as_data_key_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+}
+
+#[contractimpl]
+impl Token {
+    pub fn set_admin(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingContractTypeEnumRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "DataKey");
+        assert!(violations[0].description.contains("set_admin"));
+    }
+
+    #[test]
+    fn test_contracttype_enum_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+}
+
+#[contractimpl]
+impl Token {
+    pub fn set_admin(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingContractTypeEnumRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
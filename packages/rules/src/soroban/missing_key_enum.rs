@@ -0,0 +1,206 @@
+//! Missing storage key enum detection
+//!
+//! Contracts that address storage with bare `symbol_short!` symbols, string
+//! symbols or tuples spread the key space across every call site, so a typo
+//! or reused name silently aliases two entries. A `#[contracttype]` key enum
+//! keeps every key in one checked definition.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// SDK types whose associated constructors build ad-hoc keys rather than naming a key enum
+const SDK_KEY_TYPES: [&str; 5] = ["Symbol", "String", "Bytes", "BytesN", "Address"];
+
+/// Rule for detecting contracts whose storage keys are ad-hoc values rather than a key enum
+pub struct MissingKeyEnumRule {
+    enabled: bool,
+}
+
+impl Default for MissingKeyEnumRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for MissingKeyEnumRule {
+    fn id(&self) -> &str {
+        "soroban-missing-key-enum"
+    }
+
+    fn name(&self) -> &str {
+        "Missing Storage Key Enum"
+    }
+
+    fn description(&self) -> &str {
+        "Detects contracts that access storage from several functions using bare symbols or tuples as keys and no key enum"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let access_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*(?:get|set|has|remove|update|extend_ttl)(?:::<[^>]*>)?\s*\(",
+        )
+        .unwrap();
+        let enum_key_re = Regex::new(r"^([A-Z]\w*)::[A-Z]\w*").unwrap();
+
+        let mut storage_functions = 0;
+        let mut first_ad_hoc: Option<(String, usize)> = None;
+        for function in contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+        {
+            let Some(body) = function_body(&function.raw_definition) else {
+                continue;
+            };
+            let body_start = function.raw_definition.len() - body.len();
+            let stripped = strip_comments_and_strings(body);
+
+            let mut touches_storage = false;
+            for call in access_re.find_iter(&stripped) {
+                touches_storage = true;
+                let open = call.end() - 1;
+                let Some(close) = matching_close(&stripped, open) else {
+                    continue;
+                };
+                let Some(key) = split_top_level(&body[open + 1..close]).first().copied() else {
+                    continue;
+                };
+                let key = key.trim_start_matches('&').trim();
+
+                if let Some(captures) = enum_key_re.captures(key) {
+                    if !SDK_KEY_TYPES.contains(&&captures[1]) {
+                        // The contract already routes its keys through an enum
+                        return Vec::new();
+                    }
+                }
+                if first_ad_hoc.is_none() && Self::is_ad_hoc_key(key) {
+                    let line = function.line_number
+                        + line_offset(&function.raw_definition, body_start + call.start());
+                    first_ad_hoc = Some((key.to_string(), line));
+                }
+            }
+            if touches_storage {
+                storage_functions += 1;
+            }
+        }
+
+        let Some((key, line_number)) = first_ad_hoc else {
+            return Vec::new();
+        };
+        if storage_functions < 2 {
+            return Vec::new();
+        }
+
+        vec![RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Contract '{}' accesses storage from {} functions with ad-hoc keys such as '{}' and no key enum",
+                contract.name, storage_functions, key
+            ),
+            suggestion: "Define a `#[contracttype] pub enum DataKey { ... }` with one variant per entry and pass `&DataKey::Variant` to every storage call".to_string(),
+            line_number,
+            column_number: 0,
+            variable_name: contract.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }]
+    }
+}
+
+impl MissingKeyEnumRule {
+    /// Whether `key` is a symbol, string or tuple written at the call site, or a constant holding one
+    fn is_ad_hoc_key(key: &str) -> bool {
+        let is_constant = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+
+        key.starts_with("symbol_short!")
+            || key.starts_with('(')
+            || key.starts_with('"')
+            || SDK_KEY_TYPES
+                .iter()
+                .any(|ty| key.starts_with(&format!("{}::", ty)))
+            || is_constant
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_ad_hoc_keys_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+
+const ADMIN: Symbol = symbol_short!("ADMIN");
+
+#[contractimpl]
+impl Vault {
+    pub fn init(env: Env, admin: Address) {
+        env.storage().instance().set(&ADMIN, &admin);
+    }
+
+    pub fn deposit(env: Env, user: Address, amount: i128) {
+        let key = (symbol_short!("BAL"), user);
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&(symbol_short!("BAL"), user), &(balance + amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingKeyEnumRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("from 2 functions"));
+        assert!(violations[0].description.contains("'ADMIN'"));
+    }
+
+    #[test]
+    fn test_enum_keyed_storage_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Balance(Address),
+}
+
+#[contractimpl]
+impl Vault {
+    pub fn init(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    pub fn deposit(env: Env, user: Address, amount: i128) {
+        let key = DataKey::Balance(user);
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MissingKeyEnumRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+//! Mixed authorization form detection
+//!
+//! `require_auth_for_args` scopes what the signer approved to specific
+//! arguments, while `require_auth` approves the whole invocation. A contract
+//! that uses both usually meant to scope every value-moving call and missed
+//! some, leaving those approvals broader than the rest.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting contracts mixing `require_auth()` with `require_auth_for_args()`
+pub struct MixedAuthFormRule {
+    enabled: bool,
+}
+
+impl Default for MixedAuthFormRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for MixedAuthFormRule {
+    fn id(&self) -> &str {
+        "soroban-mixed-auth-form"
+    }
+
+    fn name(&self) -> &str {
+        "Mixed Authorization Form"
+    }
+
+    fn description(&self) -> &str {
+        "Detects functions using argument-less require_auth() in contracts that scope other approvals with require_auth_for_args()"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let scoped_re = Regex::new(r"\.\s*require_auth_for_args\s*\(").unwrap();
+        let functions: Vec<&SorobanFunction> = contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .collect();
+
+        let scoped: Vec<&str> = functions
+            .iter()
+            .filter(|f| {
+                function_body(&f.raw_definition)
+                    .is_some_and(|body| scoped_re.is_match(&strip_comments_and_strings(body)))
+            })
+            .map(|f| f.name.as_str())
+            .collect();
+        if scoped.is_empty() {
+            return Vec::new();
+        }
+
+        functions
+            .iter()
+            .filter_map(|function| self.check_function(function, &scoped))
+            .collect()
+    }
+}
+
+impl MixedAuthFormRule {
+    fn check_function(&self, function: &SorobanFunction, scoped: &[&str]) -> Option<RuleViolation> {
+        let body = function_body(&function.raw_definition)?;
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let plain_re = Regex::new(r"\.\s*require_auth\s*\(\s*\)").unwrap();
+        let balance_write_re = Regex::new(r"(?i)\.\s*set\s*\([^;]*balance").unwrap();
+
+        let call = plain_re.find(&stripped)?;
+        let (description, suggestion) = if balance_write_re.is_match(&stripped) {
+            (
+                format!(
+                    "Function '{}' updates balances after an argument-less require_auth(), while '{}' scopes its approval with require_auth_for_args()",
+                    function.name, scoped[0]
+                ),
+                "Use require_auth_for_args() with the amount and counterparty so the signer approves exactly this balance change".to_string(),
+            )
+        } else {
+            (
+                format!(
+                    "Function '{}' uses require_auth() while '{}' uses require_auth_for_args()",
+                    function.name, scoped[0]
+                ),
+                "Pick one authorization form for similar operations, or document why this call approves all of its arguments".to_string(),
+            )
+        };
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description,
+            suggestion,
+            line_number: function.line_number
+                + line_offset(&function.raw_definition, body_start + call.start()),
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_mixed_auth_forms_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth_for_args((to.clone(), amount).into_val(&env));
+        move_balance(&env, &from, &to, amount);
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let balance: i128 = env.storage().persistent().get(&DataKey::Balance(from.clone())).unwrap();
+        env.storage().persistent().set(&DataKey::Balance(from), &(balance - amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MixedAuthFormRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "burn");
+        assert!(violations[0].description.contains("updates balances"));
+    }
+
+    #[test]
+    fn test_consistent_auth_form_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        move_balance(&env, &from, &to, amount);
+    }
+
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let balance: i128 = env.storage().persistent().get(&DataKey::Balance(from.clone())).unwrap();
+        env.storage().persistent().set(&DataKey::Balance(from), &(balance - amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MixedAuthFormRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! Mixed storage bucket detection
+//!
+//! Instance, persistent and temporary storage are separate key spaces. A key
+//! written through `instance()` and read back through `persistent()` is never
+//! found, so the read silently falls back to its default.
+
+use crate::soroban::helpers::{
+    line_offset, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Rule for detecting storage keys accessed through more than one bucket
+pub struct MixedStorageBucketRule {
+    enabled: bool,
+}
+
+impl Default for MixedStorageBucketRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Where a key was first seen in a given bucket
+struct Access {
+    function: String,
+    line_number: usize,
+}
+
+impl SorobanRule for MixedStorageBucketRule {
+    fn id(&self) -> &str {
+        "soroban-mixed-storage-bucket"
+    }
+
+    fn name(&self) -> &str {
+        "Mixed Storage Bucket"
+    }
+
+    fn description(&self) -> &str {
+        "Detects storage keys that are read or written through more than one of instance/persistent/temporary storage"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let access_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(instance|persistent|temporary)\(\)\s*\.\s*(?:get|try_get|set|has|remove|update|extend_ttl)(?:::<[^>]*>)?\(",
+        )
+        .unwrap();
+
+        // key -> bucket -> first access
+        let mut keys: BTreeMap<String, BTreeMap<String, Access>> = BTreeMap::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                let raw = &function.raw_definition;
+                let stripped = strip_comments_and_strings(raw);
+
+                for captures in access_re.captures_iter(&stripped) {
+                    let (Some(call), Some(bucket)) = (captures.get(0), captures.get(1)) else {
+                        continue;
+                    };
+                    let open_idx = call.end() - 1;
+                    let Some(close_idx) = matching_close(&stripped, open_idx) else {
+                        continue;
+                    };
+                    let Some(key) = split_top_level(&raw[open_idx + 1..close_idx])
+                        .first()
+                        .copied()
+                        .and_then(Self::key_literal)
+                    else {
+                        continue;
+                    };
+
+                    keys.entry(key)
+                        .or_default()
+                        .entry(bucket.as_str().to_string())
+                        .or_insert(Access {
+                            function: function.name.clone(),
+                            line_number: function.line_number + line_offset(raw, call.start()),
+                        });
+                }
+            }
+        }
+
+        keys.into_iter()
+            .filter(|(_, buckets)| buckets.len() > 1)
+            .map(|(key, buckets)| self.violation(&key, &buckets))
+            .collect()
+    }
+}
+
+impl MixedStorageBucketRule {
+    /// Normalized key text, if it names a fixed key rather than a local variable
+    fn key_literal(argument: &str) -> Option<String> {
+        let key: String = argument
+            .trim_start_matches('&')
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect();
+        let const_re = Regex::new(r"^[A-Z][A-Z0-9_]*$").unwrap();
+
+        (key.contains("!(") || key.contains("::") || const_re.is_match(&key)).then_some(key)
+    }
+
+    fn violation(&self, key: &str, buckets: &BTreeMap<String, Access>) -> RuleViolation {
+        let usages: Vec<String> = buckets
+            .iter()
+            .map(|(bucket, access)| format!("{}() in '{}'", bucket, access.function))
+            .collect();
+        // Report at the last access, which is the one most likely to miss the entry
+        let line_number = buckets.values().map(|a| a.line_number).max().unwrap_or(0);
+
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Storage key '{}' is accessed through different buckets: {}",
+                key,
+                usages.join(", ")
+            ),
+            suggestion: "Pick one storage bucket for this key and use it for every read and write"
+                .to_string(),
+            line_number,
+            column_number: 0,
+            variable_name: key.to_string(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_key_written_to_instance_and_read_from_persistent_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+#[contractimpl]
+impl Config {
+    pub fn set_fee(env: Env, fee: u32) {
+        env.storage().instance().set(&symbol_short!("fee"), &fee);
+    }
+
+    pub fn fee(env: Env) -> u32 {
+        env.storage().persistent().get(&symbol_short!("fee")).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MixedStorageBucketRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0]
+            .description
+            .contains("instance() in 'set_fee'"));
+        assert!(violations[0].description.contains("persistent() in 'fee'"));
+    }
+
+    #[test]
+    fn test_consistent_bucket_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+#[contractimpl]
+impl Config {
+    pub fn set_fee(env: Env, fee: u32) {
+        env.storage().instance().set(&symbol_short!("fee"), &fee);
+    }
+
+    pub fn fee(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("fee")).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = MixedStorageBucketRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -7,26 +7,154 @@
 pub mod parser;
 pub mod analyzer;
 pub mod rule_engine;
+pub(crate) mod helpers;
+pub mod address_as_string;
+pub mod call_before_state_update;
+pub mod map_get_unwrap;
+pub mod unused_parameter;
+pub mod client_interface_mismatch;
+pub mod repeated_storage_handle;
+pub mod unfinished_code;
+pub mod repeated_admin_read;
+pub mod large_literal_collection;
+pub mod string_map_key;
+pub mod redundant_counter;
+pub mod ignored_constructor_param;
+pub mod repeated_option_match;
+pub mod mixed_storage_bucket;
+pub mod storage_len_loop;
+pub mod missing_contracttype_enum;
+pub mod hardcoded_address;
+pub mod inconsistent_error_type;
+pub mod pure_contract_function;
+pub mod iterator_length;
+pub mod panic_in_result_fn;
+pub mod timestamp_type;
+pub mod asymmetric_conditional_write;
+pub mod redundant_auth_check;
+pub mod loop_append;
+pub mod missing_contract_meta;
+pub mod large_event_payload;
+pub mod env_clone;
+pub mod duplicate_function;
+pub mod long_function;
+pub mod unannotated_storage_get;
+pub mod missing_key_enum;
+pub mod default_collection_derive;
+pub mod uncapped_mint;
+pub mod mixed_auth_form;
+pub mod reference_return;
+pub mod storage_read_clone;
+pub mod missing_contract_doc;
+pub mod unseparated_literal;
+pub mod inconsistent_ttl;
+pub mod aggregate_by_iteration;
+pub mod exported_test_helper;
+pub mod storage_access_in_loop;
+pub mod over_widened_integer;
+pub mod returned_collection_clone;
+pub mod per_user_instance_storage;
+pub mod blind_struct_write;
+pub mod unchecked_transfer_from;
+pub mod storage_key_typo;
+pub mod unbounded_capacity;
+pub mod missing_auth_check;
+pub mod constructed_auth_address;
+pub mod repeated_config_read;
+pub mod getter_unwrap;
+pub mod lost_storage_update;
 
 pub use parser::*;
 pub use analyzer::*;
 pub use rule_engine::*;
+pub use address_as_string::*;
+pub use call_before_state_update::*;
+pub use map_get_unwrap::*;
+pub use unused_parameter::*;
+pub use client_interface_mismatch::*;
+pub use repeated_storage_handle::*;
+pub use unfinished_code::*;
+pub use repeated_admin_read::*;
+pub use large_literal_collection::*;
+pub use string_map_key::*;
+pub use redundant_counter::*;
+pub use ignored_constructor_param::*;
+pub use repeated_option_match::*;
+pub use mixed_storage_bucket::*;
+pub use storage_len_loop::*;
+pub use missing_contracttype_enum::*;
+pub use hardcoded_address::*;
+pub use inconsistent_error_type::*;
+pub use pure_contract_function::*;
+pub use iterator_length::*;
+pub use panic_in_result_fn::*;
+pub use timestamp_type::*;
+pub use asymmetric_conditional_write::*;
+pub use redundant_auth_check::*;
+pub use loop_append::*;
+pub use missing_contract_meta::*;
+pub use large_event_payload::*;
+pub use env_clone::*;
+pub use duplicate_function::*;
+pub use long_function::*;
+pub use unannotated_storage_get::*;
+pub use missing_key_enum::*;
+pub use default_collection_derive::*;
+pub use uncapped_mint::*;
+pub use mixed_auth_form::*;
+pub use reference_return::*;
+pub use storage_read_clone::*;
+pub use missing_contract_doc::*;
+pub use unseparated_literal::*;
+pub use inconsistent_ttl::*;
+pub use aggregate_by_iteration::*;
+pub use exported_test_helper::*;
+pub use storage_access_in_loop::*;
+pub use over_widened_integer::*;
+pub use returned_collection_clone::*;
+pub use per_user_instance_storage::*;
+pub use blind_struct_write::*;
+pub use unchecked_transfer_from::*;
+pub use storage_key_typo::*;
+pub use unbounded_capacity::*;
+pub use missing_auth_check::*;
+pub use constructed_auth_address::*;
+pub use repeated_config_read::*;
+pub use getter_unwrap::*;
+pub use lost_storage_update::*;
 
 /// Represents a Soroban contract structure
 #[derive(Debug, Clone, PartialEq)]
 pub struct SorobanContract {
     /// The name of the contract
     pub name: String,
+    /// The struct marked with #[contract], if the file declares it
+    pub contract_struct: Option<SorobanContractStruct>,
     /// Struct definitions marked with #[contracttype]
     pub contract_types: Vec<SorobanStruct>,
     /// Implementation blocks marked with #[contractimpl]
     pub implementations: Vec<SorobanImpl>,
+    /// Client interface traits marked with #[contractclient]
+    pub interfaces: Vec<SorobanInterface>,
+    /// Enum definitions, with or without #[contracttype]
+    pub enums: Vec<SorobanEnum>,
     /// Raw contract source code
     pub source: String,
     /// File path of the contract
     pub file_path: String,
 }
 
+/// Represents the struct marked with #[contract]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SorobanContractStruct {
+    /// Name of the struct
+    pub name: String,
+    /// Lines of the `///` doc comment on the struct, without the `///` markers
+    pub doc: Vec<String>,
+    /// Line number of the struct declaration
+    pub line_number: usize,
+}
+
 /// Represents a struct definition with #[contracttype] macro
 #[derive(Debug, Clone, PartialEq)]
 pub struct SorobanStruct {
@@ -34,12 +162,29 @@ pub struct SorobanStruct {
     pub name: String,
     /// Fields in the struct
     pub fields: Vec<SorobanField>,
+    /// Traits named in `#[derive(...)]` attributes above the struct, last path segment only
+    pub derives: Vec<String>,
     /// Line number where the struct is defined
     pub line_number: usize,
     /// Raw struct definition
     pub raw_definition: String,
 }
 
+/// Represents an enum definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct SorobanEnum {
+    /// Name of the enum
+    pub name: String,
+    /// Variant names, without payloads or discriminants
+    pub variants: Vec<String>,
+    /// Whether the enum is marked with #[contracttype]
+    pub is_contract_type: bool,
+    /// Line number where the enum is defined
+    pub line_number: usize,
+    /// Raw enum definition
+    pub raw_definition: String,
+}
+
 /// Represents a field in a Soroban struct
 #[derive(Debug, Clone, PartialEq)]
 pub struct SorobanField {
@@ -67,12 +212,27 @@ pub struct SorobanImpl {
     pub target: String,
     /// Functions defined in the impl block
     pub functions: Vec<SorobanFunction>,
+    /// Line of each macro invocation in the impl block, whose functions syn cannot see
+    pub macro_lines: Vec<usize>,
     /// Line number where the impl starts
     pub line_number: usize,
     /// Raw impl definition
     pub raw_definition: String,
 }
 
+/// Represents a trait marked with #[contractclient] describing a contract's external surface
+#[derive(Debug, Clone, PartialEq)]
+pub struct SorobanInterface {
+    /// Name of the trait
+    pub name: String,
+    /// Method signatures declared by the trait
+    pub methods: Vec<SorobanFunction>,
+    /// Line number where the trait is defined
+    pub line_number: usize,
+    /// Raw trait definition
+    pub raw_definition: String,
+}
+
 /// Represents a function in a Soroban contract
 #[derive(Debug, Clone, PartialEq)]
 pub struct SorobanFunction {
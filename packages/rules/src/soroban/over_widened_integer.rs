@@ -0,0 +1,243 @@
+//! Over-widened integer local detection
+//!
+//! `i128` is the SEP-41 type for balances, but internal counters and time
+//! deltas built only from `u64`-sized inputs gain nothing from it: 128-bit
+//! arithmetic is slower in wasm and the value never needs the range. The
+//! check is textual, so a long-running sum could still outgrow `u64`;
+//! findings are advisory.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeSet;
+
+/// Integer types whose values always fit in a `u64`
+const NARROW_TYPES: [&str; 4] = ["u8", "u16", "u32", "u64"];
+
+/// Rule for detecting `i128`/`u128` locals computed only from `u64`-sized values
+pub struct OverWidenedIntegerRule {
+    enabled: bool,
+}
+
+impl Default for OverWidenedIntegerRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for OverWidenedIntegerRule {
+    fn id(&self) -> &str {
+        "soroban-over-widened-integer"
+    }
+
+    fn name(&self) -> &str {
+        "Over-Widened Integer Local"
+    }
+
+    fn description(&self) -> &str {
+        "Detects i128/u128 locals whose every assignment only adds or subtracts u64-sized parameters, locals and literals"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl OverWidenedIntegerRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        // A wide return type may be fed straight from the local
+        if function
+            .return_type
+            .as_deref()
+            .is_some_and(|ty| ty.contains("i128") || ty.contains("u128"))
+        {
+            return Vec::new();
+        }
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let wide_re =
+            Regex::new(r"\blet\s+(?:mut\s+)?(\w+)\s*:\s*(i128|u128)\s*=\s*([^;]+);").unwrap();
+
+        let narrow = Self::narrow_names(function, &stripped);
+        let mut violations = Vec::new();
+        for local in wide_re.captures_iter(&stripped) {
+            let whole = local.get(0).unwrap();
+            let name = &local[1];
+            let ty = &local[2];
+
+            // Lending the local out usually means an API wants the wide type
+            let borrow_re =
+                Regex::new(&format!(r"&\s*(?:mut\s+)?{}\b", regex::escape(name))).unwrap();
+            if borrow_re.is_match(&stripped) {
+                continue;
+            }
+            let assign_re = Regex::new(&format!(
+                r"\b{}\s*([-+*/%&|^]|<<|>>)?=\s*([^=;][^;]*);",
+                regex::escape(name)
+            ))
+            .unwrap();
+            let rest = &stripped[whole.end()..];
+            let mut assignments = vec![Some(local.get(3).unwrap().as_str())];
+            // Only plain, `+=` and `-=` updates keep a u64-sized value u64-sized
+            assignments.extend(assign_re.captures_iter(rest).map(|c| {
+                matches!(c.get(1).map(|op| op.as_str()), None | Some("+") | Some("-"))
+                    .then(|| c.get(2).unwrap().as_str())
+            }));
+            if !assignments.iter().all(|expr| {
+                expr.is_some_and(|expr| Self::is_narrow_expression(expr, name, &narrow))
+            }) {
+                continue;
+            }
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Local '{}: {}' in function '{}' is only assigned from u64-sized values and likely fits in u64",
+                    name, ty, function.name
+                ),
+                suggestion: format!(
+                    "Declare '{}' as u64 unless it can outgrow u64::MAX; keep {} for SEP-41 balances and amounts",
+                    name, ty
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + whole.start()),
+                column_number: 0,
+                variable_name: name.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Parameters and locals known to hold `u64`-sized values
+    fn narrow_names<'a>(function: &'a SorobanFunction, stripped: &'a str) -> BTreeSet<&'a str> {
+        let is_narrow = |ty: &str| NARROW_TYPES.contains(&ty.trim_start_matches('&').trim());
+        let typed_re = Regex::new(r"\blet\s+(?:mut\s+)?(\w+)\s*:\s*(\w+)\s*=").unwrap();
+        let ledger_re = Regex::new(
+            r"\blet\s+(?:mut\s+)?(\w+)\s*=\s*\w+\s*\.\s*ledger\(\)\s*\.\s*(?:timestamp|sequence)\(\)\s*;",
+        )
+        .unwrap();
+
+        let mut names: BTreeSet<&str> = function
+            .params
+            .iter()
+            .filter(|p| is_narrow(&p.type_name))
+            .map(|p| p.name.as_str())
+            .collect();
+        for local in typed_re.captures_iter(stripped) {
+            if is_narrow(local.get(2).unwrap().as_str()) {
+                names.insert(local.get(1).unwrap().as_str());
+            }
+        }
+        names.extend(
+            ledger_re
+                .captures_iter(stripped)
+                .map(|c| c.get(1).unwrap().as_str()),
+        );
+
+        names
+    }
+
+    /// Whether `expr` only adds or subtracts `narrow` names, `local` itself and `u64`-sized literals
+    fn is_narrow_expression(expr: &str, local: &str, narrow: &BTreeSet<&str>) -> bool {
+        let cast_re = Regex::new(r"\bas\s+(?:i128|u128)\b").unwrap();
+        let shape_re = Regex::new(r"^[\w\s+\-()]+$").unwrap();
+        let token_re = Regex::new(r"\w+").unwrap();
+        let literal_re = Regex::new(r"^(\d[\d_]*)(?:[iu](?:8|16|32|64|128))?$").unwrap();
+
+        let expr = cast_re.replace_all(expr, "");
+        if !shape_re.is_match(&expr) {
+            return false;
+        }
+
+        let narrow_only = token_re.find_iter(&expr).all(|token| {
+            let token = token.as_str();
+            if let Some(literal) = literal_re.captures(token) {
+                return literal[1].replace('_', "").parse::<u64>().is_ok();
+            }
+            token == local || narrow.contains(token)
+        });
+        narrow_only
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_counter_built_from_u64_values_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Vesting {
+    pub fn elapsed_periods(env: Env, start: u64, period: u64) -> u32 {
+        let now = env.ledger().timestamp();
+        let mut elapsed: i128 = now as i128 - start as i128;
+        let mut periods: u128 = 0;
+        while elapsed >= period as i128 {
+            elapsed -= period as i128;
+            periods += 1;
+        }
+        periods as u32
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = OverWidenedIntegerRule::default().apply(&contract);
+
+        let names: Vec<_> = violations
+            .iter()
+            .map(|v| v.variable_name.as_str())
+            .collect();
+        assert_eq!(names, ["elapsed", "periods"]);
+    }
+
+    #[test]
+    fn test_balance_read_from_storage_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn deposit(env: Env, id: Address, amount: u64) {
+        let balance: i128 = env.storage().persistent().get(&id).unwrap_or(0);
+        let updated: i128 = balance + amount as i128;
+        env.storage().persistent().set(&id, &updated);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = OverWidenedIntegerRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,148 @@
+//! `panic_with_error!` in `Result` function detection
+//!
+//! `panic_with_error!` traps the whole invocation. A function that already
+//! returns `Result` can hand the same error back with `Err(...)`, letting
+//! callers that use `try_` client methods recover from it.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `panic_with_error!` inside functions that return `Result`
+pub struct PanicInResultFnRule {
+    enabled: bool,
+}
+
+impl Default for PanicInResultFnRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for PanicInResultFnRule {
+    fn id(&self) -> &str {
+        "soroban-panic-in-result-fn"
+    }
+
+    fn name(&self) -> &str {
+        "panic_with_error! in Result Function"
+    }
+
+    fn description(&self) -> &str {
+        "Detects panic_with_error! in functions that return Result and could return Err instead"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl PanicInResultFnRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let returns_result = function
+            .return_type
+            .as_deref()
+            .is_some_and(|t| t.trim().starts_with("Result<"));
+        if !returns_result {
+            return Vec::new();
+        }
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let panic_re = Regex::new(r"\bpanic_with_error!\s*\(").unwrap();
+
+        panic_re
+            .find_iter(&stripped)
+            .map(|m| RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' returns a Result but traps with panic_with_error!",
+                    function.name
+                ),
+                suggestion: "Return Err(error) instead so callers can handle the failure"
+                    .to_string(),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + m.start()),
+                column_number: 0,
+                variable_name: function.name.clone(),
+                severity: self.severity(),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_panic_in_result_function_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, panic_with_error, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, amount: i128) -> Result<(), Error> {
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+        Ok(())
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = PanicInResultFnRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "withdraw");
+    }
+
+    #[test]
+    fn test_panic_in_unit_function_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, panic_with_error, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(&env, Error::InvalidAmount);
+        }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = PanicInResultFnRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -2,9 +2,17 @@
 //!
 //! This module provides parsing capabilities for Soroban smart contracts,
 //! extracting AST-like structures from Rust code containing Soroban macros.
+//! Contracts are valid Rust, so the file is parsed with `syn` and the raw
+//! definitions rules work on are sliced out of the source by token span.
 
 use super::*;
+use proc_macro2::LineColumn;
 use regex::Regex;
+use syn::spanned::Spanned;
+use syn::{
+    Attribute, FnArg, ImplItem, Item, ItemEnum, ItemImpl, ItemStruct, ItemTrait,
+    ReturnType, Signature, TraitItem, Visibility,
+};
 
 /// Parses Soroban contracts from source code
 pub struct SorobanParser;
@@ -12,441 +20,339 @@ pub struct SorobanParser;
 impl SorobanParser {
     /// Parse a Soroban contract from source code
     pub fn parse_contract(source: &str, file_path: &str) -> SorobanResult<SorobanContract> {
-        let lines: Vec<&str> = source.lines().collect();
-        
+        let file = syn::parse_file(source).map_err(|e| {
+            SorobanParseError::ParseError(format!("{} at line {}", e, e.span().start().line))
+        })?;
+        let source_map = SourceMap::new(source);
+
         // Extract contract name from #[contract] attribute, or fallback to first struct
         let contract_name = Self::extract_contract_name(source)
             .unwrap_or_else(|_| "UnknownContract".to_string());
-        
-        // Parse struct definitions with #[contracttype]
-        let contract_types = Self::parse_contract_types(&lines)?;
-        
-        // Parse implementation blocks with #[contractimpl]
-        let implementations = Self::parse_implementations(&lines)?;
-        
-        Ok(SorobanContract {
+
+        let mut contract = SorobanContract {
             name: contract_name,
-            contract_types,
-            implementations,
+            contract_struct: None,
+            contract_types: Vec::new(),
+            implementations: Vec::new(),
+            interfaces: Vec::new(),
+            enums: Vec::new(),
             source: source.to_string(),
             file_path: file_path.to_string(),
-        })
+        };
+        Self::collect_items(&file.items, &source_map, &mut contract);
+
+        Ok(contract)
     }
-    
+
     /// Extract contract name from #[contract] attribute
     fn extract_contract_name(source: &str) -> SorobanResult<String> {
         let contract_re = Regex::new(r#"#\s*\[\s*contract\s*\(\s*(.*?)\s*\)\s*\]"#).unwrap();
-        
+
         if let Some(captures) = contract_re.captures(source) {
             if let Some(name) = captures.get(1) {
                 return Ok(name.as_str().trim().to_string());
             }
         }
-        
+
         let struct_re = Regex::new(r#"#\s*\[\s*contracttype\s*\][\s\S]*?(?:pub\s+)?struct\s+(\w+)"#).unwrap();
         if let Some(captures) = struct_re.captures(source) {
             if let Some(name) = captures.get(1) {
                 return Ok(name.as_str().to_string());
             }
         }
-        
+
         Err(SorobanParseError::MissingMacro(
             "Could not determine contract name from #[contract] or #[contracttype] attributes".to_string()
         ))
     }
-    
-    /// Parse struct definitions with #[contracttype] macro
-    fn parse_contract_types(lines: &[&str]) -> SorobanResult<Vec<SorobanStruct>> {
-        let mut structs = Vec::new();
-        let mut i = 0;
-        
-        while i < lines.len() {
-            if lines[i].trim().starts_with("#[contracttype]") {
-                let line_number = i + 1;
-                i += 1;
-                while i < lines.len() && !lines[i].trim().contains("struct") {
-                    i += 1;
+
+    /// Record every item the rules care about, descending into inline modules
+    fn collect_items(items: &[Item], source: &SourceMap, contract: &mut SorobanContract) {
+        for item in items {
+            match item {
+                Item::Struct(item) => {
+                    if has_attribute(&item.attrs, "contract") && contract.contract_struct.is_none() {
+                        contract.contract_struct = Some(Self::parse_contract_struct(item));
+                    }
+                    if has_attribute(&item.attrs, "contracttype") {
+                        contract.contract_types.push(Self::parse_struct(item, source));
+                    }
                 }
-                
-                if i >= lines.len() {
-                    break;
+                Item::Enum(item) => contract.enums.push(Self::parse_enum(item, source)),
+                Item::Impl(item) if has_attribute(&item.attrs, "contractimpl") => {
+                    if let Some(implementation) = Self::parse_impl(item, source) {
+                        contract.implementations.push(implementation);
+                    }
                 }
-                
-                if let Some(soroban_struct) = Self::parse_single_struct(&lines[i..], line_number)? {
-                    structs.push(soroban_struct);
+                Item::Trait(item) if has_attribute(&item.attrs, "contractclient") => {
+                    contract.interfaces.push(Self::parse_interface(item, source));
                 }
-            }
-            i += 1;
-        }
-        
-        Ok(structs)
-    }
-    
-    /// Parse a single struct definition
-    fn parse_single_struct(lines: &[&str], start_line: usize) -> SorobanResult<Option<SorobanStruct>> {
-        if lines.is_empty() || !lines[0].trim().contains("struct") {
-            return Ok(None);
-        }
-        
-        let struct_line = lines[0].trim();
-        let name_re = Regex::new(r"struct\s+(\w+)").unwrap();
-        let name = name_re.captures(struct_line)
-            .and_then(|caps| caps.get(1))
-            .map(|m| m.as_str().to_string())
-            .ok_or_else(|| SorobanParseError::ParseError(
-                format!("Could not parse struct name from: {}", struct_line)
-            ))?;
-        
-        let mut brace_count = 0;
-        let mut struct_lines = vec![struct_line];
-        let mut i = 1;
-        
-        if struct_line.contains('{') {
-            brace_count += 1;
-        }
-        
-        while i < lines.len() {
-            let line = lines[i].trim();
-            struct_lines.push(line);
-            
-            if line.contains('{') && i > 0 {
-                brace_count += 1;
-            }
-            if line.contains('}') {
-                brace_count -= 1;
-                if brace_count == 0 {
-                    break;
+                Item::Mod(module) => {
+                    if let Some((_, items)) = &module.content {
+                        Self::collect_items(items, source, contract);
+                    }
                 }
+                _ => {}
             }
-            i += 1;
         }
-        
-        let fields = Self::parse_struct_fields(&struct_lines, start_line)?;
-        
-        Ok(Some(SorobanStruct {
-            name,
-            fields,
-            line_number: start_line,
-            raw_definition: struct_lines.join("\n"),
-        }))
     }
-    
-    /// Parse fields from a struct definition
-    fn parse_struct_fields(lines: &[&str], base_line: usize) -> SorobanResult<Vec<SorobanField>> {
-        let mut fields = Vec::new();
-        let full_content = lines.join(" ");
-        let fields_content = Self::extract_between_braces(&full_content)
-            .ok_or_else(|| SorobanParseError::ParseError("Could not extract struct fields".to_string()))?;
-        
-        let field_parts = Self::split_preserving_parentheses(&fields_content, ',');
-        
-        for (index, field_part) in field_parts.iter().enumerate() {
-            let field_part = field_part.trim();
-            if field_part.is_empty() {
-                continue;
-            }
-            if let Some(field) = Self::parse_field(field_part, base_line + index)? {
-                fields.push(field);
-            }
+
+    /// The struct marked with #[contract] and the `///` doc comment on it
+    fn parse_contract_struct(item: &ItemStruct) -> SorobanContractStruct {
+        let doc = item
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .filter_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(meta) => match &meta.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(text),
+                        ..
+                    }) => Some(text.value().trim().to_string()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        SorobanContractStruct {
+            name: item.ident.to_string(),
+            doc,
+            line_number: definition_start(&item.vis, item.struct_token.span()).line,
         }
-        
-        Ok(fields)
     }
-    
-    /// Parse a single field definition
-    fn parse_field(field_str: &str, line_number: usize) -> SorobanResult<Option<SorobanField>> {
-        let field_str = field_str.trim();
-        if field_str.is_empty() {
-            return Ok(None);
-        }
-        
-        let (visibility, remaining) = if field_str.starts_with("pub ") {
-            (FieldVisibility::Public, &field_str[4..])
-        } else {
-            (FieldVisibility::Private, field_str)
-        };
-        
-        let parts: Vec<&str> = remaining.split(':').collect();
-        if parts.len() < 2 {
-            return Ok(None);
+
+    /// A #[contracttype] struct; tuple fields are named by their index
+    fn parse_struct(item: &ItemStruct, source: &SourceMap) -> SorobanStruct {
+        let fields = item
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(idx, field)| SorobanField {
+                name: field
+                    .ident
+                    .as_ref()
+                    .map(|ident| ident.to_string())
+                    .unwrap_or_else(|| idx.to_string()),
+                type_name: source.text(field.ty.span()).to_string(),
+                visibility: if matches!(field.vis, Visibility::Inherited) {
+                    FieldVisibility::Private
+                } else {
+                    FieldVisibility::Public
+                },
+                line_number: definition_start(
+                    &field.vis,
+                    field.ident.as_ref().map_or(field.ty.span(), |ident| ident.span()),
+                )
+                .line,
+            })
+            .collect();
+
+        let start = definition_start(&item.vis, item.struct_token.span());
+        SorobanStruct {
+            name: item.ident.to_string(),
+            fields,
+            derives: derived_traits(&item.attrs),
+            line_number: start.line,
+            raw_definition: source.slice(start, item.span().end()).to_string(),
         }
-        
-        let name = parts[0].trim().to_string();
-        let type_name = parts[1..].join(":").trim().to_string();
-        
-        Ok(Some(SorobanField {
-            name,
-            type_name,
-            visibility,
-            line_number,
-        }))
     }
-    
-    /// Parse implementation blocks with #[contractimpl] macro
-    fn parse_implementations(lines: &[&str]) -> SorobanResult<Vec<SorobanImpl>> {
-        let mut implementations = Vec::new();
-        let mut i = 0;
-        
-        while i < lines.len() {
-            if lines[i].trim().starts_with("#[contractimpl]") {
-                let line_number = i + 1;
-                i += 1;
-                while i < lines.len() && !lines[i].trim().starts_with("impl") {
-                    i += 1;
-                }
-                
-                if i >= lines.len() {
-                    break;
-                }
-                
-                if let Some(implementation) = Self::parse_single_impl(&lines[i..], line_number)? {
-                    implementations.push(implementation);
-                }
-            }
-            i += 1;
+
+    /// An enum, recording whether it carries #[contracttype]
+    fn parse_enum(item: &ItemEnum, source: &SourceMap) -> SorobanEnum {
+        let start = definition_start(&item.vis, item.enum_token.span());
+        SorobanEnum {
+            name: item.ident.to_string(),
+            variants: item.variants.iter().map(|v| v.ident.to_string()).collect(),
+            is_contract_type: has_attribute(&item.attrs, "contracttype"),
+            line_number: start.line,
+            raw_definition: source.slice(start, item.span().end()).to_string(),
         }
-        
-        Ok(implementations)
     }
-    
-    /// Parse a single implementation block
-    fn parse_single_impl(lines: &[&str], start_line: usize) -> SorobanResult<Option<SorobanImpl>> {
-        if lines.is_empty() || !lines[0].trim().starts_with("impl") {
-            return Ok(None);
-        }
-        
-        let impl_line = lines[0].trim();
-        let target_re = Regex::new(r"impl\s+(?:.*?\s+for\s+)?(\w+)").unwrap();
-        let target = target_re.captures(impl_line)
-            .and_then(|caps| caps.get(1))
-            .map(|m| m.as_str().to_string())
-            .ok_or_else(|| SorobanParseError::ParseError(
-                format!("Could not parse impl target from: {}", impl_line)
-            ))?;
-        
-        let mut brace_count = 0;
-        let mut impl_lines = vec![impl_line];
-        let mut i = 1;
-        let mut functions = Vec::new();
-        
-        if impl_line.contains('{') {
-            brace_count += 1;
-        }
-        
-        while i < lines.len() {
-            let line = lines[i].trim();
-            impl_lines.push(line);
-            
-            if line.contains('{') && i > 0 {
-                brace_count += 1;
-            }
-            
-            if line.contains('}') {
-                brace_count -= 1;
-                if brace_count == 0 {
-                    break;
-                }
-            }
-            
-            // Correct logic to identify functions inside impl block:
-            // We allow brace_count 2 IF the current line starts the function (contains '{')
-            // Otherwise brace_count must be 1 (direct child of impl)
-            let is_fn_def = line.starts_with("pub ") && line.contains("fn ");
-            let correct_depth = brace_count == 1 || (brace_count == 2 && line.contains('{'));
-
-            if is_fn_def && correct_depth {
-                if let Some(function) = Self::parse_function(&lines[i..], start_line + i)? {
-                    functions.push(function);
+
+    /// A #[contractimpl] block and the functions it exports
+    ///
+    /// Inherent impls export only their `pub` functions; trait impl methods
+    /// carry no `pub` but are exported all the same.
+    fn parse_impl(item: &ItemImpl, source: &SourceMap) -> Option<SorobanImpl> {
+        let syn::Type::Path(self_ty) = item.self_ty.as_ref() else {
+            return None;
+        };
+        let target = self_ty.path.segments.last()?.ident.to_string();
+        let is_trait_impl = item.trait_.is_some();
+        let start = item.impl_token.span().start();
+
+        let functions = item
+            .items
+            .iter()
+            .filter_map(|impl_item| match impl_item {
+                ImplItem::Fn(function)
+                    if is_trait_impl || !matches!(function.vis, Visibility::Inherited) =>
+                {
+                    let start = definition_start(&function.vis, function.sig.span());
+                    Some(Self::parse_function(
+                        &function.sig,
+                        start.line,
+                        source.slice(start, function.block.span().end()),
+                        source,
+                    ))
                 }
-            }
-            
-            i += 1;
-        }
-        
-        Ok(Some(SorobanImpl {
+                _ => None,
+            })
+            .collect();
+        let macro_lines = item
+            .items
+            .iter()
+            .filter_map(|impl_item| match impl_item {
+                ImplItem::Macro(item) => Some(item.mac.path.span().start().line),
+                _ => None,
+            })
+            .collect();
+
+        Some(SorobanImpl {
             target,
             functions,
-            line_number: start_line,
-            raw_definition: impl_lines.join("\n"),
-        }))
+            macro_lines,
+            line_number: start.line,
+            raw_definition: source.slice(start, item.span().end()).to_string(),
+        })
     }
-    
-    /// Parse a function definition
-    fn parse_function(lines: &[&str], start_line: usize) -> SorobanResult<Option<SorobanFunction>> {
-        if lines.is_empty() {
-            return Ok(None);
-        }
-        
-        let func_line = lines[0].trim();
-        if !func_line.starts_with("pub ") || !func_line.contains("fn ") {
-            return Ok(None);
-        }
-        
-        let name_re = Regex::new(r"fn\s+(\w+)").unwrap();
-        let name = name_re.captures(func_line)
-            .and_then(|caps| caps.get(1))
-            .map(|m| m.as_str().to_string())
-            .ok_or_else(|| SorobanParseError::ParseError(
-                format!("Could not parse function name from: {}", func_line)
-            ))?;
-        
-        let params = Self::extract_parameters(func_line).unwrap_or_default();
-        let return_type = Self::extract_return_type(func_line).unwrap_or(None);
-        let is_constructor = name == "new" || name.ends_with("_init");
-        
-        let mut brace_count = 0;
-        let mut func_lines = vec![func_line];
-        let mut i = 1;
-        
-        if func_line.contains('{') {
-            brace_count += 1;
-        }
-        
-        while i < lines.len() && brace_count > 0 {
-            let line = lines[i].trim();
-            func_lines.push(line);
-            
-            if line.contains('{') {
-                brace_count += 1;
-            }
-            if line.contains('}') {
-                brace_count -= 1;
-            }
-            i += 1;
+
+    /// A #[contractclient] trait and its method signatures
+    fn parse_interface(item: &ItemTrait, source: &SourceMap) -> SorobanInterface {
+        let methods = item
+            .items
+            .iter()
+            .filter_map(|trait_item| match trait_item {
+                TraitItem::Fn(method) => {
+                    let span = method.sig.span();
+                    Some(Self::parse_function(
+                        &method.sig,
+                        span.start().line,
+                        source.text(span),
+                        source,
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let start = definition_start(&item.vis, item.trait_token.span());
+        SorobanInterface {
+            name: item.ident.to_string(),
+            methods,
+            line_number: start.line,
+            raw_definition: source.slice(start, item.span().end()).to_string(),
         }
-        
-        Ok(Some(SorobanFunction {
+    }
+
+    /// A function from its signature and the source text of its definition
+    fn parse_function(
+        sig: &Signature,
+        line_number: usize,
+        raw_definition: &str,
+        source: &SourceMap,
+    ) -> SorobanFunction {
+        let name = sig.ident.to_string();
+        // Receivers such as `&self` are not parameters
+        let params = sig
+            .inputs
+            .iter()
+            .filter_map(|input| match input {
+                FnArg::Typed(param) => Some(SorobanParam {
+                    name: source.text(param.pat.span()).to_string(),
+                    type_name: source.text(param.ty.span()).to_string(),
+                }),
+                FnArg::Receiver(_) => None,
+            })
+            .collect();
+        let return_type = match &sig.output {
+            ReturnType::Type(_, ty) => Some(source.text(ty.span()).to_string()),
+            ReturnType::Default => None,
+        };
+
+        SorobanFunction {
+            is_constructor: name == "new" || name.ends_with("_init"),
             name,
             params,
             return_type,
             visibility: FunctionVisibility::Public,
-            is_constructor,
-            line_number: start_line,
-            raw_definition: func_lines.join("\n"),
-        }))
-    }
-    
-    /// Extract function parameters
-    fn extract_parameters(func_signature: &str) -> SorobanResult<Vec<SorobanParam>> {
-        let params_section = Self::extract_between_parentheses(func_signature)
-            .ok_or_else(|| SorobanParseError::ParseError("Could not extract parameters".to_string()))?;
-        
-        let mut params = Vec::new();
-        let param_parts = Self::split_preserving_parentheses(&params_section, ',');
-        
-        for param_part in param_parts {
-            let param_part = param_part.trim();
-            if param_part.is_empty() {
-                continue;
-            }
-            
-            let parts: Vec<&str> = param_part.split(':').collect();
-            if parts.len() >= 2 {
-                let name = parts[0].trim().to_string();
-                let type_name = parts[1..].join(":").trim().to_string();
-                params.push(SorobanParam { name, type_name });
-            }
+            line_number,
+            raw_definition: raw_definition.to_string(),
         }
-        
-        Ok(params)
     }
-    
-    /// Extract return type from function signature
-    fn extract_return_type(func_signature: &str) -> SorobanResult<Option<String>> {
-        let return_re = Regex::new(r"->\s*([^{\n]+)").unwrap();
-        
-        if let Some(captures) = return_re.captures(func_signature) {
-            if let Some(return_type) = captures.get(1) {
-                let clean_type = return_type.as_str().trim().to_string();
-                if !clean_type.is_empty() {
-                    return Ok(Some(clean_type));
-                }
+}
+
+/// Whether any attribute's path ends in `name`, e.g. `#[contractimpl]` or `#[soroban_sdk::contractimpl]`
+fn has_attribute(attrs: &[Attribute], name: &str) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().segments.last().is_some_and(|s| s.ident == name))
+}
+
+/// Traits named in `#[derive(...)]` attributes, last path segment only, sorted
+fn derived_traits(attrs: &[Attribute]) -> Vec<String> {
+    let mut derives = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("derive")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if let Some(segment) = meta.path.segments.last() {
+                derives.push(segment.ident.to_string());
             }
-        }
-        
-        Ok(None)
+            Ok(())
+        });
     }
-    
-    /// Helper function to extract content between parentheses
-    fn extract_between_parentheses(text: &str) -> Option<String> {
-        let start = text.find('(')?;
-        let mut paren_count = 1;
-        let mut end = start + 1;
-        
-        while end < text.len() && paren_count > 0 {
-            match text.chars().nth(end).unwrap() {
-                '(' => paren_count += 1,
-                ')' => paren_count -= 1,
-                _ => {}
-            }
-            if paren_count > 0 {
-                end += 1;
-            }
-        }
-        
-        if paren_count == 0 {
-            Some(text[start + 1..end].to_string())
-        } else {
-            None
-        }
+    derives.sort();
+    derives
+}
+
+/// Where a definition's text starts: its visibility if written, else its keyword
+///
+/// Attributes and doc comments are left out, as rules only look at the code.
+fn definition_start(vis: &Visibility, keyword: proc_macro2::Span) -> LineColumn {
+    match vis {
+        Visibility::Inherited => keyword.start(),
+        _ => vis.span().start(),
     }
-    
-    /// Helper function to extract content between braces
-    fn extract_between_braces(text: &str) -> Option<String> {
-        let start = text.find('{')?;
-        let mut brace_count = 1;
-        let mut end = start + 1;
-        
-        while end < text.len() && brace_count > 0 {
-            match text.chars().nth(end).unwrap() {
-                '{' => brace_count += 1,
-                '}' => brace_count -= 1,
-                _ => {}
-            }
-            if brace_count > 0 {
-                end += 1;
-            }
-        }
-        
-        if brace_count == 0 {
-            Some(text[start + 1..end].to_string())
-        } else {
-            None
+}
+
+/// Maps span positions back onto byte offsets in the parsed source
+struct SourceMap<'a> {
+    source: &'a str,
+    /// Byte offset of the start of each line
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceMap<'a> {
+    fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+        Self {
+            source,
+            line_starts,
         }
     }
-    
-    /// Split string by delimiter while preserving parentheses nesting
-    fn split_preserving_parentheses(text: &str, delimiter: char) -> Vec<String> {
-        let mut result = Vec::new();
-        let mut current = String::new();
-        let mut paren_count = 0;
-        let mut bracket_count = 0;
-        let mut brace_count = 0;
-        
-        for ch in text.chars() {
-            match ch {
-                '(' => paren_count += 1,
-                ')' => paren_count -= 1,
-                '[' => bracket_count += 1,
-                ']' => bracket_count -= 1,
-                '{' => brace_count += 1,
-                '}' => brace_count -= 1,
-                _ => {}
-            }
-            
-            if ch == delimiter && paren_count == 0 && bracket_count == 0 && brace_count == 0 {
-                result.push(current.trim().to_string());
-                current = String::new();
-            } else {
-                current.push(ch);
-            }
-        }
-        
-        if !current.trim().is_empty() {
-            result.push(current.trim().to_string());
-        }
-        
-        result
+
+    /// Byte offset of a span position; lines are 1-based, columns count characters
+    fn offset(&self, position: LineColumn) -> usize {
+        let Some(&line_start) = self.line_starts.get(position.line.saturating_sub(1)) else {
+            return self.source.len();
+        };
+        self.source[line_start..]
+            .char_indices()
+            .nth(position.column)
+            .map(|(idx, _)| line_start + idx)
+            .unwrap_or(self.source.len())
+    }
+
+    fn slice(&self, start: LineColumn, end: LineColumn) -> &'a str {
+        let start = self.offset(start);
+        let end = self.offset(end).max(start);
+        &self.source[start..end]
+    }
+
+    fn text(&self, span: proc_macro2::Span) -> &'a str {
+        self.slice(span.start(), span.end())
     }
 }
 
@@ -460,6 +366,7 @@ mod tests {
 use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
 
 #[contracttype]
+#[derive(Clone, core::fmt::Debug)]
 pub struct TokenContract {
     pub admin: Address,
     pub total_supply: u64,
@@ -488,6 +395,7 @@ impl TokenContract {
         let struct_def = &contract.contract_types[0];
         assert_eq!(struct_def.name, "TokenContract");
         assert_eq!(struct_def.fields.len(), 2);
+        assert_eq!(struct_def.derives, vec!["Clone", "Debug"]);
         
         let impl_block = &contract.implementations[0];
         // This assertion failed previously because brace counting was off
@@ -495,4 +403,130 @@ impl TokenContract {
         assert_eq!(impl_block.functions[0].name, "new");
         assert_eq!(impl_block.functions[1].name, "get_total_supply");
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_parse_enums() {
+        let source = r#"
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Balance(Address),
+}
+
+enum Phase { Open, Closed = 2 }
+
+#[contracttype]
+pub struct Config {
+    pub phase: u32,
+}
+"#;
+        
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        
+        assert_eq!(contract.enums.len(), 2);
+        assert_eq!(contract.enums[0].name, "DataKey");
+        assert!(contract.enums[0].is_contract_type);
+        assert_eq!(contract.enums[0].variants, vec!["Admin", "Balance"]);
+        assert_eq!(contract.enums[1].name, "Phase");
+        assert!(!contract.enums[1].is_contract_type);
+        assert_eq!(contract.enums[1].variants, vec!["Open", "Closed"]);
+        
+        assert_eq!(contract.contract_types.len(), 1);
+        assert_eq!(contract.contract_types[0].name, "Config");
+        assert_eq!(contract.contract_types[0].line_number, 14);
+    }
+
+    #[test]
+    fn test_parse_generic_fields_and_multiline_signatures() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map};
+
+#[contracttype]
+pub struct Ledger {
+    pub balances: Map<Address, u64>,
+    pub limits: Map<Address, (u64, u64)>,
+}
+
+#[contractimpl]
+impl Vault {
+    pub fn deposit(
+        env: Env,
+        from: Address,
+        amounts: Map<Address, u64>,
+    ) -> Result<u64, Error> {
+        let total = amounts.values().iter().fold(0, |acc, v| { acc + v });
+        if total > 0 { Ok(total) } else { Err(Error::Empty) }
+    }
+
+    fn helper(env: &Env) {}
+
+    pub fn ping(env: Env) -> u32 { 1 }
+}
+"#;
+
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+
+        let fields = &contract.contract_types[0].fields;
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].type_name, "Map<Address, u64>");
+        assert_eq!(fields[1].line_number, 7);
+
+        let functions = &contract.implementations[0].functions;
+        let names: Vec<&str> = functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["deposit", "ping"]);
+        let deposit = &functions[0];
+        assert_eq!(deposit.line_number, 12);
+        assert_eq!(deposit.params.len(), 3);
+        assert_eq!(deposit.params[2].type_name, "Map<Address, u64>");
+        assert_eq!(deposit.return_type.as_deref(), Some("Result<u64, Error>"));
+        assert!(deposit.raw_definition.starts_with("pub fn deposit("));
+        assert!(deposit.raw_definition.ends_with("Err(Error::Empty) }\n    }"));
+    }
+
+    #[test]
+    fn test_attributed_items_report_their_keyword_line() {
+        let source = r#"
+use soroban_sdk::{contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Config {
+    /// Who may upgrade the contract
+    #[allow(dead_code)]
+    pub admin: Address,
+}
+
+#[contractimpl]
+impl Vault {
+    pub fn admin(env: Env, config: Config) -> Address {
+        config.admin
+    }
+}
+"#;
+
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+
+        let config = &contract.contract_types[0];
+        assert_eq!(config.line_number, 6);
+        assert!(config.raw_definition.starts_with("pub struct Config"));
+        assert_eq!(config.fields[0].line_number, 9);
+        assert_eq!(contract.implementations[0].line_number, 13);
+    }
+
+    #[test]
+    fn test_invalid_rust_is_a_parse_error() {
+        let source = r#"
+#[contractimpl]
+impl Token {
+    pub fn burn(env: Env, from Address, amount: i128) {}
+}
+"#;
+
+        let error = SorobanParser::parse_contract(source, "test.rs").unwrap_err();
+
+        assert!(error.to_string().contains("line 4"));
+    }
+}
@@ -0,0 +1,198 @@
+//! Per-user instance storage detection
+//!
+//! Instance storage is one entry shared by the whole contract: it is loaded
+//! on every invocation and capped in size. Data keyed by an `Address` or
+//! another caller-supplied value grows with the number of users, so it
+//! belongs in persistent storage, where each key is its own ledger entry.
+
+use crate::soroban::helpers::{
+    contains_identifier, function_body, line_offset, matching_close, split_top_level,
+    strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `instance().set(` calls whose key is derived from a function parameter
+pub struct PerUserInstanceStorageRule {
+    enabled: bool,
+}
+
+impl Default for PerUserInstanceStorageRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for PerUserInstanceStorageRule {
+    fn id(&self) -> &str {
+        "soroban-per-user-instance-storage"
+    }
+
+    fn name(&self) -> &str {
+        "Per-User Instance Storage"
+    }
+
+    fn description(&self) -> &str {
+        "Detects instance storage writes keyed by an Address or other parameter, which grow the shared instance entry per user"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl PerUserInstanceStorageRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let set_re =
+            Regex::new(r"\bstorage\(\)\s*\.\s*instance\(\)\s*\.\s*set(?:::<[^>]*>)?\(").unwrap();
+
+        let sources = Self::parameter_derived(function, &stripped);
+        if sources.is_empty() {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+        for call in set_re.find_iter(&stripped) {
+            let open = call.end() - 1;
+            let Some(close) = matching_close(&stripped, open) else {
+                continue;
+            };
+            let Some(key) = split_top_level(&stripped[open + 1..close]).first().copied() else {
+                continue;
+            };
+            let Some(&(_, param, type_name)) = sources
+                .iter()
+                .find(|(name, _, _)| contains_identifier(key, name))
+            else {
+                continue;
+            };
+            let key = split_top_level(&body[open + 1..close])
+                .first()
+                .copied()
+                .unwrap_or(key)
+                .trim_start_matches('&');
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' writes instance storage under key '{}', derived from parameter '{}: {}'",
+                    function.name, key, param, type_name
+                ),
+                suggestion: format!(
+                    "Store per-{} data with env.storage().persistent() so each key is its own ledger entry and the shared instance entry stays small",
+                    if type_name.contains("Address") { "user" } else { "key" }
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + call.start()),
+                column_number: 0,
+                variable_name: key.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Parameters other than `Env`, plus locals initialized from them, as `(name, parameter, parameter type)`
+    fn parameter_derived<'a>(
+        function: &'a SorobanFunction,
+        stripped: &'a str,
+    ) -> Vec<(&'a str, &'a str, &'a str)> {
+        let let_re = Regex::new(r"\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]*)?=([^;]*);").unwrap();
+
+        let mut sources: Vec<(&str, &str, &str)> = function
+            .params
+            .iter()
+            .filter(|p| p.type_name.trim_start_matches('&').trim() != "Env")
+            .map(|p| (p.name.as_str(), p.name.as_str(), p.type_name.as_str()))
+            .collect();
+        // Bindings are visited in order, so a chain of lets is followed to its parameter
+        for binding in let_re.captures_iter(stripped) {
+            let (Some(name), Some(init)) = (binding.get(1), binding.get(2)) else {
+                continue;
+            };
+            if let Some(&(_, param, type_name)) = sources
+                .iter()
+                .find(|(source, _, _)| contains_identifier(init.as_str(), source))
+            {
+                sources.push((name.as_str(), param, type_name));
+            }
+        }
+
+        sources
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_per_address_instance_write_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Rewards {
+    pub fn claim(env: Env, user: Address, amount: i128) {
+        user.require_auth();
+        let key = DataKey::Claimed(user);
+        env.storage().instance().set(&key, &amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = PerUserInstanceStorageRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "key");
+        assert!(violations[0].description.contains("'user: Address'"));
+    }
+
+    #[test]
+    fn test_fixed_config_key_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Rewards {
+    pub fn set_rate(env: Env, admin: Address, rate: u32) {
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Rate, &rate);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = PerUserInstanceStorageRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,153 @@
+//! Pure contract function detection
+//!
+//! A `#[contractimpl]` function that never touches `env`, `self`, storage or
+//! authorization is a plain computation. Exporting it adds an entry point to
+//! the contract's interface and wasm without needing any contract context.
+
+use crate::soroban::helpers::{contains_identifier, function_body, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+
+/// Rule for detecting exported contract functions that use no contract context
+pub struct PureContractFunctionRule {
+    enabled: bool,
+}
+
+impl Default for PureContractFunctionRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for PureContractFunctionRule {
+    fn id(&self) -> &str {
+        "soroban-pure-contract-function"
+    }
+
+    fn name(&self) -> &str {
+        "Pure Contract Function"
+    }
+
+    fn description(&self) -> &str {
+        "Detects #[contractimpl] functions whose bodies use neither env, self, storage nor authorization"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                if Self::is_pure(function) {
+                    violations.push(self.violation(function));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl PureContractFunctionRule {
+    fn is_pure(function: &SorobanFunction) -> bool {
+        if function.is_constructor {
+            return false;
+        }
+        let Some(body) = function_body(&function.raw_definition) else {
+            return false;
+        };
+        let body = strip_comments_and_strings(body);
+        let inner = body.trim().trim_start_matches('{').trim_end_matches('}');
+        // Empty and unfinished stubs are reported by other rules
+        if inner.trim().is_empty() || inner.contains("todo!") || inner.contains("unimplemented!") {
+            return false;
+        }
+
+        let env_params = function
+            .params
+            .iter()
+            .filter(|p| p.type_name.contains("Env"))
+            .map(|p| p.name.trim_start_matches("mut ").trim());
+        let context = ["self", "Self", "env"].into_iter().chain(env_params);
+
+        !context
+            .into_iter()
+            .any(|ident| contains_identifier(inner, ident))
+            && !inner.contains("storage()")
+            && !inner.contains("require_auth")
+    }
+
+    fn violation(&self, function: &SorobanFunction) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Contract function '{}' uses no env, self, storage or auth and is a pure computation",
+                function.name
+            ),
+            suggestion: format!(
+                "Move '{}' into a non-#[contractimpl] impl block or a free (possibly const) function",
+                function.name
+            ),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_exported_arithmetic_helper_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Pool {
+    pub fn fee_for(amount: i128, bps: i128) -> i128 {
+        amount * bps / 10_000
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = PureContractFunctionRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "fee_for");
+    }
+
+    #[test]
+    fn test_storage_using_function_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Env};
+
+#[contractimpl]
+impl Pool {
+    pub fn fee_for(e: Env, amount: i128) -> i128 {
+        let bps: i128 = e.storage().instance().get(&symbol_short!("bps")).unwrap_or(30);
+        amount * bps / 10_000
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = PureContractFunctionRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
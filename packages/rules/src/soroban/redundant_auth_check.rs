@@ -0,0 +1,170 @@
+//! Redundant authorization check detection
+//!
+//! `admin.require_auth()` already traps unless `admin` signed the invocation.
+//! Comparing another address against `admin` afterwards re-implements the same
+//! check by hand and costs extra instructions without adding any protection.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::HashSet;
+
+/// Rule for detecting `require_auth` followed by an explicit equality check on the same address
+pub struct RedundantAuthCheckRule {
+    enabled: bool,
+}
+
+impl Default for RedundantAuthCheckRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for RedundantAuthCheckRule {
+    fn id(&self) -> &str {
+        "soroban-redundant-auth-check"
+    }
+
+    fn name(&self) -> &str {
+        "Redundant Authorization Check"
+    }
+
+    fn description(&self) -> &str {
+        "Detects addresses checked with require_auth and then compared for equality again"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl RedundantAuthCheckRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let auth_re = Regex::new(r"\b([A-Za-z_]\w*)\s*\.\s*require_auth\s*\(\s*\)").unwrap();
+
+        let mut seen = HashSet::new();
+        let mut violations = Vec::new();
+        for auth in auth_re.captures_iter(&stripped) {
+            let address = &auth[1];
+            if !seen.insert(address.to_string()) {
+                continue;
+            }
+            let auth_end = auth.get(0).unwrap().end();
+            let Some(comparison) = Self::equality_check(&stripped[auth_end..], address) else {
+                continue;
+            };
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' calls {}.require_auth() and then compares '{}' for equality again",
+                    function.name, address, address
+                ),
+                suggestion: format!(
+                    "Keep either the require_auth() call or the equality check on '{}', not both",
+                    address
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + auth_end + comparison),
+                column_number: 0,
+                variable_name: address.to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Offset of the first `==` / `!=` comparison involving `address`
+    fn equality_check(text: &str, address: &str) -> Option<usize> {
+        let address = regex::escape(address);
+        let comparison_re = Regex::new(&format!(
+            r"(?:^|[^.\w])({address}\s*[!=]=)|([!=]=\s*[&*]*{address}\b)"
+        ))
+        .unwrap();
+
+        comparison_re.captures(text).map(|c| {
+            c.get(1)
+                .or_else(|| c.get(2))
+                .map(|m| m.start())
+                .unwrap_or(0)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_require_auth_with_equality_check_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Admin {
+    pub fn set_fee(env: Env, caller: Address, fee: u32) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if caller != admin {
+            panic!("not admin");
+        }
+        env.storage().instance().set(&DataKey::Fee, &fee);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RedundantAuthCheckRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "admin");
+    }
+
+    #[test]
+    fn test_single_auth_mechanism_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Admin {
+    pub fn set_fee(env: Env, fee: u32) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Fee, &fee);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RedundantAuthCheckRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
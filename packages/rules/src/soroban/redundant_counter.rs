@@ -0,0 +1,231 @@
+//! Redundant counter detection
+//!
+//! A `count` field that moves by one every time an entry is added to or
+//! removed from a collection duplicates `len()`. Keeping it costs an extra
+//! ledger write per mutation and can drift out of sync with the collection.
+
+use crate::soroban::helpers::{function_body, strip_comments_and_strings};
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanField, SorobanFunction, SorobanRule,
+};
+use crate::{RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+const INTEGER_TYPES: [&str; 8] = ["u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize"];
+
+/// Rule for detecting counter fields that only mirror a collection's length
+pub struct RedundantCounterRule {
+    enabled: bool,
+}
+
+impl Default for RedundantCounterRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for RedundantCounterRule {
+    fn id(&self) -> &str {
+        "soroban-redundant-counter"
+    }
+
+    fn name(&self) -> &str {
+        "Redundant Counter"
+    }
+
+    fn description(&self) -> &str {
+        "Detects count/size/len fields that are only adjusted by one alongside Map/Vec mutations and could be derived from the collection"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+        let counter_name_re =
+            Regex::new(r"^(count|size|len|length|num_\w+|\w+_(count|size|len))$").unwrap();
+        let functions: Vec<&SorobanFunction> = contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .collect();
+
+        for contract_type in &contract.contract_types {
+            let collections: Vec<&str> = contract_type
+                .fields
+                .iter()
+                .filter(|f| f.type_name.starts_with("Map<") || f.type_name.starts_with("Vec<"))
+                .map(|f| f.name.as_str())
+                .collect();
+            if collections.is_empty() {
+                continue;
+            }
+
+            let counters = contract_type.fields.iter().filter(|f| {
+                counter_name_re.is_match(&f.name) && INTEGER_TYPES.contains(&f.type_name.as_str())
+            });
+
+            for counter in counters {
+                if let Some(collection) =
+                    Self::mirrored_collection(counter, &collections, &functions)
+                {
+                    violations.push(self.violation(counter, collection));
+                }
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl RedundantCounterRule {
+    /// The collection the counter tracks, if every change to the counter is a
+    /// unit step made in a function that also mutates that collection
+    fn mirrored_collection<'a>(
+        counter: &SorobanField,
+        collections: &[&'a str],
+        functions: &[&SorobanFunction],
+    ) -> Option<&'a str> {
+        let field = regex::escape(&counter.name);
+        let unit_step_re = Regex::new(&format!(r"\.{}\s*(?:\+|-)=\s*1\b", field)).unwrap();
+        let any_write_re = Regex::new(&format!(r"\.{}\s*(?:\+=|-=|\*=|/=|=[^=])", field)).unwrap();
+
+        let mut candidates: Vec<&str> = collections.to_vec();
+        let mut steps = 0;
+
+        for function in functions {
+            let Some(body) = function_body(&function.raw_definition) else {
+                continue;
+            };
+            let body = strip_comments_and_strings(body);
+
+            let unit_steps = unit_step_re.find_iter(&body).count();
+            let writes = any_write_re.find_iter(&body).count();
+            if writes == 0 {
+                continue;
+            }
+            if unit_steps != writes {
+                // Assigned or moved by arbitrary amounts: independent state
+                return None;
+            }
+
+            candidates.retain(|collection| Self::mutates_collection(&body, collection));
+            steps += unit_steps;
+        }
+
+        if steps == 0 {
+            return None;
+        }
+        candidates.first().copied()
+    }
+
+    fn mutates_collection(body: &str, collection: &str) -> bool {
+        Regex::new(&format!(
+            r"\.{}\s*\.\s*(?:set|remove|insert|push_back|push_front|pop_back|pop_front|append)\(",
+            regex::escape(collection)
+        ))
+        .map(|re| re.is_match(body))
+        .unwrap_or(false)
+    }
+
+    fn violation(&self, counter: &SorobanField, collection: &str) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Field '{}' only changes by one alongside mutations of '{}' and duplicates its length",
+                counter.name, collection
+            ),
+            suggestion: format!(
+                "Remove '{}' and derive it from '{}.len()' to save a storage write per mutation",
+                counter.name, collection
+            ),
+            line_number: counter.line_number,
+            column_number: 0,
+            variable_name: counter.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_counter_mirroring_map_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map};
+
+#[contracttype]
+pub struct Registry {
+    pub members: Map<Address, u32>,
+    pub count: u32,
+}
+
+#[contractimpl]
+impl Club {
+    pub fn join(env: Env, mut registry: Registry, who: Address) -> Registry {
+        registry.members.set(who, 1);
+        registry.count += 1;
+        registry
+    }
+
+    pub fn leave(env: Env, mut registry: Registry, who: Address) -> Registry {
+        registry.members.remove(who);
+        registry.count -= 1;
+        registry
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RedundantCounterRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "count");
+    }
+
+    #[test]
+    fn test_independent_counter_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map};
+
+#[contracttype]
+pub struct Registry {
+    pub members: Map<Address, u32>,
+    pub visit_count: u64,
+}
+
+#[contractimpl]
+impl Club {
+    pub fn join(env: Env, mut registry: Registry, who: Address) -> Registry {
+        registry.members.set(who, 1);
+        registry
+    }
+
+    pub fn visit(env: Env, mut registry: Registry) -> Registry {
+        registry.visit_count += 1;
+        registry
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RedundantCounterRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,142 @@
+//! Reference return type detection
+//!
+//! Contract entry points hand their result back to the host, which needs an
+//! owned value it can convert. A `-> &T` signature such as
+//! `get_owner(&self) -> &Address` cannot be exported and usually means the
+//! function was written as a plain method rather than a contract function.
+
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+
+/// Rule for detecting `#[contractimpl]` functions whose return type is a reference
+pub struct ReferenceReturnRule {
+    enabled: bool,
+}
+
+impl Default for ReferenceReturnRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for ReferenceReturnRule {
+    fn id(&self) -> &str {
+        "soroban-reference-return"
+    }
+
+    fn name(&self) -> &str {
+        "Reference Return Type"
+    }
+
+    fn description(&self) -> &str {
+        "Detects contract functions returning a reference, which entry points cannot pass back to the host"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .filter_map(|function| self.check_function(function))
+            .collect()
+    }
+}
+
+impl ReferenceReturnRule {
+    fn check_function(&self, function: &SorobanFunction) -> Option<RuleViolation> {
+        let return_type = function.return_type.as_deref()?;
+        let owned = return_type.strip_prefix('&')?;
+        // Drop any lifetime and `mut` so the suggestion names the owned type
+        let owned = owned
+            .trim_start()
+            .strip_prefix('\'')
+            .map(|rest| {
+                rest.split_once(char::is_whitespace)
+                    .map_or(rest, |(_, ty)| ty)
+            })
+            .unwrap_or(owned);
+        let owned = owned.trim_start().trim_start_matches("mut ").trim();
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Contract function '{}' returns reference type '{}', which cannot be returned from an entry point",
+                function.name, return_type
+            ),
+            suggestion: format!(
+                "Return an owned '{}' (clone it if needed), or move '{}' out of the #[contractimpl] block if it is only a helper",
+                owned, function.name
+            ),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_reference_return_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+pub struct Vault {
+    pub owner: Address,
+}
+
+#[contractimpl]
+impl Vault {
+    pub fn get_owner(&self) -> &Address {
+        &self.owner
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = ReferenceReturnRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "get_owner");
+        assert!(violations[0].suggestion.contains("owned 'Address'"));
+    }
+
+    #[test]
+    fn test_owned_return_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn get_owner(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Owner).unwrap()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = ReferenceReturnRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,164 @@
+//! Repeated admin read detection
+//!
+//! Guarded functions commonly read the admin address from storage. Reading it
+//! more than once in the same invocation pays for the same ledger entry twice.
+
+use crate::cost_model::STORAGE_ACCESS_INSTRUCTIONS;
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{EstimatedSaving, RuleViolation, ViolationSeverity};
+
+/// Key fragments that identify the privileged account entry
+const ADMIN_KEY_PATTERNS: [&str; 2] = ["admin", "owner"];
+
+/// Rule for detecting functions reading the admin/owner storage entry more than once
+pub struct RepeatedAdminReadRule {
+    enabled: bool,
+}
+
+impl Default for RepeatedAdminReadRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for RepeatedAdminReadRule {
+    fn id(&self) -> &str {
+        "soroban-repeated-admin-read"
+    }
+
+    fn name(&self) -> &str {
+        "Repeated Admin Read"
+    }
+
+    fn description(&self) -> &str {
+        "Detects functions that read the admin/owner storage entry more than once instead of caching it"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                if let Some(violation) = self.check_function(function) {
+                    violations.push(violation);
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl RepeatedAdminReadRule {
+    fn check_function(&self, function: &SorobanFunction) -> Option<RuleViolation> {
+        let body = function_body(&function.raw_definition)?;
+        let body_start = function.raw_definition.len() - body.len();
+        let reads = Self::admin_read_offsets(body);
+
+        if reads.len() < 2 {
+            return None;
+        }
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' reads the admin/owner entry from storage {} times",
+                function.name,
+                reads.len()
+            ),
+            suggestion: "Read the admin address once into a local and reuse it for every check"
+                .to_string(),
+            line_number: function.line_number
+                + line_offset(&function.raw_definition, body_start + reads[1]),
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: Some(EstimatedSaving::instructions(
+                (reads.len() as u64 - 1) * STORAGE_ACCESS_INSTRUCTIONS,
+            )),
+        })
+    }
+
+    /// Offsets of `.get(...)` calls whose key refers to an admin-like entry
+    fn admin_read_offsets(body: &str) -> Vec<usize> {
+        // Locate calls in code only, but read the key from the original text so
+        // string keys such as symbol_short!("admin") are still visible
+        let stripped = strip_comments_and_strings(body);
+
+        stripped
+            .match_indices(".get(")
+            .filter_map(|(idx, _)| {
+                let open_idx = idx + ".get".len();
+                let close_idx = matching_close(&stripped, open_idx)?;
+                let key = body[open_idx + 1..close_idx].to_lowercase();
+                ADMIN_KEY_PATTERNS
+                    .iter()
+                    .any(|pattern| key.contains(pattern))
+                    .then_some(idx)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_two_admin_reads_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn set_fee(env: Env, fee: u32) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        let recipient: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        env.storage().instance().set(&symbol_short!("fee"), &(fee, recipient));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedAdminReadRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("2 times"));
+    }
+
+    #[test]
+    fn test_single_admin_read_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn set_fee(env: Env, fee: u32) {
+        let admin: Address = env.storage().instance().get(&symbol_short!("admin")).unwrap();
+        admin.require_auth();
+        env.storage().instance().set(&symbol_short!("fee"), &(fee, admin));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedAdminReadRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,217 @@
+//! Repeated configuration read detection
+//!
+//! Contracts often keep a config struct under one storage key and have every
+//! entry point fetch and deserialize it by hand. Each copy repeats the key,
+//! the type and the unwrap, and they drift apart as the contract evolves. A
+//! single accessor keeps the read in one place.
+
+use crate::soroban::helpers::{
+    function_body, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Functions that must read the same key as the same type before it is reported
+const MIN_READERS: usize = 3;
+
+/// SDK and primitive types that are plain values rather than config structs
+const VALUE_TYPES: [&str; 10] = [
+    "Address", "Symbol", "String", "Bytes", "BytesN", "Vec", "Map", "Option", "bool", "I256",
+];
+
+/// Rule for detecting the same struct being read from the same storage key in several functions
+pub struct RepeatedConfigReadRule {
+    enabled: bool,
+}
+
+impl Default for RepeatedConfigReadRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for RepeatedConfigReadRule {
+    fn id(&self) -> &str {
+        "soroban-repeated-config-read"
+    }
+
+    fn name(&self) -> &str {
+        "Repeated Config Read"
+    }
+
+    fn description(&self) -> &str {
+        "Detects three or more functions each reading and deserializing the same struct from the same storage key"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        // (key, type) -> functions reading it, in source order
+        let mut readers: BTreeMap<(String, String), Vec<&SorobanFunction>> = BTreeMap::new();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                for read in Self::typed_reads(function) {
+                    let functions = readers.entry(read).or_default();
+                    if !functions.iter().any(|f| f.name == function.name) {
+                        functions.push(function);
+                    }
+                }
+            }
+        }
+
+        readers
+            .into_iter()
+            .filter(|(_, functions)| functions.len() >= MIN_READERS)
+            .map(|((key, type_name), functions)| {
+                let names: Vec<&str> = functions.iter().map(|f| f.name.as_str()).collect();
+                RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "{} functions ({}) each read '{}' from storage key '{}'",
+                        functions.len(),
+                        names.join(", "),
+                        type_name,
+                        key
+                    ),
+                    suggestion: format!(
+                        "Read '{}' through one helper such as fn load_config(env: &Env) -> {} so the key and unwrap live in one place",
+                        type_name, type_name
+                    ),
+                    line_number: functions[0].line_number,
+                    column_number: 0,
+                    variable_name: key,
+                    severity: self.severity(),
+                    estimated_saving: None,
+                }
+            })
+            .collect()
+    }
+}
+
+impl RepeatedConfigReadRule {
+    /// `(key, type)` of each storage `get` whose result type is written out and is a struct
+    fn typed_reads(function: &SorobanFunction) -> Vec<(String, String)> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let stripped = strip_comments_and_strings(body);
+        // `let cfg: Config = ...get(&key)` or `...get::<K, Config>(&key)`
+        let get_re = Regex::new(
+            r"(?:\blet\s+(?:mut\s+)?\w+\s*:\s*(\w+)\s*=\s*)?\benv\s*\.\s*storage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*get(?:::<[^,>]*,\s*(\w+)\s*>)?\(",
+        )
+        .unwrap();
+
+        let mut reads = Vec::new();
+        for call in get_re.captures_iter(&stripped) {
+            let (Some(whole), Some(type_name)) = (call.get(0), call.get(1).or(call.get(2))) else {
+                continue;
+            };
+            let type_name = type_name.as_str();
+            if VALUE_TYPES.contains(&type_name) || !type_name.starts_with(char::is_uppercase) {
+                continue;
+            }
+            let open = whole.end() - 1;
+            let Some(close) = matching_close(&stripped, open) else {
+                continue;
+            };
+            let Some(key) = split_top_level(&body[open + 1..close]).first().copied() else {
+                continue;
+            };
+            let key: String = key
+                .trim_start_matches('&')
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            reads.push((key, type_name.to_string()));
+        }
+
+        reads
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_config_read_in_three_functions_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Env};
+
+#[contracttype]
+pub struct Config {
+    pub fee_bps: u32,
+    pub paused: bool,
+}
+
+#[contractimpl]
+impl Pool {
+    pub fn swap(env: Env, amount: i128) -> i128 {
+        let config: Config = env.storage().instance().get(&DataKey::Config).unwrap();
+        amount * config.fee_bps as i128 / 10_000
+    }
+
+    pub fn deposit(env: Env, amount: i128) {
+        let config: Config = env.storage().instance().get(&DataKey::Config).unwrap();
+        assert!(!config.paused);
+    }
+
+    pub fn fee(env: Env) -> u32 {
+        env.storage().instance().get::<DataKey, Config>(&DataKey::Config).unwrap().fee_bps
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedConfigReadRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "DataKey::Config");
+        assert!(violations[0].description.contains("swap, deposit, fee"));
+    }
+
+    #[test]
+    fn test_centralized_config_accessor_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Env};
+
+#[contracttype]
+pub struct Config {
+    pub fee_bps: u32,
+    pub paused: bool,
+}
+
+#[contractimpl]
+impl Pool {
+    pub fn config(env: Env) -> Config {
+        env.storage().instance().get(&DataKey::Config).unwrap()
+    }
+
+    pub fn swap(env: Env, amount: i128) -> i128 {
+        let config = Self::config(env.clone());
+        amount * config.fee_bps as i128 / 10_000
+    }
+
+    pub fn deposit(env: Env, amount: i128) {
+        assert!(!Self::config(env).paused);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedConfigReadRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+//! Repeated Option match detection
+//!
+//! Matching `storage.get(&key)` in one `if let` and then again in a later
+//! `match` performs the lookup twice. Reading once into a local and matching
+//! on that local gives the same control flow for a single host call.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Rule for detecting the same `.get(...)` result destructured by several `match`/`if let`
+pub struct RepeatedOptionMatchRule {
+    enabled: bool,
+}
+
+impl Default for RepeatedOptionMatchRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for RepeatedOptionMatchRule {
+    fn id(&self) -> &str {
+        "soroban-repeated-option-match"
+    }
+
+    fn name(&self) -> &str {
+        "Repeated Option Match"
+    }
+
+    fn description(&self) -> &str {
+        "Detects several match/if let expressions in a function destructuring the result of the same .get() call"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl RepeatedOptionMatchRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let scrutinee_re = Regex::new(r"\bmatch\s+|\bif\s+let\s+[^=;{]*[^=!<>]=\s*").unwrap();
+        let lookup_re = Regex::new(r"\.get(?:::<[^>]*>)?\(").unwrap();
+
+        // normalized scrutinee -> offsets of the matches on it
+        let mut matched: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        for m in scrutinee_re.find_iter(&stripped) {
+            let Some(end) = Self::scrutinee_end(&stripped, m.end()) else {
+                continue;
+            };
+            // Keys come from the original text so string literals still distinguish them
+            let scrutinee: String = body[m.end()..end]
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            if lookup_re.is_match(&scrutinee) {
+                matched.entry(scrutinee).or_default().push(m.start());
+            }
+        }
+
+        matched
+            .into_iter()
+            .filter(|(_, offsets)| offsets.len() >= 2)
+            .map(|(scrutinee, offsets)| RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' matches '{}' {} times, repeating the lookup each time",
+                    function.name,
+                    scrutinee,
+                    offsets.len()
+                ),
+                suggestion: "Bind the result of the lookup to a local once and match on the local"
+                    .to_string(),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + offsets[1]),
+                column_number: 0,
+                variable_name: function.name.clone(),
+                severity: self.severity(),
+                estimated_saving: None,
+            })
+            .collect()
+    }
+
+    /// Offset of the `{` opening the match arms or `if let` block
+    fn scrutinee_end(text: &str, start: usize) -> Option<usize> {
+        let mut depth = 0i32;
+        for (idx, c) in text[start..].char_indices() {
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth -= 1,
+                '{' if depth == 0 => return Some(start + idx),
+                ';' => return None,
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_duplicated_option_match_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, user: Address, amount: i128) -> i128 {
+        if let Some(locked) = env.storage().persistent().get::<_, bool>(&user) {
+            assert!(!locked);
+        }
+        match env.storage().persistent().get::<_, bool>(&user) {
+            Some(_) => amount,
+            None => 0,
+        }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedOptionMatchRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("2 times"));
+    }
+
+    #[test]
+    fn test_single_match_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn withdraw(env: Env, user: Address, amount: i128) -> i128 {
+        match env.storage().persistent().get::<_, bool>(&user) {
+            Some(true) => 0,
+            _ => amount,
+        }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedOptionMatchRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
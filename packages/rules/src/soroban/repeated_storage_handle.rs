@@ -0,0 +1,158 @@
+//! Repeated storage handle detection
+//!
+//! Every `env.storage().persistent()` chain is a fresh host call. Binding the
+//! bucket once (`let storage = env.storage().persistent();`) and reusing it
+//! avoids paying for the same lookup at each access site.
+
+use crate::cost_model::HOST_CALL_INSTRUCTIONS;
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{EstimatedSaving, RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Number of fresh fetches of the same bucket tolerated within one function
+const MAX_FRESH_FETCHES: usize = 2;
+
+/// Rule for detecting storage buckets re-fetched at every access site
+pub struct RepeatedStorageHandleRule {
+    enabled: bool,
+}
+
+impl Default for RepeatedStorageHandleRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for RepeatedStorageHandleRule {
+    fn id(&self) -> &str {
+        "soroban-repeated-storage-handle"
+    }
+
+    fn name(&self) -> &str {
+        "Repeated Storage Handle"
+    }
+
+    fn description(&self) -> &str {
+        "Detects env.storage().<bucket>() chains re-fetched repeatedly within a function instead of being bound to a local"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl RepeatedStorageHandleRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body = strip_comments_and_strings(body);
+        let body_start = function.raw_definition.len() - body.len();
+        let chain_re =
+            Regex::new(r"\benv\s*\.\s*storage\(\)\s*\.\s*(instance|persistent|temporary)\(\)")
+                .unwrap();
+
+        // bucket -> (count, offset of first fetch)
+        let mut fetches: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        for captures in chain_re.captures_iter(&body) {
+            let start = captures.get(0).map(|m| m.start()).unwrap_or(0);
+            let entry = fetches.entry(captures[1].to_string()).or_insert((0, start));
+            entry.0 += 1;
+        }
+
+        fetches
+            .into_iter()
+            .filter(|(_, (count, _))| *count > MAX_FRESH_FETCHES)
+            .map(|(bucket, (count, first))| RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' fetches env.storage().{}() {} times",
+                    function.name, bucket, count
+                ),
+                suggestion: format!(
+                    "Bind the bucket once with 'let storage = env.storage().{}();' and reuse it",
+                    bucket
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + first),
+                column_number: 0,
+                variable_name: function.name.clone(),
+                severity: self.severity(),
+                estimated_saving: Some(EstimatedSaving::instructions(
+                    (count as u64 - 1) * HOST_CALL_INSTRUCTIONS,
+                )),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_three_fresh_fetches_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        let balance: i128 = env.storage().persistent().get(&from).unwrap_or(0);
+        env.storage().persistent().set(&from, &(balance - amount));
+        env.storage().persistent().extend_ttl(&from, 100, 1000);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedStorageHandleRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("3 times"));
+    }
+
+    #[test]
+    fn test_bound_handle_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        let storage = env.storage().persistent();
+        let from_balance: i128 = storage.get(&from).unwrap_or(0);
+        let to_balance: i128 = storage.get(&to).unwrap_or(0);
+        storage.set(&from, &(from_balance - amount));
+        storage.set(&to, &(to_balance + amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = RepeatedStorageHandleRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+//! Returned collection clone detection
+//!
+//! `self.balances.clone()` handed back to the caller copies every entry of a
+//! stored `Map`/`Vec` into the return value, and the cost grows with the
+//! collection. Callers almost always want one entry or one page of entries.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanField, SorobanFunction, SorobanRule,
+};
+use crate::{RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting functions that return a clone of a `Vec`/`Map` field of `self`
+pub struct ReturnedCollectionCloneRule {
+    enabled: bool,
+}
+
+impl Default for ReturnedCollectionCloneRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for ReturnedCollectionCloneRule {
+    fn id(&self) -> &str {
+        "soroban-returned-collection-clone"
+    }
+
+    fn name(&self) -> &str {
+        "Returned Collection Clone"
+    }
+
+    fn description(&self) -> &str {
+        "Detects functions returning self.<field>.clone() of a Vec/Map field, copying the whole collection to the caller"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let collections: Vec<&SorobanField> = contract
+            .contract_types
+            .iter()
+            .flat_map(|t| t.fields.iter())
+            .filter(|f| f.type_name.starts_with("Vec<") || f.type_name.starts_with("Map<"))
+            .collect();
+        if collections.is_empty() {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function, &collections));
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl ReturnedCollectionCloneRule {
+    fn check_function(
+        &self,
+        function: &SorobanFunction,
+        collections: &[&SorobanField],
+    ) -> Vec<RuleViolation> {
+        if function.return_type.is_none() {
+            return Vec::new();
+        }
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let clone_re = Regex::new(r"\bself\s*\.\s*(\w+)\s*\.\s*clone\s*\(\s*\)").unwrap();
+
+        let mut violations = Vec::new();
+        for clone in clone_re.captures_iter(&stripped) {
+            let whole = clone.get(0).unwrap();
+            let Some(field) = collections.iter().find(|f| f.name == clone[1]) else {
+                continue;
+            };
+            // Either `return self.x.clone();` or the function's tail expression
+            let returned = stripped[..whole.start()].trim_end().ends_with("return")
+                || stripped[whole.end()..].trim() == "}";
+            if !returned {
+                continue;
+            }
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' returns a clone of collection field '{}: {}', copying every entry",
+                    function.name, field.name, field.type_name
+                ),
+                suggestion: format!(
+                    "Return a single entry of '{}' by key, or a page of it with start/limit arguments",
+                    field.name
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + whole.start()),
+                column_number: 0,
+                variable_name: field.name.clone(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_returning_cloned_map_field_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Map};
+
+#[contracttype]
+pub struct Ledger {
+    pub balances: Map<Address, i128>,
+}
+
+#[contractimpl]
+impl Ledger {
+    pub fn all_balances(&self) -> Map<Address, i128> {
+        self.balances.clone()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = ReturnedCollectionCloneRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "balances");
+    }
+
+    #[test]
+    fn test_returning_looked_up_value_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Map};
+
+#[contracttype]
+pub struct Ledger {
+    pub balances: Map<Address, i128>,
+}
+
+#[contractimpl]
+impl Ledger {
+    pub fn balance(&self, id: Address) -> i128 {
+        self.balances.get(id).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = ReturnedCollectionCloneRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
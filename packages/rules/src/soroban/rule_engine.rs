@@ -2,16 +2,35 @@
 //!
 //! This module provides a specialized rule engine for analyzing Soroban smart contracts.
 
-use crate::soroban::{SorobanAnalyzer, SorobanContract, SorobanParser, SorobanResult};
-use crate::{RuleViolation, ViolationSeverity};
+use crate::soroban::{
+    AddressAsStringRule, AggregateByIterationRule, AsymmetricConditionalWriteRule,
+    BlindStructWriteRule, CallBeforeStateUpdateRule, ClientInterfaceMismatchRule,
+    ConstructedAuthAddressRule, DefaultCollectionDeriveRule, DuplicateFunctionRule, EnvCloneRule,
+    ExportedTestHelperRule, GetterUnwrapRule, HardcodedAddressRule, IgnoredConstructorParamRule,
+    InconsistentErrorTypeRule, InconsistentTtlRule, IteratorLengthRule, LargeEventPayloadRule,
+    LargeLiteralCollectionRule, LongFunctionRule, LoopAppendRule, LostStorageUpdateRule,
+    MapGetUnwrapRule, MissingAuthCheckRule, MissingContractDocRule, MissingContractMetaRule,
+    MissingContractTypeEnumRule, MissingKeyEnumRule, MixedAuthFormRule, MixedStorageBucketRule,
+    OverWidenedIntegerRule, PanicInResultFnRule, PerUserInstanceStorageRule,
+    PureContractFunctionRule, RedundantAuthCheckRule, RedundantCounterRule, ReferenceReturnRule,
+    RepeatedAdminReadRule, RepeatedConfigReadRule, RepeatedOptionMatchRule,
+    RepeatedStorageHandleRule, ReturnedCollectionCloneRule, SorobanAnalyzer, SorobanContract,
+    SorobanParser, SorobanResult, StorageAccessInLoopRule, StorageKeyTypoRule, StorageLenLoopRule,
+    StorageReadCloneRule, StringMapKeyRule, TimestampTypeRule, UnannotatedStorageGetRule,
+    UnboundedCapacityRule, UncappedMintRule, UncheckedTransferFromRule, UnfinishedCodeRule,
+    UnseparatedLiteralRule, UnusedParameterRule,
+};
+use crate::cost_model::{
+    estimate_cpu_cost, loop_nesting_depth, type_size_bytes, STORAGE_ACCESS_INSTRUCTIONS,
+};
+use crate::soroban::helpers::identifier_count;
+use crate::{EstimatedSaving, RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
 use std::collections::HashMap;
 
 /// Soroban-specific rule engine
 pub struct SorobanRuleEngine {
     /// Active rules in the engine
     rules: HashMap<String, Box<dyn SorobanRule>>,
-    /// Whether to enable all rules by default
-    enable_all_by_default: bool,
 }
 
 impl SorobanRuleEngine {
@@ -26,7 +45,6 @@ impl SorobanRuleEngine {
     pub fn new() -> Self {
         Self {
             rules: HashMap::new(),
-            enable_all_by_default: true,
         }
     }
     
@@ -45,7 +63,62 @@ impl SorobanRuleEngine {
             .add_rule(MissingConstructorRule::default())
             .add_rule(AdminPatternRule::default())
             .add_rule(InefficientIntegerTypesRule::default())
-            .add_rule(MissingErrorHandlingRule::default());
+            .add_rule(MissingErrorHandlingRule::default())
+            .add_rule(AddressAsStringRule::default())
+            .add_rule(CallBeforeStateUpdateRule::default())
+            .add_rule(MapGetUnwrapRule::default())
+            .add_rule(UnusedParameterRule::default())
+            .add_rule(ClientInterfaceMismatchRule::default())
+            .add_rule(RepeatedStorageHandleRule::default())
+            .add_rule(UnfinishedCodeRule::default())
+            .add_rule(RepeatedAdminReadRule::default())
+            .add_rule(LargeLiteralCollectionRule::default())
+            .add_rule(StringMapKeyRule::default())
+            .add_rule(RedundantCounterRule::default())
+            .add_rule(IgnoredConstructorParamRule::default())
+            .add_rule(RepeatedOptionMatchRule::default())
+            .add_rule(MixedStorageBucketRule::default())
+            .add_rule(StorageLenLoopRule::default())
+            .add_rule(MissingContractTypeEnumRule::default())
+            .add_rule(HardcodedAddressRule::default())
+            .add_rule(InconsistentErrorTypeRule::default())
+            .add_rule(PureContractFunctionRule::default())
+            .add_rule(IteratorLengthRule::default())
+            .add_rule(PanicInResultFnRule::default())
+            .add_rule(TimestampTypeRule::default())
+            .add_rule(AsymmetricConditionalWriteRule::default())
+            .add_rule(RedundantAuthCheckRule::default())
+            .add_rule(LoopAppendRule::default())
+            .add_rule(MissingContractMetaRule::default())
+            .add_rule(LargeEventPayloadRule::default())
+            .add_rule(EnvCloneRule::default())
+            .add_rule(DuplicateFunctionRule::default())
+            .add_rule(LongFunctionRule::default())
+            .add_rule(UnannotatedStorageGetRule::default())
+            .add_rule(MissingKeyEnumRule::default())
+            .add_rule(DefaultCollectionDeriveRule::default())
+            .add_rule(UncappedMintRule::default())
+            .add_rule(MixedAuthFormRule::default())
+            .add_rule(ReferenceReturnRule::default())
+            .add_rule(StorageReadCloneRule::default())
+            .add_rule(MissingContractDocRule::default())
+            .add_rule(UnseparatedLiteralRule::default())
+            .add_rule(InconsistentTtlRule::default())
+            .add_rule(AggregateByIterationRule::default())
+            .add_rule(ExportedTestHelperRule::default())
+            .add_rule(StorageAccessInLoopRule::default())
+            .add_rule(OverWidenedIntegerRule::default())
+            .add_rule(ReturnedCollectionCloneRule::default())
+            .add_rule(PerUserInstanceStorageRule::default())
+            .add_rule(BlindStructWriteRule::default())
+            .add_rule(UncheckedTransferFromRule::default())
+            .add_rule(StorageKeyTypoRule::default())
+            .add_rule(UnboundedCapacityRule::default())
+            .add_rule(MissingAuthCheckRule::default())
+            .add_rule(ConstructedAuthAddressRule::default())
+            .add_rule(RepeatedConfigReadRule::default())
+            .add_rule(GetterUnwrapRule::default())
+            .add_rule(LostStorageUpdateRule::default());
     }
     
     /// Analyze Soroban contract source code
@@ -67,6 +140,22 @@ impl SorobanRuleEngine {
         Ok(all_violations)
     }
     
+    /// Count, per enabled rule and per analyzer check, the functions and types it inspected in `source`
+    pub fn coverage(&self, source: &str, file_path: &str) -> SorobanResult<Vec<(String, RuleCoverage)>> {
+        let contract = SorobanParser::parse_contract(source, file_path)?;
+
+        let mut coverage: Vec<(String, RuleCoverage)> = self
+            .rules
+            .values()
+            .filter(|rule| rule.is_enabled())
+            .map(|rule| (rule.id().to_string(), rule.coverage(&contract)))
+            .chain(SorobanAnalyzer::coverage(&contract))
+            .collect();
+        coverage.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(coverage)
+    }
+    
     /// Get all registered rules
     pub fn get_rules(&self) -> Vec<&dyn SorobanRule> {
         self.rules.values().map(|r| r.as_ref()).collect()
@@ -80,6 +169,12 @@ impl SorobanRuleEngine {
     }
 }
 
+impl Default for SorobanRuleEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Trait for Soroban-specific rules
 pub trait SorobanRule: Send + Sync {
     /// Unique identifier for the rule
@@ -94,6 +189,16 @@ pub trait SorobanRule: Send + Sync {
     /// Severity level of violations from this rule
     fn severity(&self) -> ViolationSeverity;
     
+    /// Kind of problem the rule looks for
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Gas
+    }
+    
+    /// Whether `gasguard fix` can apply the suggestion without review
+    fn auto_fixable(&self) -> bool {
+        false
+    }
+    
     /// Whether this rule is currently enabled
     fn is_enabled(&self) -> bool;
     
@@ -102,6 +207,30 @@ pub trait SorobanRule: Send + Sync {
     
     /// Apply the rule to a parsed Soroban contract
     fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation>;
+
+    /// Count what the rule inspected in a parsed Soroban contract
+    ///
+    /// Most rules look at function bodies; rules that look at contract types
+    /// override this with [`type_coverage`].
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        function_coverage(contract)
+    }
+}
+
+/// Coverage over impl functions; a macro invocation in an impl, whose functions syn cannot see, is uninspected
+pub fn function_coverage(contract: &SorobanContract) -> RuleCoverage {
+    RuleCoverage {
+        inspected: contract.implementations.iter().map(|imp| imp.functions.len()).sum(),
+        uninspected: contract.implementations.iter().map(|imp| imp.macro_lines.len()).sum(),
+    }
+}
+
+/// Coverage over contract types; syn parses every field, so each type is inspected
+pub fn type_coverage(contract: &SorobanContract) -> RuleCoverage {
+    RuleCoverage {
+        inspected: contract.contract_types.len(),
+        uninspected: 0,
+    }
 }
 
 // --- Specific Rule Implementations ---
@@ -147,9 +276,10 @@ impl SorobanRule for UnusedStateVariablesRule {
         
         for contract_type in &contract.contract_types {
             for field in &contract_type.fields {
-                // Simple heuristic: Definition + Initialization = 2 occurrences.
-                let occurrences = contract.source.matches(&field.name).count();
-                if occurrences <= 2 {
+                // Outside its own definition, a field that is only initialized appears once
+                let occurrences = identifier_count(&contract.source, &field.name)
+                    .saturating_sub(identifier_count(&contract_type.raw_definition, &field.name));
+                if occurrences <= 1 {
                     violations.push(RuleViolation {
                         rule_name: self.id().to_string(),
                         description: format!("State variable '{}' appears to be unused", field.name),
@@ -158,6 +288,7 @@ impl SorobanRule for UnusedStateVariablesRule {
                         column_number: 0,
                         variable_name: field.name.clone(),
                         severity: self.severity(),
+                        estimated_saving: Some(EstimatedSaving::bytes(type_size_bytes(&field.type_name))),
                     });
                 }
             }
@@ -165,6 +296,10 @@ impl SorobanRule for UnusedStateVariablesRule {
         
         violations
     }
+    
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract)
+    }
 }
 
 /// Rule for detecting inefficient storage access patterns
@@ -228,6 +363,9 @@ impl SorobanRule for InefficientStorageAccessRule {
                         column_number: 0,
                         variable_name: function.name.clone(),
                         severity: self.severity(),
+                        estimated_saving: Some(EstimatedSaving::instructions(
+                            (total_ops as u64 - 1) * STORAGE_ACCESS_INSTRUCTIONS,
+                        )),
                     });
                 }
             }
@@ -296,6 +434,9 @@ impl SorobanRule for UnboundedLoopRule {
                         column_number: 0,
                         variable_name: function.name.clone(),
                         severity: self.severity(),
+                        estimated_saving: Some(EstimatedSaving::instructions(estimate_cpu_cost(
+                            loop_nesting_depth(func_source).max(1),
+                        ))),
                     });
                 }
             }
@@ -360,6 +501,7 @@ impl SorobanRule for ExpensiveStringOperationsRule {
                         column_number: 0,
                         variable_name: function.name.clone(),
                         severity: self.severity(),
+                        estimated_saving: None,
                     });
                 }
             }
@@ -397,6 +539,10 @@ impl SorobanRule for MissingConstructorRule {
         ViolationSeverity::Warning
     }
     
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+    
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -419,6 +565,7 @@ impl SorobanRule for MissingConstructorRule {
                 column_number: 0,
                 variable_name: contract.name.clone(),
                 severity: self.severity(),
+                estimated_saving: None,
             }]
         } else {
             Vec::new()
@@ -454,6 +601,10 @@ impl SorobanRule for AdminPatternRule {
         ViolationSeverity::Info
     }
     
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+    
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -480,11 +631,16 @@ impl SorobanRule for AdminPatternRule {
                 column_number: 0,
                 variable_name: contract.name.clone(),
                 severity: self.severity(),
+                estimated_saving: None,
             }]
         } else {
             Vec::new()
         }
     }
+    
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract)
+    }
 }
 
 /// Rule for detecting inefficient integer types
@@ -537,6 +693,9 @@ impl SorobanRule for InefficientIntegerTypesRule {
                         column_number: 0,
                         variable_name: field.name.clone(),
                         severity: self.severity(),
+                        estimated_saving: Some(EstimatedSaving::bytes(
+                            type_size_bytes(&field.type_name) - type_size_bytes("u64"),
+                        )),
                     });
                 }
             }
@@ -544,6 +703,10 @@ impl SorobanRule for InefficientIntegerTypesRule {
         
         violations
     }
+    
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract)
+    }
 }
 
 /// Rule for detecting missing error handling
@@ -574,6 +737,10 @@ impl SorobanRule for MissingErrorHandlingRule {
         ViolationSeverity::Medium
     }
     
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+    
     fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -603,6 +770,7 @@ impl SorobanRule for MissingErrorHandlingRule {
                         column_number: 0,
                         variable_name: function.name.clone(),
                         severity: self.severity(),
+                        estimated_saving: None,
                     });
                 }
             }
@@ -660,4 +828,80 @@ impl TestContract {
         );
         assert!(unused_found);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_unused_field_is_not_hidden_by_longer_identifiers_or_comments() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address};
+
+#[contracttype]
+pub struct Registry {
+    pub id: u64,
+    pub owner: Address,
+}
+
+#[contractimpl]
+impl Registry {
+    pub fn new(owner: Address) -> Self {
+        // id is assigned on first registration
+        Self { id: 0, owner }
+    }
+
+    pub fn is_owner(&self, valid_id: u64, candidate: Address) -> bool {
+        valid_id > 0 && self.owner == candidate
+    }
+}
+"#;
+
+        let mut engine = SorobanRuleEngine::new();
+        engine.add_rule(UnusedStateVariablesRule::default());
+
+        let violations = engine.analyze(source, "test.rs").unwrap();
+
+        let unused: Vec<_> = violations
+            .iter()
+            .filter(|v| v.rule_name == "soroban-unused-state-variables")
+            .map(|v| v.variable_name.as_str())
+            .collect();
+        assert_eq!(unused, ["id"]);
+    }
+
+    #[test]
+    fn test_coverage_counts_macro_generated_functions() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env};
+
+#[contracttype]
+pub struct Config {
+    pub admin: Address,
+}
+
+#[contractimpl]
+impl Vault {
+    pub fn ping(env: Env) -> u32 {
+        1
+    }
+
+    pub fn deposit(
+        env: Env,
+        amount: i128,
+    ) {
+        env.storage().instance().set(&DataKey::Total, &amount);
+    }
+
+    getters!(admin, total);
+}
+"#;
+        let engine = SorobanRuleEngine::with_default_rules();
+        let coverage: HashMap<String, RuleCoverage> =
+            engine.coverage(source, "test.rs").unwrap().into_iter().collect();
+        
+        assert_eq!(coverage.len(), engine.get_rules().len() + SorobanAnalyzer::CHECKS.len());
+        // The functions `getters!` expands to are invisible to the parser
+        let functions = coverage["soroban-unused-parameter"];
+        assert_eq!(functions, RuleCoverage { inspected: 2, uninspected: 1 });
+        assert_eq!(functions.percentage(), Some(200.0 / 3.0));
+        let types = coverage["soroban-timestamp-type"];
+        assert_eq!(types, RuleCoverage { inspected: 1, uninspected: 0 });
+    }
+}
@@ -0,0 +1,265 @@
+//! Storage access inside loops
+//!
+//! Every `env.storage()` read or write is a host call that meters ledger IO,
+//! so one inside a `for`/`while`/`loop` body multiplies that cost by the
+//! iteration count. When the key does not depend on the loop, the access can
+//! be hoisted outright: read once before the loop, write once after it.
+
+use crate::soroban::helpers::{
+    block_open, function_body, line_offset, matching_close, split_top_level,
+    strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeSet;
+
+/// Rule for detecting `env.storage()` reads and writes inside loop bodies
+pub struct StorageAccessInLoopRule {
+    enabled: bool,
+}
+
+impl Default for StorageAccessInLoopRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for StorageAccessInLoopRule {
+    fn id(&self) -> &str {
+        "soroban-storage-access-in-loop"
+    }
+
+    fn name(&self) -> &str {
+        "Storage Access in Loop"
+    }
+
+    fn description(&self) -> &str {
+        "Detects env.storage() reads and writes inside for/while/loop bodies, calling out loop-invariant keys that can be hoisted"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+/// A loop found in stripped source, as byte offsets
+struct LoopBlock {
+    /// Start of the `for`/`while`/`loop` keyword
+    header_start: usize,
+    /// Index of the body's opening brace
+    open: usize,
+    /// Index of the body's closing brace
+    close: usize,
+}
+
+impl StorageAccessInLoopRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let loops = Self::loops(&stripped);
+        if loops.is_empty() {
+            return Vec::new();
+        }
+        let access_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*(get|set|has|remove|update|try_update|extend_ttl)(?:::<[^>]*>)?\(",
+        )
+        .unwrap();
+
+        // An access inside nested loops is reported once, against its innermost loop
+        let mut seen = BTreeSet::new();
+        let mut violations = Vec::new();
+        for call in access_re.captures_iter(&stripped) {
+            let whole = call.get(0).unwrap();
+            let Some(innermost) = loops
+                .iter()
+                .filter(|l| l.open < whole.start() && whole.start() < l.close)
+                .min_by_key(|l| l.close - l.open)
+            else {
+                continue;
+            };
+            if !seen.insert(whole.start()) {
+                continue;
+            }
+
+            let method = call.get(1).unwrap().as_str();
+            let open = whole.end() - 1;
+            let key = matching_close(&stripped, open)
+                .and_then(|close| split_top_level(&body[open + 1..close]).first().copied())
+                .unwrap_or("")
+                .trim_start_matches('&')
+                .trim();
+            let invariant = !key.is_empty() && Self::is_loop_invariant(key, &stripped, innermost);
+
+            let (description, suggestion) = if invariant {
+                (
+                    format!(
+                        "Function '{}' calls storage {}() on loop-invariant key '{}' in every iteration",
+                        function.name, method, key
+                    ),
+                    format!(
+                        "Hoist the access to '{}' out of the loop: read it once before the loop and write it once after",
+                        key
+                    ),
+                )
+            } else {
+                (
+                    format!(
+                        "Function '{}' calls storage {}() inside a loop, paying a host call per iteration",
+                        function.name, method
+                    ),
+                    "Batch the entries into one stored Vec/Map, or collect the changes and write them once after the loop"
+                        .to_string(),
+                )
+            };
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description,
+                suggestion,
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + whole.start()),
+                column_number: 0,
+                variable_name: if key.is_empty() {
+                    function.name.clone()
+                } else {
+                    key.to_string()
+                },
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Every `for`/`while`/`loop` with a block body
+    fn loops(stripped: &str) -> Vec<LoopBlock> {
+        let loop_re = Regex::new(r"\b(for|while|loop)\b").unwrap();
+
+        loop_re
+            .find_iter(stripped)
+            .filter_map(|m| {
+                let open = block_open(stripped, m.end())?;
+                let close = matching_close(stripped, open)?;
+                Some(LoopBlock {
+                    header_start: m.start(),
+                    open,
+                    close,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether `key` mentions no variable the loop binds or reassigns
+    fn is_loop_invariant(key: &str, stripped: &str, block: &LoopBlock) -> bool {
+        let ident_re = Regex::new(r"\b[a-z_]\w*\b").unwrap();
+        let pattern_re = Regex::new(r"^for\b(.*?)\bin\b").unwrap();
+        let let_re = Regex::new(r"\blet\s+(?:mut\s+)?\(?([^=:;]*)").unwrap();
+        let assign_re = Regex::new(r"\b([a-z_]\w*)\s*(?:[-+*/%|&^]|<<|>>)?=[^=]").unwrap();
+
+        let mut varying = BTreeSet::new();
+        let header = &stripped[block.header_start..block.open];
+        if let Some(pattern) = pattern_re.captures(header).and_then(|c| c.get(1)) {
+            varying.extend(ident_re.find_iter(pattern.as_str()).map(|m| m.as_str()));
+        }
+        let inner = &stripped[block.open + 1..block.close];
+        for binding in let_re.captures_iter(inner) {
+            varying.extend(
+                ident_re
+                    .find_iter(binding.get(1).unwrap().as_str())
+                    .map(|m| m.as_str()),
+            );
+        }
+        for assignment in assign_re.captures_iter(inner) {
+            varying.insert(assignment.get(1).unwrap().as_str());
+        }
+        varying.remove("mut");
+
+        let key = strip_comments_and_strings(key);
+        let invariant = !ident_re
+            .find_iter(&key)
+            .any(|ident| varying.contains(ident.as_str()));
+        invariant
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_storage_access_in_loop_is_flagged_once_per_access() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contractimpl]
+impl Airdrop {
+    pub fn distribute(env: Env, batches: Vec<Vec<Address>>, amount: i128) {
+        for batch in batches.iter() {
+            for user in batch.iter() {
+                let rate: i128 = env.storage().instance().get(&DataKey::Rate).unwrap();
+                env.storage().persistent().set(&DataKey::Balance(user), &(amount * rate));
+            }
+        }
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageAccessInLoopRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].variable_name, "DataKey::Rate");
+        assert!(violations[0].description.contains("loop-invariant key"));
+        assert!(violations[0].suggestion.starts_with("Hoist"));
+        assert_eq!(violations[1].variable_name, "DataKey::Balance(user)");
+        assert!(!violations[1].description.contains("loop-invariant"));
+    }
+
+    #[test]
+    fn test_storage_access_outside_loop_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contractimpl]
+impl Airdrop {
+    pub fn distribute(env: Env, users: Vec<Address>, amount: i128) {
+        let rate: i128 = env.storage().instance().get(&DataKey::Rate).unwrap();
+        let mut total = 0;
+        for _user in users.iter() {
+            total += amount * rate;
+        }
+        env.storage().instance().set(&DataKey::Total, &total);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageAccessInLoopRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,255 @@
+//! Storage key typo detection
+//!
+//! Storage keys written as string literals are compared byte for byte, so
+//! `symbol_short!("admin")` and `symbol_short!("Admin")` name two different
+//! ledger entries. A value written under one spelling and read under the
+//! other silently comes back empty.
+
+use crate::soroban::helpers::{
+    line_offset, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+use std::collections::BTreeMap;
+
+/// Rule for detecting storage key literals that differ only by case or a single character
+pub struct StorageKeyTypoRule {
+    enabled: bool,
+}
+
+impl Default for StorageKeyTypoRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for StorageKeyTypoRule {
+    fn id(&self) -> &str {
+        "soroban-storage-key-typo"
+    }
+
+    fn name(&self) -> &str {
+        "Storage Key Typo"
+    }
+
+    fn description(&self) -> &str {
+        "Detects storage key literals that differ only by case or one character, which address different entries"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let keys = Self::key_literals(&contract.source);
+        let spellings: Vec<(&String, &KeyUse)> = keys.iter().collect();
+
+        let mut violations = Vec::new();
+        for (idx, &(first, first_use)) in spellings.iter().enumerate() {
+            for &(second, second_use) in &spellings[idx + 1..] {
+                let Some(reason) = Self::confusable(first, second) else {
+                    continue;
+                };
+                // The spelling used less often, or introduced later, is the likely typo
+                let ((suspect, suspect_use), (intended, _)) =
+                    if (second_use.count, first_use.line) > (first_use.count, second_use.line) {
+                        ((first, first_use), (second, second_use))
+                    } else {
+                        ((second, second_use), (first, first_use))
+                    };
+
+                violations.push(RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Storage key \"{}\" {} key \"{}\"; they address different ledger entries",
+                        suspect, reason, intended
+                    ),
+                    suggestion: format!(
+                        "Use one spelling for this key, ideally a single const or DataKey variant instead of repeated \"{}\" literals",
+                        intended
+                    ),
+                    line_number: suspect_use.line,
+                    column_number: 0,
+                    variable_name: suspect.clone(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                });
+            }
+        }
+
+        violations
+    }
+}
+
+/// Where a key spelling first appears and how often it is used
+struct KeyUse {
+    line: usize,
+    count: usize,
+}
+
+impl StorageKeyTypoRule {
+    /// String literals used as storage keys, directly or through a `Symbol` const
+    fn key_literals(source: &str) -> BTreeMap<String, KeyUse> {
+        let stripped = strip_comments_and_strings(source);
+        let call_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*(?:get|set|has|remove|update|extend_ttl)(?:::<[^>]*>)?\(",
+        )
+        .unwrap();
+        let const_re = Regex::new(r"\bconst\s+\w+\s*:\s*Symbol\s*=([^;]*);").unwrap();
+        let literal_re =
+            Regex::new(r#"(?:symbol_short!|Symbol::new)\s*\((?:[^,()"]*,)?\s*"([^"]*)"\s*\)"#)
+                .unwrap();
+
+        let mut key_ranges = Vec::new();
+        for call in call_re.find_iter(&stripped) {
+            let open = call.end() - 1;
+            let Some(close) = matching_close(&stripped, open) else {
+                continue;
+            };
+            let Some(key) = split_top_level(&stripped[open + 1..close]).first().copied() else {
+                continue;
+            };
+            let start = stripped[open + 1..]
+                .find(key)
+                .map(|i| open + 1 + i)
+                .unwrap_or(open + 1);
+            key_ranges.push((start, start + key.len()));
+        }
+        for declaration in const_re.captures_iter(&stripped) {
+            if let Some(value) = declaration.get(1) {
+                key_ranges.push((value.start(), value.end()));
+            }
+        }
+
+        let mut keys: BTreeMap<String, KeyUse> = BTreeMap::new();
+        for (start, end) in key_ranges {
+            // Literal contents are blanked in `stripped`, so read them from the source
+            for literal in literal_re.captures_iter(&source[start..end]) {
+                let Some(text) = literal.get(1) else {
+                    continue;
+                };
+                let line = line_offset(source, start + text.start()) + 1;
+                keys.entry(text.as_str().to_string())
+                    .and_modify(|existing| {
+                        existing.count += 1;
+                        existing.line = existing.line.min(line);
+                    })
+                    .or_insert(KeyUse { line, count: 1 });
+            }
+        }
+
+        keys
+    }
+
+    /// How `a` resembles `b`, if they are close enough to be the same key mistyped
+    fn confusable(a: &str, b: &str) -> Option<&'static str> {
+        if a.eq_ignore_ascii_case(b) {
+            return Some("differs only by case from");
+        }
+        // Short keys and numbered series (`slot1`, `slot2`) are distinct on purpose
+        let without_digits = |s: &str| {
+            s.chars()
+                .filter(|c| !c.is_ascii_digit())
+                .collect::<String>()
+        };
+        if a.len().min(b.len()) < 5 || without_digits(a) == without_digits(b) {
+            return None;
+        }
+        (edit_distance(&a.to_ascii_lowercase(), &b.to_ascii_lowercase()) == 1)
+            .then_some("is one character away from")
+    }
+}
+
+/// Levenshtein distance between `a` and `b`, counted in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_keys_differing_by_case_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn init(env: Env, admin: Address) {
+        env.storage().instance().set(&symbol_short!("admin"), &admin);
+        env.storage().instance().set(&symbol_short!("balance"), &0i128);
+    }
+
+    pub fn pause(env: Env) {
+        let admin: Address = env.storage().instance().get(&symbol_short!("Admin")).unwrap();
+        admin.require_auth();
+        let _ = env.storage().instance().get::<_, i128>(&symbol_short!("balance"));
+        env.storage().instance().set(&symbol_short!("balanse"), &0i128);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageKeyTypoRule::default().apply(&contract);
+
+        let mut flagged: Vec<&str> = violations
+            .iter()
+            .map(|v| v.variable_name.as_str())
+            .collect();
+        flagged.sort();
+        assert_eq!(flagged, ["Admin", "balanse"]);
+        assert!(violations
+            .iter()
+            .any(|v| v.description.contains("differs only by case")));
+    }
+
+    #[test]
+    fn test_distinct_keys_are_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+
+const OWNER: Symbol = symbol_short!("owner");
+
+#[contractimpl]
+impl Vault {
+    pub fn init(env: Env, owner: Address) {
+        env.storage().instance().set(&OWNER, &owner);
+        env.storage().instance().set(&symbol_short!("admin"), &owner);
+        env.storage().instance().set(&symbol_short!("slot1"), &1u32);
+        env.storage().instance().set(&symbol_short!("slot2"), &2u32);
+        env.events().publish((symbol_short!("Admin"),), owner);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageKeyTypoRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
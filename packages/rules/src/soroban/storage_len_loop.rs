@@ -0,0 +1,188 @@
+//! Storage-backed loop bound detection
+//!
+//! `for i in 0..self.records.len()` over a persisted `Vec`/`Map` reads the
+//! length from the host and then fetches every element by index, which is
+//! O(n) ledger IO. Iterating the collection fetches it once.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanField, SorobanFunction, SorobanRule,
+};
+use crate::{RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting index loops bounded by `.len()` of a storage-backed collection field
+pub struct StorageLenLoopRule {
+    enabled: bool,
+}
+
+impl Default for StorageLenLoopRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for StorageLenLoopRule {
+    fn id(&self) -> &str {
+        "soroban-storage-len-loop"
+    }
+
+    fn name(&self) -> &str {
+        "Storage-Backed Loop Bound"
+    }
+
+    fn description(&self) -> &str {
+        "Detects for loops ranging over .len() of a storage-backed Vec/Map field, which re-reads the collection per index"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let collections: Vec<&SorobanField> = contract
+            .contract_types
+            .iter()
+            .flat_map(|t| t.fields.iter())
+            .filter(|f| f.type_name.starts_with("Vec<") || f.type_name.starts_with("Map<"))
+            .collect();
+        if collections.is_empty() {
+            return Vec::new();
+        }
+
+        let mut violations = Vec::new();
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function, &collections));
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl StorageLenLoopRule {
+    fn check_function(
+        &self,
+        function: &SorobanFunction,
+        collections: &[&SorobanField],
+    ) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let header_re = Regex::new(r"\bfor\b[^{;]*?\bin\b([^{;]*)\{").unwrap();
+        let bound_re = Regex::new(r"\.\.=?\s*\w+\s*\.\s*(\w+)\s*\.\s*len\s*\(\s*\)").unwrap();
+
+        let mut violations = Vec::new();
+        for header in header_re.captures_iter(&stripped) {
+            let (Some(whole), Some(range)) = (header.get(0), header.get(1)) else {
+                continue;
+            };
+            let Some(field_name) = bound_re
+                .captures(range.as_str())
+                .and_then(|c| c.get(1))
+                .map(|m| m.as_str())
+            else {
+                continue;
+            };
+            let Some(field) = collections.iter().find(|f| f.name == field_name) else {
+                continue;
+            };
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' loops over 0..{}.len() on storage-backed field '{}: {}', reading it once per index",
+                    function.name, field.name, field.name, field.type_name
+                ),
+                suggestion: format!(
+                    "Iterate '{}' with .iter() to fetch it once, or paginate with explicit start/limit arguments",
+                    field.name
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + whole.start()),
+                column_number: 0,
+                variable_name: field.name.clone(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_indexed_loop_over_storage_vec_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Vec};
+
+#[contracttype]
+pub struct Ledger {
+    pub records: Vec<i128>,
+}
+
+#[contractimpl]
+impl Audit {
+    pub fn total(env: Env, ledger: Ledger) -> i128 {
+        let mut sum = 0;
+        for i in 0..ledger.records.len() {
+            sum += ledger.records.get(i).unwrap();
+        }
+        sum
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageLenLoopRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "records");
+    }
+
+    #[test]
+    fn test_loop_over_local_vec_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Vec};
+
+#[contracttype]
+pub struct Ledger {
+    pub records: Vec<i128>,
+}
+
+#[contractimpl]
+impl Audit {
+    pub fn total(env: Env, amounts: Vec<i128>) -> i128 {
+        let mut sum = 0;
+        for i in 0..amounts.len() {
+            sum += amounts.get(i).unwrap();
+        }
+        sum
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageLenLoopRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
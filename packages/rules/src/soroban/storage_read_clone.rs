@@ -0,0 +1,172 @@
+//! Clone of a storage read detection
+//!
+//! A storage `.get()` already returns an owned value decoded from the
+//! ledger. Cloning that binding before modifying it copies the whole
+//! collection again; when the original is not used afterwards the clone
+//! can simply be dropped and the binding made `mut`.
+
+use crate::soroban::helpers::{
+    contains_identifier, function_body, line_offset, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting clones of storage-read bindings whose original is never used again
+pub struct StorageReadCloneRule {
+    enabled: bool,
+}
+
+impl Default for StorageReadCloneRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for StorageReadCloneRule {
+    fn id(&self) -> &str {
+        "soroban-storage-read-clone"
+    }
+
+    fn name(&self) -> &str {
+        "Clone of Storage Read"
+    }
+
+    fn description(&self) -> &str {
+        "Detects owned storage reads that are cloned before modification although the original binding is not used again"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl StorageReadCloneRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let read_re = Regex::new(
+            r"\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]*)?=[^;]*\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*get\b[^;]*;",
+        )
+        .unwrap();
+
+        let mut violations = Vec::new();
+        for read in read_re.captures_iter(&stripped) {
+            let (Some(whole), Some(name)) = (read.get(0), read.get(1)) else {
+                continue;
+            };
+            let clone_re = Regex::new(&format!(
+                r"\blet\s+(?:mut\s+)?(\w+)\s*(?::[^=;]*)?=\s*{}\s*\.\s*clone\s*\(\s*\)\s*;",
+                regex::escape(name.as_str())
+            ))
+            .unwrap();
+            let Some(clone) = clone_re.captures(&stripped[whole.end()..]) else {
+                continue;
+            };
+            let (Some(clone_stmt), Some(copy)) = (clone.get(0), clone.get(1)) else {
+                continue;
+            };
+            let clone_end = whole.end() + clone_stmt.end();
+            if contains_identifier(&stripped[clone_end..], name.as_str()) {
+                continue;
+            }
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' clones storage read '{}' into '{}' and never uses '{}' again",
+                    function.name,
+                    name.as_str(),
+                    copy.as_str(),
+                    name.as_str()
+                ),
+                suggestion: format!(
+                    "Declare `let mut {}` and modify it directly; the storage read already returns an owned value",
+                    name.as_str()
+                ),
+                line_number: function.line_number
+                    + line_offset(
+                        &function.raw_definition,
+                        body_start + whole.end() + clone_stmt.start(),
+                    ),
+                column_number: 0,
+                variable_name: name.as_str().to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_clone_of_storage_read_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contractimpl]
+impl Registry {
+    pub fn join(env: Env, member: Address) {
+        let members: Vec<Address> = env.storage().instance().get(&DataKey::Members).unwrap();
+        let mut updated = members.clone();
+        updated.push_back(member);
+        env.storage().instance().set(&DataKey::Members, &updated);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageReadCloneRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "members");
+    }
+
+    #[test]
+    fn test_direct_modification_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+
+#[contractimpl]
+impl Registry {
+    pub fn join(env: Env, member: Address) {
+        let mut members: Vec<Address> = env.storage().instance().get(&DataKey::Members).unwrap();
+        members.push_back(member);
+        env.storage().instance().set(&DataKey::Members, &members);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StorageReadCloneRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
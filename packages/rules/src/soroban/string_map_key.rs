@@ -0,0 +1,218 @@
+//! String-keyed map detection
+//!
+//! `Symbol` and `String` keys are hashed and stored byte for byte, so a map
+//! keyed by names costs more per entry than one keyed by a small integer id.
+//! When the keys are built at runtime from caller input, an id scheme is
+//! usually available.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, matching_close, split_top_level, strip_comments_and_strings,
+};
+use crate::soroban::{
+    function_coverage, type_coverage, SorobanContract, SorobanField, SorobanFunction, SorobanRule,
+};
+use crate::{RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `Map<Symbol, _>`/`Map<String, _>` fields accessed with runtime-built keys
+pub struct StringMapKeyRule {
+    enabled: bool,
+}
+
+impl Default for StringMapKeyRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for StringMapKeyRule {
+    fn id(&self) -> &str {
+        "soroban-string-map-key"
+    }
+
+    fn name(&self) -> &str {
+        "String Map Key"
+    }
+
+    fn description(&self) -> &str {
+        "Detects Map<Symbol, _> or Map<String, _> fields accessed with dynamically built keys where a numeric id would be cheaper"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let key_type_re = Regex::new(r"^Map\s*<\s*(Symbol|String)\s*,").unwrap();
+        let constant_keys = ConstantKeyPatterns::new();
+        let functions: Vec<&SorobanFunction> = contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .collect();
+
+        contract
+            .contract_types
+            .iter()
+            .flat_map(|ct| ct.fields.iter())
+            .filter_map(|field| {
+                let key_type = key_type_re.captures(field.type_name.trim())?[1].to_string();
+                let access_re = Regex::new(&format!(
+                    r"\.{}\s*\.\s*(?:get|try_get|set|contains_key|remove)\(",
+                    regex::escape(&field.name)
+                ))
+                .unwrap();
+                let (function, line_number) = functions.iter().find_map(|f| {
+                    Self::dynamic_access(f, &access_re, &constant_keys).map(|line| (*f, line))
+                })?;
+                Some(self.violation(field, &key_type, function, line_number))
+            })
+            .collect()
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract) + function_coverage(contract)
+    }
+}
+
+impl StringMapKeyRule {
+    /// Line of the first map access matched by `access_re` whose key is not a constant
+    fn dynamic_access(
+        function: &SorobanFunction,
+        access_re: &Regex,
+        constant_keys: &ConstantKeyPatterns,
+    ) -> Option<usize> {
+        let body = function_body(&function.raw_definition)?;
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+
+        let access = access_re.find_iter(&stripped).find(|m| {
+            let open_idx = m.end() - 1;
+            matching_close(&stripped, open_idx).is_some_and(|close_idx| {
+                !constant_keys.matches(
+                    split_top_level(&stripped[open_idx + 1..close_idx])
+                        .first()
+                        .copied()
+                        .unwrap_or(""),
+                )
+            })
+        })?;
+
+        Some(
+            function.line_number
+                + line_offset(&function.raw_definition, body_start + access.start()),
+        )
+    }
+
+    fn violation(
+        &self,
+        field: &SorobanField,
+        key_type: &str,
+        function: &SorobanFunction,
+        line_number: usize,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Field '{}' is a map keyed by {} and '{}' looks it up with a runtime-built key",
+                field.name, key_type, function.name
+            ),
+            suggestion: format!(
+                "If the keys of '{}' are enumerable, assign each a u32 id and use Map<u32, _> instead",
+                field.name
+            ),
+            line_number,
+            column_number: 0,
+            variable_name: field.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        }
+    }
+}
+
+/// Keys written out in the source, such as `symbol_short!("a")` or a `const`
+struct ConstantKeyPatterns {
+    const_re: Regex,
+    literal_ctor_re: Regex,
+}
+
+impl ConstantKeyPatterns {
+    fn new() -> Self {
+        Self {
+            const_re: Regex::new(r"^[A-Z][A-Z0-9_]*$").unwrap(),
+            literal_ctor_re: Regex::new(
+                r#"^(?:Symbol::new|String::from_str)\(\s*&?\s*\w+\s*,\s*"[^"]*"\s*\)$"#,
+            )
+            .unwrap(),
+        }
+    }
+
+    fn matches(&self, key: &str) -> bool {
+        let key = key.trim_start_matches('&').trim();
+
+        key.starts_with("symbol_short!")
+            || self.const_re.is_match(key)
+            || self.literal_ctor_re.is_match(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_string_keyed_balances_are_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Env, Map, String};
+
+#[contracttype]
+pub struct Ledger {
+    pub balances: Map<String, u64>,
+}
+
+#[contractimpl]
+impl Bank {
+    pub fn balance_of(env: Env, ledger: Ledger, name: String) -> u64 {
+        ledger.balances.get(name).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StringMapKeyRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "balances");
+    }
+
+    #[test]
+    fn test_address_keyed_balances_are_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Map};
+
+#[contracttype]
+pub struct Ledger {
+    pub balances: Map<Address, u64>,
+}
+
+#[contractimpl]
+impl Bank {
+    pub fn balance_of(env: Env, ledger: Ledger, owner: Address) -> u64 {
+        ledger.balances.get(owner).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = StringMapKeyRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -111,6 +111,7 @@ pub struct Token {
                 raw_definition: "".to_string(),
             }],
             implementations: vec![],
+            interfaces: vec![],
             source: r#"
 use soroban_sdk::{contract, contractimpl, contracttype, Address};
 
@@ -154,6 +155,7 @@ pub struct TestContract {
                 raw_definition: "".to_string(),
             }],
             implementations: vec![],
+            interfaces: vec![],
             source: "struct Test { never_used: u64 }".to_string(),
             file_path: "test.rs".to_string(),
         };
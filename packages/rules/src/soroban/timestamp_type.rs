@@ -0,0 +1,134 @@
+//! Timestamp field type detection
+//!
+//! `env.ledger().timestamp()` returns a `u64`. Storing it as a `String`, or
+//! widening it to `u128`/`i128`, spends extra ledger bytes and a conversion on
+//! every read and write for no additional range.
+
+use crate::cost_model::type_size_bytes;
+use crate::soroban::{type_coverage, SorobanContract, SorobanField, SorobanRule};
+use crate::{EstimatedSaving, RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+const OVERSIZED_TYPES: [&str; 3] = ["String", "u128", "i128"];
+
+/// Rule for detecting timestamp-like fields stored with a type other than `u64`
+pub struct TimestampTypeRule {
+    enabled: bool,
+}
+
+impl Default for TimestampTypeRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for TimestampTypeRule {
+    fn id(&self) -> &str {
+        "soroban-timestamp-type"
+    }
+
+    fn name(&self) -> &str {
+        "Timestamp Type"
+    }
+
+    fn description(&self) -> &str {
+        "Detects timestamp/created_at/expiry style fields typed as String, u128 or i128 instead of u64"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let name_re =
+            Regex::new(r"(?i)(?:timestamp|_at$|^expir|_expir|deadline|_time$|^time$)").unwrap();
+
+        contract
+            .contract_types
+            .iter()
+            .flat_map(|t| t.fields.iter())
+            .filter(|f| name_re.is_match(&f.name) && OVERSIZED_TYPES.contains(&f.type_name.trim()))
+            .map(|f| self.violation(f))
+            .collect()
+    }
+
+    fn coverage(&self, contract: &SorobanContract) -> RuleCoverage {
+        type_coverage(contract)
+    }
+}
+
+impl TimestampTypeRule {
+    fn violation(&self, field: &SorobanField) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Field '{}' looks like a ledger timestamp but is stored as '{}'",
+                field.name, field.type_name
+            ),
+            suggestion: format!(
+                "Declare '{}' as u64, the type returned by env.ledger().timestamp()",
+                field.name
+            ),
+            line_number: field.line_number,
+            column_number: 0,
+            variable_name: field.name.clone(),
+            severity: self.severity(),
+            estimated_saving: Some(EstimatedSaving::bytes(
+                type_size_bytes(&field.type_name).saturating_sub(type_size_bytes("u64")),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_u128_timestamp_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+pub struct Listing {
+    pub seller: Address,
+    pub created_at: u128,
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = TimestampTypeRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "created_at");
+        assert_eq!(
+            violations[0].estimated_saving,
+            Some(EstimatedSaving::bytes(8))
+        );
+    }
+
+    #[test]
+    fn test_u64_timestamp_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contracttype, Address};
+
+#[contracttype]
+pub struct Listing {
+    pub seller: Address,
+    pub created_at: u64,
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = TimestampTypeRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
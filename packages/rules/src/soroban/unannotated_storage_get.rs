@@ -0,0 +1,159 @@
+//! Un-annotated storage read detection
+//!
+//! `let x = env.storage().instance().get(&key).unwrap()` leaves the value type
+//! to inference. If `x` is later used where a different type fits, the read
+//! silently decodes the stored value as that type and fails at runtime
+//! instead of at compile time.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting storage reads bound by a `let` with neither a type annotation nor a turbofish
+pub struct UnannotatedStorageGetRule {
+    enabled: bool,
+}
+
+impl Default for UnannotatedStorageGetRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for UnannotatedStorageGetRule {
+    fn id(&self) -> &str {
+        "soroban-unannotated-storage-get"
+    }
+
+    fn name(&self) -> &str {
+        "Un-annotated Storage Read"
+    }
+
+    fn description(&self) -> &str {
+        "Detects storage .get() results bound without an explicit type, leaving the decoded type to inference"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl UnannotatedStorageGetRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        // A `:` after the binding means the `let` is annotated, so `\s*=` only matches bare ones
+        let let_re = Regex::new(r"\blet\s+(?:mut\s+)?(\w+)\s*=([^;]*)").unwrap();
+        let get_re = Regex::new(
+            r"\bstorage\(\)\s*\.\s*(?:instance|persistent|temporary)\(\)\s*\.\s*get\s*\(",
+        )
+        .unwrap();
+
+        let mut violations = Vec::new();
+        for binding in let_re.captures_iter(&stripped) {
+            let (Some(whole), Some(name), Some(value)) =
+                (binding.get(0), binding.get(1), binding.get(2))
+            else {
+                continue;
+            };
+            if !get_re.is_match(value.as_str()) {
+                continue;
+            }
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' binds storage read '{}' without a type, so the decoded type is inferred from later use",
+                    function.name,
+                    name.as_str()
+                ),
+                suggestion: format!(
+                    "Annotate the binding (`let {}: T = ...`) or call `get::<K, T>()` so the stored type is checked at compile time",
+                    name.as_str()
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + whole.start()),
+                column_number: 0,
+                variable_name: name.as_str().to_string(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_unannotated_storage_read_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Counter {
+    pub fn bump(env: Env) {
+        let count = env.storage().instance().get(&DataKey::Count).unwrap();
+        env.storage().instance().set(&DataKey::Count, &(count + 1));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnannotatedStorageGetRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "count");
+    }
+
+    #[test]
+    fn test_annotated_storage_read_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Counter {
+    pub fn bump(env: Env) {
+        let count: u32 = env.storage().instance().get(&DataKey::Count).unwrap();
+        let limit = env.storage().instance().get::<_, u32>(&DataKey::Limit).unwrap();
+        env.storage().instance().set(&DataKey::Count, &(count + limit));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnannotatedStorageGetRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+//! Caller-sized allocation detection
+//!
+//! `Vec::with_capacity(n)` reserves memory up front. When `n` comes straight
+//! from a contract argument, any caller can ask for an allocation that
+//! exhausts the invocation's memory budget before a single element is
+//! written.
+
+use crate::soroban::helpers::{
+    contains_identifier, function_body, line_offset, matching_close, strip_comments_and_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `with_capacity(` calls sized by an unchecked function parameter
+pub struct UnboundedCapacityRule {
+    enabled: bool,
+}
+
+impl Default for UnboundedCapacityRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for UnboundedCapacityRule {
+    fn id(&self) -> &str {
+        "soroban-unbounded-capacity"
+    }
+
+    fn name(&self) -> &str {
+        "Unbounded Capacity"
+    }
+
+    fn description(&self) -> &str {
+        "Detects with_capacity calls sized by a function parameter that is never bounded, letting callers exhaust memory"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl UnboundedCapacityRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let capacity_re = Regex::new(r"\bwith_capacity\s*\(").unwrap();
+
+        let mut violations = Vec::new();
+        for call in capacity_re.find_iter(&stripped) {
+            let open = call.end() - 1;
+            let Some(close) = matching_close(&stripped, open) else {
+                continue;
+            };
+            let argument = stripped[open + 1..close].trim();
+            // `n.min(MAX)` and `n.clamp(..)` are bounded by construction
+            if argument.contains(".min(") || argument.contains(".clamp(") {
+                continue;
+            }
+            let Some(param) = function
+                .params
+                .iter()
+                .find(|p| contains_identifier(argument, &p.name))
+            else {
+                continue;
+            };
+            if Self::is_checked(&stripped[..call.start()], &param.name) {
+                continue;
+            }
+
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' allocates with_capacity({}) sized by parameter '{}: {}', which any caller controls",
+                    function.name, argument, param.name, param.type_name
+                ),
+                suggestion: format!(
+                    "Reject or clamp '{}' against a fixed maximum before allocating, e.g. {}.min(MAX_ITEMS)",
+                    param.name, param.name
+                ),
+                line_number: function.line_number
+                    + line_offset(&function.raw_definition, body_start + call.start()),
+                column_number: 0,
+                variable_name: param.name.clone(),
+                severity: self.severity(),
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+
+    /// Whether `code` compares `param` against something, e.g. `if n > MAX { panic!() }`
+    fn is_checked(code: &str, param: &str) -> bool {
+        let name = regex::escape(param);
+        Regex::new(&format!(r"\b{}\s*(?:<|>)|(?:<|>)=?\s*{}\b", name, name))
+            .map(|re| re.is_match(code))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_capacity_from_parameter_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Batch {
+    pub fn prepare(env: Env, user_n: u32) -> u32 {
+        let slots: std::vec::Vec<u64> = std::vec::Vec::with_capacity(user_n as usize);
+        slots.len() as u32
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnboundedCapacityRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "user_n");
+        assert!(violations[0].description.contains("user_n as usize"));
+    }
+
+    #[test]
+    fn test_constant_or_checked_capacity_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contractimpl]
+impl Batch {
+    pub fn prepare(env: Env, user_n: u32) -> u32 {
+        let fixed: std::vec::Vec<u64> = std::vec::Vec::with_capacity(10);
+        if user_n > 64 {
+            panic!("too many slots");
+        }
+        let slots: std::vec::Vec<u64> = std::vec::Vec::with_capacity(user_n as usize);
+        (fixed.len() + slots.len()) as u32
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnboundedCapacityRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
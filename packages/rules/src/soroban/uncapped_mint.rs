@@ -0,0 +1,161 @@
+//! Uncapped mint detection
+//!
+//! A mint that adds to the total supply or a balance without first comparing
+//! against a maximum lets an authorized minter inflate supply without limit,
+//! and with wrapping arithmetic eventually overflow it.
+
+use crate::soroban::helpers::{function_body, line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting mint functions that increase supply or balances without a cap check
+pub struct UncappedMintRule {
+    enabled: bool,
+}
+
+impl Default for UncappedMintRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for UncappedMintRule {
+    fn id(&self) -> &str {
+        "soroban-uncapped-mint"
+    }
+
+    fn name(&self) -> &str {
+        "Uncapped Mint"
+    }
+
+    fn description(&self) -> &str {
+        "Detects mint functions that add to a supply or balance without a preceding comparison against a cap"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Medium
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        contract
+            .implementations
+            .iter()
+            .flat_map(|imp| imp.functions.iter())
+            .filter(|function| Self::is_mint(&function.name))
+            .filter_map(|function| self.check_function(function))
+            .collect()
+    }
+}
+
+impl UncappedMintRule {
+    fn is_mint(name: &str) -> bool {
+        name.split('_').any(|segment| segment == "mint")
+    }
+
+    fn check_function(&self, function: &SorobanFunction) -> Option<RuleViolation> {
+        let body = function_body(&function.raw_definition)?;
+        let body_start = function.raw_definition.len() - body.len();
+        let stripped = strip_comments_and_strings(body);
+        let increase_re =
+            Regex::new(r"(?i)\b(\w*(?:supply|balance)\w*)\s*(?:\+=?|\.\s*checked_add\s*\()")
+                .unwrap();
+        let bound_re = Regex::new(
+            r"(?i)\s(?:<=?|>=?)\s[^;{}]*\b\w*(?:max|cap|limit)\w*|\b\w*(?:max|cap|limit)\w*\b[^;{}]*\s(?:<=?|>=?)\s",
+        )
+        .unwrap();
+
+        let increase = increase_re.captures(&stripped)?;
+        let (whole, target) = (increase.get(0)?, increase.get(1)?);
+        // The cap may be checked before the increase or in the same condition
+        let statement_end = stripped[whole.end()..]
+            .find([';', '{', '}'])
+            .map(|i| whole.end() + i)
+            .unwrap_or(stripped.len());
+        if bound_re.is_match(&stripped[..statement_end]) {
+            return None;
+        }
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' increases '{}' without checking it against a maximum supply",
+                function.name,
+                target.as_str()
+            ),
+            suggestion: "Compare the new supply against a stored or constant cap (e.g. `if supply + amount > MAX_SUPPLY { panic_with_error!(...) }`) before minting".to_string(),
+            line_number: function.line_number
+                + line_offset(&function.raw_definition, body_start + whole.start()),
+            column_number: 0,
+            variable_name: target.as_str().to_string(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_uncapped_mint_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        read_admin(&env).require_auth();
+        let supply: i128 = env.storage().instance().get(&DataKey::Supply).unwrap_or(0);
+        env.storage().instance().set(&DataKey::Supply, &(supply + amount));
+        let balance: i128 = env.storage().persistent().get(&DataKey::Balance(to.clone())).unwrap_or(0);
+        env.storage().persistent().set(&DataKey::Balance(to), &(balance + amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UncappedMintRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "supply");
+    }
+
+    #[test]
+    fn test_capped_mint_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+const MAX_SUPPLY: i128 = 1_000_000_000;
+
+#[contractimpl]
+impl Token {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        read_admin(&env).require_auth();
+        let supply: i128 = env.storage().instance().get(&DataKey::Supply).unwrap_or(0);
+        if supply + amount > MAX_SUPPLY {
+            panic_with_error!(&env, Error::CapExceeded);
+        }
+        env.storage().instance().set(&DataKey::Supply, &(supply + amount));
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UncappedMintRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,175 @@
+//! Unchecked `transfer_from` detection
+//!
+//! In a SEP-41 token, `transfer_from` lets a spender move tokens on behalf of
+//! their owner, which is only safe if the spender's allowance is read and
+//! decreased by the amount moved. A `transfer_from` that never touches an
+//! allowance lets anyone drain any holder's balance.
+
+use crate::soroban::helpers::{function_body, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting token `transfer_from` functions that never consult an allowance
+pub struct UncheckedTransferFromRule {
+    enabled: bool,
+}
+
+impl Default for UncheckedTransferFromRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for UncheckedTransferFromRule {
+    fn id(&self) -> &str {
+        "soroban-unchecked-transfer-from"
+    }
+
+    fn name(&self) -> &str {
+        "Unchecked Transfer From"
+    }
+
+    fn description(&self) -> &str {
+        "Detects transfer_from in token contracts that never reads or decrements the spender's allowance"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::High
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        // Storage keys (`DataKey::Allowance`) and helpers (`spend_allowance`) both count
+        let allowance_re = Regex::new(r"(?i)\w*allowance\w*").unwrap();
+
+        contract
+            .implementations
+            .iter()
+            .flat_map(|i| i.functions.iter())
+            .filter(|f| f.name == "transfer_from")
+            .filter_map(|f| self.check_function(f, &allowance_re))
+            .collect()
+    }
+}
+
+impl UncheckedTransferFromRule {
+    fn check_function(
+        &self,
+        function: &SorobanFunction,
+        allowance_re: &Regex,
+    ) -> Option<RuleViolation> {
+        let body = function_body(&function.raw_definition)?;
+        let stripped = strip_comments_and_strings(body);
+        if allowance_re.is_match(&stripped) {
+            return None;
+        }
+
+        Some(RuleViolation {
+            rule_name: self.id().to_string(),
+            description: format!(
+                "Function '{}' moves tokens for a spender without reading or decrementing an allowance",
+                function.name
+            ),
+            suggestion: "Load the allowance for (from, spender), check it covers the amount and write back the reduced value before moving the balance".to_string(),
+            line_number: function.line_number,
+            column_number: 0,
+            variable_name: function.name.clone(),
+            severity: self.severity(),
+            estimated_saving: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_transfer_from_without_allowance_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        move_balance(&env, &from, &to, amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        move_balance(&env, &from, &to, amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UncheckedTransferFromRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "transfer_from");
+        assert_eq!(violations[0].severity, ViolationSeverity::High);
+    }
+
+    #[test]
+    fn test_transfer_from_spending_allowance_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        move_balance(&env, &from, &to, amount);
+    }
+
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        let key = DataKey::Allowance(from.clone(), spender);
+        let allowed: i128 = env.storage().temporary().get(&key).unwrap_or(0);
+        if allowed < amount {
+            panic!("insufficient allowance");
+        }
+        env.storage().temporary().set(&key, &(allowed - amount));
+        move_balance(&env, &from, &to, amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UncheckedTransferFromRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_transfer_from_is_flagged_without_a_transfer_function() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Vault {
+    pub fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        move_balance(&env, &from, &to, amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UncheckedTransferFromRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "transfer_from");
+    }
+}
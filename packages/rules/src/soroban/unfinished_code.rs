@@ -0,0 +1,185 @@
+//! Unfinished code detection
+//!
+//! `todo!()` and `unimplemented!()` compile fine but trap at runtime, so a
+//! contract shipped with them reverts every call that reaches them.
+
+use crate::soroban::helpers::{
+    function_body, line_offset, strip_comments_and_strings, strip_strings,
+};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleCategory, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Rule for detecting `todo!`, `unimplemented!` and TODO comments in contract functions
+pub struct UnfinishedCodeRule {
+    enabled: bool,
+}
+
+impl Default for UnfinishedCodeRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for UnfinishedCodeRule {
+    fn id(&self) -> &str {
+        "soroban-unfinished-code"
+    }
+
+    fn name(&self) -> &str {
+        "Unfinished Code"
+    }
+
+    fn description(&self) -> &str {
+        "Detects todo!(), unimplemented!() and TODO comments left in #[contractimpl] functions"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl UnfinishedCodeRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body_start = function.raw_definition.len() - body.len();
+        let line_of = |idx: usize| {
+            function.line_number + line_offset(&function.raw_definition, body_start + idx)
+        };
+        let mut violations = Vec::new();
+
+        // Macros only count as code, so ignore mentions inside comments and strings
+        let macro_re = Regex::new(r"\b(todo|unimplemented)!\s*[(\[{]").unwrap();
+        let stripped = strip_comments_and_strings(body);
+        for captures in macro_re.captures_iter(&stripped) {
+            let start = captures.get(0).map(|m| m.start()).unwrap_or(0);
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' calls {}!(), which traps the invocation at runtime",
+                    function.name, &captures[1]
+                ),
+                suggestion:
+                    "Implement the missing logic or return a contract error before deploying"
+                        .to_string(),
+                line_number: line_of(start),
+                column_number: 0,
+                variable_name: function.name.clone(),
+                severity: ViolationSeverity::High,
+                estimated_saving: None,
+            });
+        }
+
+        // Comments are kept but strings blanked, so `"// TODO"` in a literal is not a comment
+        let comment_re = Regex::new(r"//\s*(TODO|FIXME)\b").unwrap();
+        for m in comment_re.find_iter(&strip_strings(body)) {
+            violations.push(RuleViolation {
+                rule_name: self.id().to_string(),
+                description: format!(
+                    "Function '{}' contains an unresolved TODO comment",
+                    function.name
+                ),
+                suggestion: "Resolve or track outstanding work before publishing the contract"
+                    .to_string(),
+                line_number: line_of(m.start()),
+                column_number: 0,
+                variable_name: function.name.clone(),
+                severity: ViolationSeverity::Info,
+                estimated_saving: None,
+            });
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_todo_macro_is_flagged_high() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        todo!()
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnfinishedCodeRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(matches!(violations[0].severity, ViolationSeverity::High));
+    }
+
+    #[test]
+    fn test_clean_function_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn balance(env: Env, id: Address) -> i128 {
+        // Missing balances read as zero, not todo!()
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnfinishedCodeRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_todo_in_string_is_not_a_comment() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env, Symbol};
+
+#[contractimpl]
+impl Token {
+    pub fn label(env: Env) -> Symbol {
+        let _marker = "// TODO";
+        // FIXME: pick a shorter label
+        Symbol::new(&env, "label")
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnfinishedCodeRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line_number, 8);
+    }
+}
@@ -0,0 +1,169 @@
+//! Unseparated large literal detection
+//!
+//! In fee and supply math a literal like `1000000000000000000` is easy to
+//! miscount by a zero, silently scaling amounts by ten. Writing it as
+//! `1_000_000_000_000_000_000` makes the magnitude readable at a glance.
+
+use crate::soroban::helpers::{line_offset, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanRule};
+use crate::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use regex::Regex;
+
+/// Smallest integer literal reported when written without `_` separators
+pub const MIN_UNSEPARATED_LITERAL: u128 = 1_000_000;
+
+/// Rule for detecting large integer literals written without digit separators
+pub struct UnseparatedLiteralRule {
+    enabled: bool,
+}
+
+impl Default for UnseparatedLiteralRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for UnseparatedLiteralRule {
+    fn id(&self) -> &str {
+        "soroban-unseparated-literal"
+    }
+
+    fn name(&self) -> &str {
+        "Unseparated Large Literal"
+    }
+
+    fn description(&self) -> &str {
+        "Detects integer literals of a million or more written without _ digit separators"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let stripped = strip_comments_and_strings(&contract.source);
+        // Hex literals fail the leading `\b\d`, since `0x` has no word boundary
+        let literal_re = Regex::new(r"\b(\d[\d_]*)((?:[iu](?:8|16|32|64|128|size))?)\b").unwrap();
+
+        literal_re
+            .captures_iter(&stripped)
+            .filter_map(|captures| {
+                let (whole, digits) = (captures.get(0)?, captures.get(1)?);
+                // Skip float fractions and exponents such as `0.5000000`
+                if stripped[..whole.start()].ends_with('.')
+                    || stripped[whole.end()..].starts_with('.')
+                {
+                    return None;
+                }
+                if digits.as_str().contains('_') {
+                    return None;
+                }
+                let value: u128 = digits.as_str().parse().ok()?;
+                if value < MIN_UNSEPARATED_LITERAL {
+                    return None;
+                }
+
+                Some(RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Literal {} has {} digits and no separators",
+                        whole.as_str(),
+                        digits.as_str().trim_start_matches('0').len()
+                    ),
+                    suggestion: format!(
+                        "Write it as {}{} so the magnitude can be checked at a glance",
+                        Self::separated(digits.as_str()),
+                        &captures[2]
+                    ),
+                    line_number: 1 + line_offset(&contract.source, whole.start()),
+                    column_number: 0,
+                    variable_name: whole.as_str().to_string(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                })
+            })
+            .collect()
+    }
+
+    fn coverage(&self, _contract: &SorobanContract) -> RuleCoverage {
+        // The whole source is searched, so there is nothing the parser can miss
+        RuleCoverage {
+            inspected: 1,
+            uninspected: 0,
+        }
+    }
+}
+
+impl UnseparatedLiteralRule {
+    /// `digits` with a `_` between every group of three, counted from the right
+    fn separated(digits: &str) -> String {
+        let mut out = String::new();
+        for (idx, c) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+                out.push('_');
+            }
+            out.push(c);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_unseparated_literal_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+const MAX_SUPPLY: i128 = 1000000000;
+
+#[contractimpl]
+impl Token {
+    pub fn cap(env: Env) -> i128 {
+        MAX_SUPPLY
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnseparatedLiteralRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line_number, 4);
+        assert!(violations[0].suggestion.contains("1_000_000_000"));
+    }
+
+    #[test]
+    fn test_separated_literal_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Env};
+
+const MAX_SUPPLY: i128 = 1_000_000_000;
+
+#[contractimpl]
+impl Token {
+    pub fn cap(env: Env) -> i128 {
+        MAX_SUPPLY + 999999
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnseparatedLiteralRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
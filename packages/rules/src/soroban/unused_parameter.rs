@@ -0,0 +1,173 @@
+//! Unused function parameter detection
+//!
+//! A contract function parameter that the body never reads is dead API
+//! surface: callers pay to serialize it and it often hides a logic bug.
+
+use crate::soroban::helpers::{contains_identifier, function_body, strip_comments_and_strings};
+use crate::soroban::{SorobanContract, SorobanFunction, SorobanRule};
+use crate::{RuleViolation, ViolationSeverity};
+
+/// Rule for detecting function parameters that are never read
+pub struct UnusedParameterRule {
+    enabled: bool,
+}
+
+impl Default for UnusedParameterRule {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl SorobanRule for UnusedParameterRule {
+    fn id(&self) -> &str {
+        "soroban-unused-parameter"
+    }
+
+    fn name(&self) -> &str {
+        "Unused Function Parameter"
+    }
+
+    fn description(&self) -> &str {
+        "Detects contract function parameters that are never used in the function body"
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    fn apply(&self, contract: &SorobanContract) -> Vec<RuleViolation> {
+        let mut violations = Vec::new();
+
+        for implementation in &contract.implementations {
+            for function in &implementation.functions {
+                violations.extend(self.check_function(function));
+            }
+        }
+
+        violations
+    }
+}
+
+impl UnusedParameterRule {
+    fn check_function(&self, function: &SorobanFunction) -> Vec<RuleViolation> {
+        let Some(body) = function_body(&function.raw_definition) else {
+            return Vec::new();
+        };
+        let body = strip_comments_and_strings(body);
+
+        function
+            .params
+            .iter()
+            .filter_map(|param| {
+                let name = param.name.trim_start_matches("mut ").trim();
+                // The environment handle is covered separately and `_` marks intent
+                if name == "env" || param.type_name.trim_start_matches('&') == "Env" || name.starts_with('_') {
+                    return None;
+                }
+                // Destructuring patterns bind several names the rule does not track
+                if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    let reason = format!("parameter `{}` is a destructuring pattern", name);
+                    return Some(self.skipped(function, &reason));
+                }
+                if contains_identifier(&body, name) {
+                    return None;
+                }
+
+                Some(RuleViolation {
+                    rule_name: self.id().to_string(),
+                    description: format!(
+                        "Parameter '{}' of function '{}' is never used",
+                        name, function.name
+                    ),
+                    suggestion: format!(
+                        "Remove '{}' from the signature, or prefix it with '_' if it is required for interface compatibility",
+                        name
+                    ),
+                    line_number: function.line_number,
+                    column_number: 0,
+                    variable_name: name.to_string(),
+                    severity: self.severity(),
+                    estimated_saving: None,
+                })
+            })
+            .collect()
+    }
+
+    fn skipped(&self, function: &SorobanFunction, reason: &str) -> RuleViolation {
+        RuleViolation::rule_skipped(self.id(), &function.name, reason, function.line_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::soroban::SorobanParser;
+
+    #[test]
+    fn test_unused_parameter_is_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        // amount should be subtracted here
+        env.storage().persistent().remove(&from);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnusedParameterRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "amount");
+    }
+
+    #[test]
+    fn test_fully_used_signature_is_not_flagged() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn mint(env: Env, to: Address, amount: i128, _memo: u64) {
+        env.storage().persistent().set(&to, &amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnusedParameterRule::default().apply(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_pattern_parameter_emits_skip_note() {
+        let source = r#"
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+#[contractimpl]
+impl Token {
+    pub fn transfer(env: Env, (from, to): (Address, Address), amount: i128) {
+        env.storage().persistent().set(&from, &amount);
+    }
+}
+"#;
+        let contract = SorobanParser::parse_contract(source, "test.rs").unwrap();
+        let violations = UnusedParameterRule::default().apply(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].is_skip_note());
+        assert_eq!(violations[0].variable_name, "transfer");
+        assert!(violations[0].description.contains("(from, to)"));
+    }
+}
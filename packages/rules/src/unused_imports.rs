@@ -0,0 +1,196 @@
+use crate::rule_engine::{Rule, RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity, find_identifier_usage, top_level_coverage};
+use syn::{Item, UseTree, Visibility};
+
+pub struct UnusedImportsRule;
+
+impl Rule for UnusedImportsRule {
+    fn name(&self) -> &str {
+        "unused-imports"
+    }
+
+    fn description(&self) -> &str {
+        "Identifies `use` imports whose names are never referenced in the file, which clutter the contract and obscure what it actually depends on."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn check(&self, ast: &[Item]) -> Vec<RuleViolation> {
+        let code_items: Vec<Item> = ast
+            .iter()
+            .filter(|item| !matches!(item, Item::Use(_)))
+            .cloned()
+            .collect();
+        let used = find_identifier_usage(&code_items);
+
+        let mut violations = Vec::new();
+        for item in ast {
+            let Item::Use(item_use) = item else {
+                continue;
+            };
+            // Re-exports are used by other crates, not by this file
+            if !matches!(item_use.vis, Visibility::Inherited) {
+                continue;
+            }
+
+            let mut imported = Vec::new();
+            Self::collect_imported_names(&item_use.tree, None, &mut imported);
+
+            for (name, path) in imported {
+                // Traits such as `IntoVal` are imported only to call their methods (`.into_val(`)
+                if used.contains(&name)
+                    || Self::method_name(&name).is_some_and(|method| used.contains(&method))
+                {
+                    continue;
+                }
+                violations.push(RuleViolation {
+                    rule_name: self.name().to_string(),
+                    description: format!("Import '{}' is never used in this file.", path),
+                    severity: self.severity(),
+                    line_number: 0,
+                    column_number: 0,
+                    variable_name: name.clone(),
+                    suggestion: format!("Remove '{}' from the use statement.", name),
+                    estimated_saving: None,
+                });
+            }
+        }
+
+        violations
+    }
+
+    fn coverage(&self, ast: &[Item]) -> RuleCoverage {
+        top_level_coverage(ast, |item| matches!(item, Item::Use(_)))
+    }
+}
+
+impl UnusedImportsRule {
+    /// Snake-case form of a multi-word trait name, which is how its method is usually named
+    ///
+    /// Single words are left out so that `Map` is not taken as used by a `.map(` call.
+    fn method_name(name: &str) -> Option<String> {
+        let mut method = String::new();
+        for (idx, c) in name.char_indices() {
+            if c.is_uppercase() && idx > 0 {
+                method.push('_');
+            }
+            method.extend(c.to_lowercase());
+        }
+        method.contains('_').then_some(method)
+    }
+
+    /// Collect `(bound name, full path)` for every non-glob name in a use tree
+    fn collect_imported_names(
+        tree: &UseTree,
+        prefix: Option<&str>,
+        names: &mut Vec<(String, String)>,
+    ) {
+        let join = |ident: &str| match prefix {
+            Some(prefix) => format!("{}::{}", prefix, ident),
+            None => ident.to_string(),
+        };
+
+        match tree {
+            UseTree::Path(use_path) => {
+                let path = join(&use_path.ident.to_string());
+                Self::collect_imported_names(&use_path.tree, Some(&path), names);
+            }
+            UseTree::Group(group) => {
+                for item in &group.items {
+                    Self::collect_imported_names(item, prefix, names);
+                }
+            }
+            UseTree::Name(use_name) => {
+                let ident = use_name.ident.to_string();
+                // `use a::b::{self}` binds the parent module name
+                let bound = if ident == "self" {
+                    prefix
+                        .and_then(|p| p.rsplit("::").next())
+                        .unwrap_or("self")
+                        .to_string()
+                } else {
+                    ident.clone()
+                };
+                names.push((bound, join(&ident)));
+            }
+            UseTree::Rename(rename) => {
+                let alias = rename.rename.to_string();
+                if alias != "_" {
+                    names.push((alias, join(&rename.ident.to_string())));
+                }
+            }
+            UseTree::Glob(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn test_unused_grouped_import_is_flagged() {
+        let code = quote! {
+            use soroban_sdk::{contract, contractimpl, Env, Map};
+
+            #[contract]
+            pub struct Counter;
+
+            #[contractimpl]
+            impl Counter {
+                pub fn ping(env: Env) -> u32 {
+                    1
+                }
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = UnusedImportsRule.check(&file.items);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "Map");
+    }
+
+    #[test]
+    fn test_used_and_glob_imports_are_not_flagged() {
+        let code = quote! {
+            use soroban_sdk::{contract, Env};
+            use soroban_sdk::token::*;
+
+            #[contract]
+            pub struct Counter;
+
+            pub fn ping(env: Env) {}
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = UnusedImportsRule.check(&file.items);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_trait_imported_for_its_methods_is_not_flagged() {
+        let code = quote! {
+            use soroban_sdk::{Env, FromVal, IntoVal, Map, TryFromVal, Val};
+
+            pub fn wrap(env: Env, x: u32, keys: Option<u32>) -> Val {
+                let _ = u32::try_from_val(&env, &x.into_val(&env));
+                let _ = keys.map(|k| k + 1);
+                x.into_val(&env)
+            }
+        };
+
+        let file: syn::File = syn::parse2(code).unwrap();
+        let violations = UnusedImportsRule.check(&file.items);
+
+        let names: Vec<&str> = violations.iter().map(|v| v.variable_name.as_str()).collect();
+        assert_eq!(names, ["FromVal", "Map"]);
+    }
+}
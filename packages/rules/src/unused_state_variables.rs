@@ -1,6 +1,4 @@
-use crate::rule_engine::{
-    extract_struct_fields, find_variable_usage, Rule, RuleViolation, ViolationSeverity,
-};
+use crate::rule_engine::{find_variable_usage, top_level_coverage, Rule, RuleCoverage, RuleViolation, ViolationSeverity};
 use quote::ToTokens;
 use std::collections::HashSet;
 use syn::{Item, ItemImpl, ItemStruct, Meta};
@@ -16,6 +14,10 @@ impl Rule for UnusedStateVariablesRule {
         "Identifies state variables in Soroban contracts that are never read or written to, helping developers minimize storage footprint and ledger rent."
     }
 
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
     fn check(&self, ast: &[Item]) -> Vec<RuleViolation> {
         let mut violations = Vec::new();
 
@@ -24,31 +26,32 @@ impl Rule for UnusedStateVariablesRule {
         let contract_impls = self.find_contract_impls(ast);
 
         for (struct_name, struct_item) in &contract_structs {
-            // Get all state variables from the struct
-            let state_variables = extract_struct_fields(struct_item);
-
             // Find the corresponding implementation
             if let Some(impl_block) = contract_impls.get(struct_name) {
                 // Find all used variables in the implementation
                 let used_variables = find_variable_usage(impl_block);
 
                 // Check each state variable for usage
-                for var_name in &state_variables {
-                    if !self.is_variable_used(var_name, &used_variables) {
+                for ident in struct_item.fields.iter().filter_map(|f| f.ident.as_ref()) {
+                    let var_name = ident.to_string();
+                    if !self.is_variable_used(&var_name, &used_variables) {
+                        // Spans are only meaningful for parsed source; tokens built in memory report line 0
+                        let start = ident.span().start();
                         violations.push(RuleViolation {
                             rule_name: self.name().to_string(),
                             description: format!(
                                 "State variable '{}' is declared but never used in contract '{}'. This wastes storage space and increases ledger rent costs.",
                                 var_name, struct_name
                             ),
-                            severity: ViolationSeverity::Warning,
-                            line_number: 0, 
-                            column_number: 0,
+                            severity: self.severity(),
+                            line_number: start.line,
+                            column_number: if start.line > 0 { start.column + 1 } else { 0 },
                             variable_name: var_name.clone(),
                             suggestion: format!(
                                 "Consider removing the unused state variable '{}' or implement functionality that uses it. If it's reserved for future use, add a comment explaining its purpose.",
                                 var_name
                             ),
+                            estimated_saving: None,
                         });
                     }
                 }
@@ -57,6 +60,10 @@ impl Rule for UnusedStateVariablesRule {
 
         violations
     }
+
+    fn coverage(&self, ast: &[Item]) -> RuleCoverage {
+        top_level_coverage(ast, |item| matches!(item, Item::Struct(_)))
+    }
 }
 
 impl UnusedStateVariablesRule {
@@ -153,41 +160,43 @@ mod tests {
 
     #[test]
     fn test_unused_state_variable_detection() {
-        let code = quote! {
-            #[contracttype]
-            pub struct MyContract {
-                pub used_var: u64,
-                pub unused_var: String,
-                pub another_used: bool,
-            }
+        let code = r#"
+#[contracttype]
+pub struct MyContract {
+    pub used_var: u64,
+    pub unused_var: String,
+    pub another_used: bool,
+}
 
-            #[contractimpl]
-            impl MyContract {
-                pub fn new() -> Self {
-                    Self {
-                        used_var: 42,
-                        another_used: true,
-                        unused_var: "never_used".to_string(),
-                    }
-                }
+#[contractimpl]
+impl MyContract {
+    pub fn new() -> Self {
+        Self {
+            used_var: 42,
+            another_used: true,
+            unused_var: "never_used".to_string(),
+        }
+    }
 
-                pub fn get_used_var(&self) -> u64 {
-                    self.used_var
-                }
+    pub fn get_used_var(&self) -> u64 {
+        self.used_var
+    }
 
-                pub fn set_another_used(&mut self, value: bool) {
-                    self.another_used = value;
-                }
-            }
-        };
+    pub fn set_another_used(&mut self, value: bool) {
+        self.another_used = value;
+    }
+}
+"#;
 
         let rule = UnusedStateVariablesRule;
-        let file: syn::File = syn::parse2(code).unwrap();
+        let file = syn::parse_file(code).unwrap();
         let violations = rule.check(&file.items);
 
-        // Should find one unused variable
+        // Should find one unused variable, at the line and column of its name
         assert_eq!(violations.len(), 1);
         assert_eq!(violations[0].variable_name, "unused_var");
+        assert_eq!(violations[0].line_number, 5);
+        assert_eq!(violations[0].column_number, 9);
     }
 
     #[test]
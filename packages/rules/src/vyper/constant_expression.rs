@@ -0,0 +1,181 @@
+use crate::rule_engine::{RuleViolation, ViolationSeverity};
+use crate::vyper::parser::{VyperContract, VyperFunction};
+use crate::vyper::redundant_external::VyperRule;
+use regex::Regex;
+
+/// Rule for detecting arithmetic over only literals and constants inside functions
+///
+/// An expression such as `10 ** 18` written in a function body is evaluated
+/// on every call. Declaring it once as a module-level `constant` lets the
+/// compiler fold it.
+pub struct ConstantExpressionRule;
+
+impl VyperRule for ConstantExpressionRule {
+    fn name(&self) -> &str {
+        "vyper-constant-expression"
+    }
+
+    fn description(&self) -> &str {
+        "Detects arithmetic expressions in function bodies whose operands are all literals or constants, which could be module-level constants."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
+        let constants: Vec<String> = contract
+            .state_variables
+            .iter()
+            .filter(|variable| variable.is_constant)
+            .map(|variable| regex::escape(&variable.name))
+            .collect();
+        let operand = if constants.is_empty() {
+            r"\b\d\w*(?:\.\d+)?".to_string()
+        } else {
+            format!(r"\b(?:\d\w*(?:\.\d+)?|(?:{})\b)", constants.join("|"))
+        };
+        let expression_pattern = Regex::new(&format!(
+            r"{operand}(?:\s*(?:\*\*|//|[-+*/%])\s*{operand})+"
+        ))
+        .unwrap();
+        let string_pattern = Regex::new(r#""[^"]*"|'[^']*'"#).unwrap();
+
+        let mut violations = Vec::new();
+        for function in &contract.functions {
+            for (offset, line) in function.body.lines().enumerate() {
+                let code = line.split('#').next().unwrap_or("");
+                let code =
+                    string_pattern.replace_all(code, |m: &regex::Captures| " ".repeat(m[0].len()));
+                for expression in expression_pattern.find_iter(&code) {
+                    if Self::is_complete_operand(&code, expression.start(), expression.end()) {
+                        violations.push(self.create_violation(
+                            function,
+                            expression.as_str(),
+                            function.body_start_line + offset,
+                        ));
+                    }
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl ConstantExpressionRule {
+    /// Binding strength of a binary operator at the start of `text`, if any
+    fn precedence(text: &str) -> Option<u8> {
+        if text.starts_with("**") {
+            Some(3)
+        } else if text.starts_with(['*', '/', '%']) {
+            Some(2)
+        } else if text.starts_with(['+', '-']) {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `code[start..end]` is a whole subexpression rather than a slice of a longer one
+    ///
+    /// In `x * 2 + 3` the literal run `2 + 3` is not evaluated on its own, while
+    /// in `x * 10 ** 18` the run `10 ** 18` is, because `**` binds tighter.
+    fn is_complete_operand(code: &str, start: usize, end: usize) -> bool {
+        let before = code[..start].trim_end();
+        if before.ends_with('.') {
+            return false;
+        }
+        let expression = &code[start..end];
+        let weakest = (0..expression.len())
+            .filter_map(|idx| {
+                let rest = &expression[idx..];
+                // Skip the second character of `**` and `//`
+                let previous = expression[..idx].chars().next_back();
+                if previous == rest.chars().next() && matches!(previous, Some('*' | '/')) {
+                    return None;
+                }
+                Self::precedence(rest)
+            })
+            .min()
+            .unwrap_or(3);
+
+        let left = if before.ends_with("**") {
+            Some(3)
+        } else {
+            before
+                .chars()
+                .next_back()
+                .and_then(|c| Self::precedence(&c.to_string()))
+        };
+        let right = Self::precedence(code[end..].trim_start());
+
+        let left_ok = left.is_none_or(|p| weakest > p || (weakest == 3 && p == 3));
+        let right_ok = right.is_none_or(|p| weakest > p || (weakest == p && p != 3));
+        left_ok && right_ok
+    }
+
+    fn create_violation(
+        &self,
+        function: &VyperFunction,
+        expression: &str,
+        line_number: usize,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Function '{}' computes constant expression '{}' on every call.",
+                function.name, expression
+            ),
+            severity: self.severity(),
+            line_number,
+            column_number: 1,
+            variable_name: function.name.clone(),
+            suggestion: format!(
+                "Declare '{}' once as a module-level constant, e.g. 'SCALE: constant(uint256) = {}'.",
+                expression, expression
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_only_expression_is_flagged() {
+        let source = r#"
+# @version ^0.3.0
+FEE_BPS: constant(uint256) = 30
+
+@external
+@view
+def to_wei(amount: uint256) -> uint256:
+    fee: uint256 = amount * FEE_BPS / 10_000
+    return (amount - fee) * 10 ** 18
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = ConstantExpressionRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].description.contains("'10 ** 18'"));
+        assert_eq!(violations[0].line_number, 9);
+    }
+
+    #[test]
+    fn test_expression_with_parameter_is_not_flagged() {
+        let source = r#"
+# @version ^0.3.0
+@external
+@view
+def scale(amount: uint256, decimals: uint256) -> uint256:
+    return amount * 10 ** decimals + 1
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = ConstantExpressionRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::rule_engine::{RuleCategory, RuleViolation, ViolationSeverity};
+use crate::vyper::parser::{VyperContract, VyperFunction};
+use crate::vyper::redundant_external::VyperRule;
+
+/// Rule for detecting function decorators written out of the canonical order
+///
+/// Vyper reads visibility, then mutability, then reentrancy guards. Some
+/// compiler versions reject `@view` ahead of `@external`, and a misplaced
+/// `@payable` is easy to miss in review.
+pub struct DecoratorOrderRule;
+
+impl VyperRule for DecoratorOrderRule {
+    fn name(&self) -> &str {
+        "vyper-decorator-order"
+    }
+
+    fn description(&self) -> &str {
+        "Detects functions whose decorators are not in the canonical order: visibility (@external/@internal/@deploy), then mutability (@view/@pure/@payable/@nonpayable), then @nonreentrant."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
+        contract
+            .functions
+            .iter()
+            .filter(|function| {
+                let ranks: Vec<usize> = function
+                    .decorators
+                    .iter()
+                    .filter_map(|d| Self::rank(d))
+                    .collect();
+                ranks.windows(2).any(|pair| pair[0] > pair[1])
+            })
+            .map(|function| self.create_violation(function))
+            .collect()
+    }
+}
+
+impl DecoratorOrderRule {
+    /// Position of a decorator's group in the canonical order; unknown decorators are not ranked
+    fn rank(decorator: &str) -> Option<usize> {
+        match decorator {
+            "external" | "internal" | "deploy" => Some(0),
+            "view" | "pure" | "payable" | "nonpayable" => Some(1),
+            "nonreentrant" => Some(2),
+            _ => None,
+        }
+    }
+
+    fn create_violation(&self, function: &VyperFunction) -> RuleViolation {
+        let written: Vec<String> = function
+            .decorators
+            .iter()
+            .map(|d| format!("@{}", d))
+            .collect();
+        let mut canonical = written.clone();
+        // Stable sort keeps unranked decorators next to the ones written before them
+        canonical.sort_by_key(|d| Self::rank(&d[1..]).unwrap_or(usize::MAX));
+
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Function '{}' declares decorators in the order {}.",
+                function.name,
+                written.join(" ")
+            ),
+            severity: self.severity(),
+            line_number: function.line_number,
+            column_number: 1,
+            variable_name: function.name.clone(),
+            suggestion: format!(
+                "Reorder the decorators as {}: visibility first, then mutability, then @nonreentrant.",
+                canonical.join(" ")
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutability_before_visibility_is_flagged() {
+        let source = r#"
+# @version ^0.3.0
+@view
+@external
+def total() -> uint256:
+    return self.supply
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = DecoratorOrderRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].suggestion.contains("@external @view"));
+        assert_eq!(violations[0].line_number, 3);
+    }
+
+    #[test]
+    fn test_canonical_order_is_not_flagged() {
+        let source = r#"
+# @version ^0.3.0
+@external
+@view
+def total() -> uint256:
+    return self.supply
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = DecoratorOrderRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -1,5 +1,19 @@
+pub mod constant_expression;
+pub mod decorator_order;
 pub mod parser;
+pub mod public_sensitive_data;
 pub mod redundant_external;
+pub mod redundant_public_getter;
+pub mod unemitted_event;
+pub mod unguarded_state_change;
+pub mod unused_import;
 
+pub use constant_expression::*;
+pub use decorator_order::*;
 pub use parser::*;
+pub use public_sensitive_data::*;
 pub use redundant_external::*;
+pub use redundant_public_getter::*;
+pub use unemitted_event::*;
+pub use unguarded_state_change::*;
+pub use unused_import::*;
@@ -8,6 +8,10 @@ pub struct VyperFunction {
     pub decorators: Vec<String>,
     pub line_number: usize,
     pub column_number: usize,
+    /// Indented lines following the `def` line, up to the next top-level statement
+    pub body: String,
+    /// Line number of the first body line
+    pub body_start_line: usize,
 }
 
 /// Represents a function call within the contract
@@ -18,11 +22,56 @@ pub struct VyperFunctionCall {
     pub line_number: usize,
 }
 
+/// Represents a name brought into scope by an `import` or `from ... import` statement
+#[derive(Debug, Clone)]
+pub struct VyperImport {
+    /// Module path the name was imported from
+    pub module: String,
+    /// Name the import is bound to in this contract (alias if present)
+    pub name: String,
+    pub line_number: usize,
+}
+
+/// Represents a top-level storage variable declaration such as `owner: public(address)`
+#[derive(Debug, Clone)]
+pub struct VyperStateVariable {
+    pub name: String,
+    /// Declared type with any `public(...)` and `constant(...)` wrappers removed
+    pub type_name: String,
+    /// Whether the declaration generates an external getter
+    pub is_public: bool,
+    /// Whether the declaration is a `constant(...)`, which occupies no storage
+    pub is_constant: bool,
+    /// Initializer expression, only written for constants
+    pub value: Option<String>,
+    pub line_number: usize,
+}
+
+/// Represents an `event Name:` declaration
+#[derive(Debug, Clone)]
+pub struct VyperEvent {
+    pub name: String,
+    pub line_number: usize,
+}
+
+/// Represents a `log Name(...)` statement emitting an event
+#[derive(Debug, Clone)]
+pub struct VyperLog {
+    pub event_name: String,
+    pub line_number: usize,
+}
+
 /// Parsed Vyper contract representation
 #[derive(Debug, Clone)]
 pub struct VyperContract {
     pub functions: Vec<VyperFunction>,
     pub function_calls: Vec<VyperFunctionCall>,
+    pub imports: Vec<VyperImport>,
+    pub state_variables: Vec<VyperStateVariable>,
+    pub events: Vec<VyperEvent>,
+    pub logs: Vec<VyperLog>,
+    /// Raw contract source code
+    pub source: String,
 }
 
 impl VyperContract {
@@ -30,6 +79,10 @@ impl VyperContract {
     pub fn parse(source: &str) -> Result<Self, String> {
         let mut functions = Vec::new();
         let mut function_calls = Vec::new();
+        let mut imports = Vec::new();
+        let mut state_variables = Vec::new();
+        let mut events = Vec::new();
+        let mut logs = Vec::new();
         let mut current_decorators: Vec<String> = Vec::new();
         let mut decorator_start_line: Option<usize> = None;
 
@@ -37,10 +90,93 @@ impl VyperContract {
         let decorator_pattern = Regex::new(r"^@(\w+)").map_err(|e| e.to_string())?;
         let function_pattern = Regex::new(r"^def\s+(\w+)\s*\(").map_err(|e| e.to_string())?;
         let self_call_pattern = Regex::new(r"self\.(\w+)\s*\(").map_err(|e| e.to_string())?;
+        let import_pattern = Regex::new(r"^import\s+([\w.]+)(?:\s+as\s+(\w+))?").map_err(|e| e.to_string())?;
+        let from_import_pattern = Regex::new(r"^from\s+([\w.]+)\s+import\s+(.+)$").map_err(|e| e.to_string())?;
+        let event_pattern = Regex::new(r"^event\s+(\w+)\s*:").map_err(|e| e.to_string())?;
+        // `log.Name(...)` is the pre-0.2 spelling
+        let log_pattern = Regex::new(r"\blog\s*(?:\.\s*)?(\w+)\s*\(").map_err(|e| e.to_string())?;
+        let state_variable_pattern = Regex::new(r"^(\w+)\s*:\s*(.+)$").map_err(|e| e.to_string())?;
+
+        let mut in_docstring = false;
 
         for (line_idx, line) in source.lines().enumerate() {
             let line_number = line_idx + 1;
-            let trimmed = line.trim();
+            // Everything below matches against code only, so decorators and
+            // calls mentioned in comments or docstrings are not picked up
+            let code = Self::code_portion(line, &mut in_docstring);
+            let trimmed = code.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            // Check for import statements
+            if let Some(captures) = import_pattern.captures(trimmed) {
+                let module = captures[1].to_string();
+                let name = captures
+                    .get(2)
+                    .map(|alias| alias.as_str().to_string())
+                    .unwrap_or_else(|| module.rsplit('.').next().unwrap_or(&module).to_string());
+                imports.push(VyperImport {
+                    module,
+                    name,
+                    line_number,
+                });
+                continue;
+            }
+            if let Some(captures) = from_import_pattern.captures(trimmed) {
+                let module = captures[1].to_string();
+                for imported in captures[2].trim_matches(|c| c == '(' || c == ')').split(',') {
+                    let mut parts = imported.split_whitespace();
+                    let (Some(original), alias) = (parts.next(), parts.nth(1)) else {
+                        continue;
+                    };
+                    imports.push(VyperImport {
+                        module: module.clone(),
+                        name: alias.unwrap_or(original).to_string(),
+                        line_number,
+                    });
+                }
+                continue;
+            }
+
+            // Storage variables are declared unindented; struct, event and
+            // interface members are always indented
+            let is_top_level = !line.starts_with(char::is_whitespace);
+            if is_top_level {
+                if let Some(captures) = event_pattern.captures(trimmed) {
+                    events.push(VyperEvent {
+                        name: captures[1].to_string(),
+                        line_number,
+                    });
+                    continue;
+                }
+                if let Some(captures) = state_variable_pattern.captures(trimmed) {
+                    let name = captures[1].to_string();
+                    // Module-level statements share the `name: value` shape
+                    if !matches!(name.as_str(), "implements" | "uses" | "initializes" | "exports") {
+                        let (declared, value) = match captures[2].split_once('=') {
+                            Some((declared, value)) => (declared.trim(), Some(value.trim())),
+                            None => (captures[2].trim(), None),
+                        };
+                        let public_type = declared
+                            .strip_prefix("public(")
+                            .and_then(|rest| rest.strip_suffix(')'));
+                        let declared = public_type.unwrap_or(declared).trim();
+                        let constant_type = declared
+                            .strip_prefix("constant(")
+                            .and_then(|rest| rest.strip_suffix(')'));
+                        state_variables.push(VyperStateVariable {
+                            name,
+                            type_name: constant_type.unwrap_or(declared).trim().to_string(),
+                            is_public: public_type.is_some(),
+                            is_constant: constant_type.is_some(),
+                            value: value.map(str::to_string),
+                            line_number,
+                        });
+                        continue;
+                    }
+                }
+            }
 
             // Check for decorator
             if let Some(captures) = decorator_pattern.captures(trimmed) {
@@ -55,25 +191,35 @@ impl VyperContract {
             else if let Some(captures) = function_pattern.captures(trimmed) {
                 if let Some(func_name) = captures.get(1) {
                     let func_line = decorator_start_line.unwrap_or(line_number);
+                    let signature_end = Self::signature_end(source, line_idx);
                     functions.push(VyperFunction {
                         name: func_name.as_str().to_string(),
                         decorators: current_decorators.clone(),
                         line_number: func_line,
                         column_number: 1,
+                        body: Self::function_body(source, signature_end + 1),
+                        body_start_line: signature_end + 2,
                     });
                     current_decorators.clear();
                     decorator_start_line = None;
                 }
             }
             // Check for non-decorator, non-function lines (reset decorators if we hit something else)
-            else if !trimmed.is_empty() && !trimmed.starts_with('#') {
-                // If we encounter a non-empty, non-comment line that's not a decorator or function,
+            else {
+                // If we encounter a code line that's not a decorator or function,
                 // and we have pending decorators, they might be orphaned (edge case)
                 // For now, we keep collecting decorators until we hit a function
             }
 
+            for captures in log_pattern.captures_iter(trimmed) {
+                logs.push(VyperLog {
+                    event_name: captures[1].to_string(),
+                    line_number,
+                });
+            }
+
             // Track self.function() calls for internal usage analysis
-            for captures in self_call_pattern.captures_iter(line) {
+            for captures in self_call_pattern.captures_iter(trimmed) {
                 if let Some(func_name) = captures.get(1) {
                     function_calls.push(VyperFunctionCall {
                         function_name: func_name.as_str().to_string(),
@@ -87,9 +233,100 @@ impl VyperContract {
         Ok(VyperContract {
             functions,
             function_calls,
+            imports,
+            state_variables,
+            events,
+            logs,
+            source: source.to_string(),
         })
     }
 
+    /// The part of `line` that is code: text inside a `"""`/`'''` docstring and
+    /// any trailing `#` comment are dropped
+    ///
+    /// `in_docstring` carries an open docstring over to the following lines.
+    pub(crate) fn code_portion(line: &str, in_docstring: &mut bool) -> String {
+        let mut code = String::new();
+        let mut rest = line;
+
+        loop {
+            if *in_docstring {
+                let close = [rest.find("\"\"\""), rest.find("'''")]
+                    .into_iter()
+                    .flatten()
+                    .min();
+                let Some(close) = close else {
+                    return code;
+                };
+                *in_docstring = false;
+                rest = &rest[close + 3..];
+                continue;
+            }
+
+            let mut quote: Option<char> = None;
+            let mut docstring_at = None;
+            for (idx, c) in rest.char_indices() {
+                match quote {
+                    Some(q) if c == q => quote = None,
+                    Some(_) => {}
+                    None if rest[idx..].starts_with("\"\"\"") || rest[idx..].starts_with("'''") => {
+                        docstring_at = Some(idx);
+                        break;
+                    }
+                    None if c == '#' => {
+                        code.push_str(&rest[..idx]);
+                        return code;
+                    }
+                    None if c == '"' || c == '\'' => quote = Some(c),
+                    None => {}
+                }
+            }
+
+            let Some(open) = docstring_at else {
+                code.push_str(rest);
+                return code;
+            };
+            code.push_str(&rest[..open]);
+            *in_docstring = true;
+            rest = &rest[open + 3..];
+        }
+    }
+
+    /// Index (0-based) of the line ending the signature that starts at `def_idx`
+    ///
+    /// Parameters may be spread over several lines, including unindented
+    /// closing parentheses, so the body starts after the `:` at depth zero.
+    fn signature_end(source: &str, def_idx: usize) -> usize {
+        let mut depth = 0i32;
+        for (idx, line) in source.lines().enumerate().skip(def_idx) {
+            let code = line.split('#').next().unwrap_or("");
+            for c in code.chars() {
+                match c {
+                    '(' | '[' => depth += 1,
+                    ')' | ']' => depth -= 1,
+                    _ => {}
+                }
+            }
+            if depth <= 0 && code.trim_end().ends_with(':') {
+                return idx;
+            }
+        }
+        def_idx
+    }
+
+    /// Lines from `start_idx` (0-based) until the next unindented statement
+    fn function_body(source: &str, start_idx: usize) -> String {
+        source
+            .lines()
+            .skip(start_idx)
+            .take_while(|line| {
+                let trimmed = line.trim();
+                trimmed.is_empty() || trimmed.starts_with('#') || line.starts_with(char::is_whitespace)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Get all functions that are only called internally (via self.)
     pub fn get_internally_called_functions(&self) -> HashSet<String> {
         self.function_calls
@@ -140,6 +377,43 @@ def _helper():
         assert_eq!(contract.functions[0].decorators, vec!["internal"]);
     }
 
+    #[test]
+    fn test_parse_function_body() {
+        let source = r#"
+@external
+def set_value(v: uint256):
+    self.value = v
+
+    log Updated(v)
+value: uint256
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let function = &contract.functions[0];
+        assert_eq!(function.body_start_line, 4);
+        assert_eq!(function.body.lines().count(), 3);
+        assert!(function.body.contains("self.value = v"));
+        assert!(!function.body.contains("value: uint256"));
+    }
+
+    #[test]
+    fn test_parse_multiline_signature_body() {
+        let source = r#"
+@external
+def set_fee(
+    new_fee: uint256,
+    note: String[32],
+) -> bool:
+    self.fee = new_fee
+    return True
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let function = &contract.functions[0];
+        assert_eq!(function.body_start_line, 7);
+        assert_eq!(function.body.lines().count(), 2);
+        assert!(function.body.contains("self.fee = new_fee"));
+        assert!(!function.body.contains("note"));
+    }
+
     #[test]
     fn test_parse_multiple_decorators() {
         let source = r#"
@@ -175,6 +449,95 @@ def _helper():
             .contains("another_function"));
     }
 
+    #[test]
+    fn test_parse_imports() {
+        let source = r#"
+# @version ^0.3.0
+from vyper.interfaces import ERC20, ERC721 as NFT
+import interfaces.Oracle as PriceOracle
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let names: Vec<&str> = contract.imports.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["ERC20", "NFT", "PriceOracle"]);
+        assert_eq!(contract.imports[0].module, "vyper.interfaces");
+        assert_eq!(contract.imports[2].line_number, 4);
+    }
+
+    #[test]
+    fn test_parse_state_variables() {
+        let source = r#"
+# @version ^0.3.0
+implements: ERC20
+
+struct Point:
+    x: int128
+
+owner: public(address)
+nonces: public(HashMap[address, uint256])  # replay protection
+total: uint256
+SCALE: public(constant(uint256)) = 10 ** 18
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let names: Vec<&str> = contract.state_variables.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["owner", "nonces", "total", "SCALE"]);
+        assert!(contract.state_variables[1].is_public);
+        assert_eq!(contract.state_variables[1].type_name, "HashMap[address, uint256]");
+        assert!(!contract.state_variables[2].is_public);
+        assert!(contract.state_variables[3].is_public && contract.state_variables[3].is_constant);
+        assert_eq!(contract.state_variables[3].type_name, "uint256");
+        assert_eq!(contract.state_variables[3].value.as_deref(), Some("10 ** 18"));
+    }
+
+    #[test]
+    fn test_parse_events_and_logs() {
+        let source = r#"
+event Transfer:
+    sender: indexed(address)
+    amount: uint256
+
+event Paused: pass
+
+@external
+def transfer(to: address, amount: uint256):
+    log Transfer(msg.sender, amount)
+    # log Paused()
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let events: Vec<&str> = contract.events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(events, vec!["Transfer", "Paused"]);
+        assert!(contract.state_variables.is_empty());
+        assert_eq!(contract.logs.len(), 1);
+        assert_eq!(contract.logs[0].event_name, "Transfer");
+        assert_eq!(contract.logs[0].line_number, 10);
+    }
+
+    #[test]
+    fn test_ignores_docstrings_and_comments() {
+        let source = r#"
+"""
+@title Vault
+@notice Call this.  self._helper() does the math
+"""
+
+# @external
+@internal
+def _helper():
+    """Never calls self._other() itself"""
+    pass  # self._other()
+
+@external
+def main():
+    self._helper()
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let names: Vec<&str> = contract.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["_helper", "main"]);
+        assert_eq!(contract.functions[0].decorators, vec!["internal"]);
+        assert_eq!(contract.function_calls.len(), 1);
+        assert_eq!(contract.function_calls[0].line_number, 15);
+        assert!(contract.state_variables.is_empty());
+    }
+
     #[test]
     fn test_internal_naming_convention() {
         assert!(VyperContract::is_internal_naming_convention("_helper"));
@@ -0,0 +1,110 @@
+use crate::rule_engine::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::vyper::parser::{VyperContract, VyperStateVariable};
+use crate::vyper::redundant_external::VyperRule;
+
+/// Name segments that suggest a value should not be freely readable
+const SENSITIVE_SEGMENTS: [&str; 6] = ["secret", "private", "nonce", "nonces", "key", "keys"];
+
+/// Rule for detecting `public` storage variables whose names suggest sensitive data
+///
+/// `public(...)` generates an external getter for the variable. For internal
+/// bookkeeping such as nonces or keys that getter exposes more than intended.
+pub struct PublicSensitiveDataRule;
+
+impl VyperRule for PublicSensitiveDataRule {
+    fn name(&self) -> &str {
+        "vyper-public-sensitive-data"
+    }
+
+    fn description(&self) -> &str {
+        "Detects public storage variables and mappings whose names suggest secret or internal data."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
+        contract
+            .state_variables
+            .iter()
+            .filter(|variable| variable.is_public && Self::is_sensitive_name(&variable.name))
+            .map(|variable| self.create_violation(variable))
+            .collect()
+    }
+
+    fn coverage(&self, contract: &VyperContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.state_variables.len(),
+            uninspected: 0,
+        }
+    }
+}
+
+impl PublicSensitiveDataRule {
+    fn is_sensitive_name(name: &str) -> bool {
+        name.to_lowercase()
+            .split('_')
+            .any(|segment| SENSITIVE_SEGMENTS.contains(&segment))
+    }
+
+    fn create_violation(&self, variable: &VyperStateVariable) -> RuleViolation {
+        let kind = if variable.type_name.starts_with("HashMap") {
+            "mapping"
+        } else {
+            "variable"
+        };
+
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Public {} '{}' generates an external getter for data that looks sensitive.",
+                kind, variable.name
+            ),
+            severity: self.severity(),
+            line_number: variable.line_number,
+            column_number: 1,
+            variable_name: variable.name.clone(),
+            suggestion: format!(
+                "Declare '{}' without public() and expose it through an explicit, access-controlled getter if it must be readable.",
+                variable.name
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public_nonce_map_is_flagged() {
+        let source = r#"
+# @version ^0.3.0
+nonces: public(HashMap[address, uint256])
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = PublicSensitiveDataRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "nonces");
+        assert_eq!(violations[0].line_number, 3);
+    }
+
+    #[test]
+    fn test_public_balances_map_is_not_flagged() {
+        let source = r#"
+# @version ^0.3.0
+balances: public(HashMap[address, uint256])
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = PublicSensitiveDataRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -1,5 +1,12 @@
-use crate::rule_engine::{RuleViolation, ViolationSeverity};
+use crate::rule_engine::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::vyper::constant_expression::ConstantExpressionRule;
+use crate::vyper::decorator_order::DecoratorOrderRule;
 use crate::vyper::parser::{VyperContract, VyperFunction};
+use crate::vyper::public_sensitive_data::PublicSensitiveDataRule;
+use crate::vyper::redundant_public_getter::RedundantPublicGetterRule;
+use crate::vyper::unemitted_event::UnemittedEventRule;
+use crate::vyper::unguarded_state_change::UnguardedStateChangeRule;
+use crate::vyper::unused_import::UnusedImportRule;
 use std::collections::HashSet;
 
 /// Rule for detecting redundant @external decorators on internal Vyper functions
@@ -10,10 +17,26 @@ use std::collections::HashSet;
 pub struct RedundantExternalDecoratorRule;
 
 /// Vyper-specific rule trait for analyzing Vyper contracts
-pub trait VyperRule {
+pub trait VyperRule: Send + Sync {
     fn name(&self) -> &str;
     fn description(&self) -> &str;
+    /// Severity the rule reports its findings at
+    fn severity(&self) -> ViolationSeverity;
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Gas
+    }
+    /// Whether `gasguard fix` can apply the suggestion without review
+    fn auto_fixable(&self) -> bool {
+        false
+    }
     fn check(&self, contract: &VyperContract) -> Vec<RuleViolation>;
+    /// Count what the rule inspected; most rules look at functions
+    fn coverage(&self, contract: &VyperContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.functions.len(),
+            uninspected: 0,
+        }
+    }
 }
 
 impl VyperRule for RedundantExternalDecoratorRule {
@@ -25,6 +48,10 @@ impl VyperRule for RedundantExternalDecoratorRule {
         "Detects internal functions that are accidentally marked as @external, which leads to higher gas consumption and potential security gaps."
     }
 
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
     fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
         let mut violations = Vec::new();
 
@@ -73,7 +100,7 @@ impl RedundantExternalDecoratorRule {
                 This may expose internal logic unnecessarily and increase gas costs.",
                 func.name
             ),
-            severity: ViolationSeverity::Warning,
+            severity: self.severity(),
             line_number: func.line_number,
             column_number: func.column_number,
             variable_name: func.name.clone(),
@@ -82,6 +109,7 @@ impl RedundantExternalDecoratorRule {
                 Internal functions save gas by not generating external interface code and improve security by not exposing internal logic.",
                 func.name
             ),
+            estimated_saving: None,
         }
     }
 
@@ -94,7 +122,7 @@ impl RedundantExternalDecoratorRule {
                 This wastes gas and may expose internal logic unnecessarily.",
                 func.name, func.name
             ),
-            severity: ViolationSeverity::Warning,
+            severity: self.severity(),
             line_number: func.line_number,
             column_number: func.column_number,
             variable_name: func.name.clone(),
@@ -103,6 +131,7 @@ impl RedundantExternalDecoratorRule {
                 Internal functions are more gas-efficient and don't expose the function in the contract's ABI.",
                 func.name
             ),
+            estimated_saving: None,
         }
     }
 
@@ -156,16 +185,28 @@ impl RedundantExternalDecoratorRule {
 /// Vyper rule engine for running Vyper-specific rules
 pub struct VyperRuleEngine {
     rules: Vec<Box<dyn VyperRule>>,
+    /// Names of rules skipped by `analyze`
+    disabled: HashSet<String>,
 }
 
 impl VyperRuleEngine {
     pub fn new() -> Self {
-        Self { rules: Vec::new() }
+        Self {
+            rules: Vec::new(),
+            disabled: HashSet::new(),
+        }
     }
 
     pub fn with_default_rules() -> Self {
         let mut engine = Self::new();
         engine.add_rule(Box::new(RedundantExternalDecoratorRule));
+        engine.add_rule(Box::new(UnusedImportRule));
+        engine.add_rule(Box::new(PublicSensitiveDataRule));
+        engine.add_rule(Box::new(UnguardedStateChangeRule));
+        engine.add_rule(Box::new(ConstantExpressionRule));
+        engine.add_rule(Box::new(DecoratorOrderRule));
+        engine.add_rule(Box::new(UnemittedEventRule));
+        engine.add_rule(Box::new(RedundantPublicGetterRule));
         engine
     }
 
@@ -173,16 +214,45 @@ impl VyperRuleEngine {
         self.rules.push(rule);
     }
 
+    pub fn rules(&self) -> impl Iterator<Item = &dyn VyperRule> {
+        self.rules.iter().map(|r| r.as_ref())
+    }
+
+    /// Enable or disable a rule by name; unknown names are ignored
+    pub fn set_rule_enabled(&mut self, name: &str, enabled: bool) {
+        if enabled {
+            self.disabled.remove(name);
+        } else if self.rules.iter().any(|r| r.name() == name) {
+            self.disabled.insert(name.to_string());
+        }
+    }
+
+    pub fn is_rule_enabled(&self, name: &str) -> bool {
+        !self.disabled.contains(name)
+    }
+
     pub fn analyze(&self, source: &str) -> Result<Vec<RuleViolation>, String> {
         let contract = VyperContract::parse(source)?;
 
         let mut violations = Vec::new();
-        for rule in &self.rules {
+        for rule in self.rules.iter().filter(|r| !self.disabled.contains(r.name())) {
             violations.extend(rule.check(&contract));
         }
 
         Ok(violations)
     }
+
+    /// Count, per enabled rule, what it inspected in `source`
+    pub fn coverage(&self, source: &str) -> Result<Vec<(String, RuleCoverage)>, String> {
+        let contract = VyperContract::parse(source)?;
+
+        Ok(self
+            .rules
+            .iter()
+            .filter(|r| !self.disabled.contains(r.name()))
+            .map(|r| (r.name().to_string(), r.coverage(&contract)))
+            .collect())
+    }
 }
 
 impl Default for VyperRuleEngine {
@@ -0,0 +1,121 @@
+use crate::rule_engine::{RuleViolation, ViolationSeverity};
+use crate::vyper::parser::{VyperContract, VyperFunction};
+use crate::vyper::redundant_external::VyperRule;
+use regex::Regex;
+
+/// Rule for detecting hand-written getters of storage variables that are already `public`
+///
+/// Vyper generates an external getter for every `public(...)` storage
+/// variable, so an `@external @view` function that only returns it duplicates
+/// that getter in the ABI and the bytecode.
+pub struct RedundantPublicGetterRule;
+
+impl VyperRule for RedundantPublicGetterRule {
+    fn name(&self) -> &str {
+        "vyper-redundant-public-getter"
+    }
+
+    fn description(&self) -> &str {
+        "Detects @external @view functions that only return a storage variable already declared public."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
+        let return_pattern = Regex::new(r"^return\s+self\.(\w+)$").unwrap();
+
+        contract
+            .functions
+            .iter()
+            .filter(|func| {
+                VyperContract::function_has_decorator(func, "external")
+                    && VyperContract::function_has_decorator(func, "view")
+            })
+            .filter_map(|func| {
+                let statement = Self::only_statement(func)?;
+                let variable = return_pattern.captures(&statement)?[1].to_string();
+                contract
+                    .state_variables
+                    .iter()
+                    .any(|var| var.is_public && var.name == variable)
+                    .then(|| self.create_violation(func, &variable))
+            })
+            .collect()
+    }
+}
+
+impl RedundantPublicGetterRule {
+    /// The body's single statement, without comments and docstrings
+    fn only_statement(func: &VyperFunction) -> Option<String> {
+        let mut in_docstring = false;
+        let mut statements = func
+            .body
+            .lines()
+            .map(|line| VyperContract::code_portion(line, &mut in_docstring))
+            .map(|code| code.trim().to_string())
+            .filter(|code| !code.is_empty());
+
+        let statement = statements.next()?;
+        statements.next().is_none().then_some(statement)
+    }
+
+    fn create_violation(&self, func: &VyperFunction, variable: &str) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "Function '{}' only returns 'self.{}', which is public and already has a generated getter '{}()'.",
+                func.name, variable, variable
+            ),
+            severity: self.severity(),
+            line_number: func.line_number,
+            column_number: 1,
+            variable_name: func.name.clone(),
+            suggestion: format!(
+                "Remove '{}' and have callers use the generated '{}()' getter.",
+                func.name, variable
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_getter_of_public_variable_is_flagged() {
+        let source = r#"
+owner: public(address)
+
+@external
+@view
+def get_owner() -> address:
+    """Return the owner"""
+    return self.owner
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = RedundantPublicGetterRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "get_owner");
+    }
+
+    #[test]
+    fn test_getter_of_private_variable_is_not_flagged() {
+        let source = r#"
+owner: address
+
+@external
+@view
+def get_owner() -> address:
+    return self.owner
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = RedundantPublicGetterRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
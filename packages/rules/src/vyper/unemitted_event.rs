@@ -0,0 +1,113 @@
+use crate::rule_engine::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::vyper::parser::{VyperContract, VyperEvent};
+use crate::vyper::redundant_external::VyperRule;
+use std::collections::HashSet;
+
+/// Rule for detecting events that are declared but never emitted with `log`
+///
+/// A dead event still bloats the ABI, and more often means a state change
+/// that off-chain indexers expect to see is missing its `log` statement.
+pub struct UnemittedEventRule;
+
+impl VyperRule for UnemittedEventRule {
+    fn name(&self) -> &str {
+        "vyper-unemitted-event"
+    }
+
+    fn description(&self) -> &str {
+        "Detects events declared with `event` that no `log` statement ever emits."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Correctness
+    }
+
+    fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
+        let emitted: HashSet<&str> = contract
+            .logs
+            .iter()
+            .map(|log| log.event_name.as_str())
+            .collect();
+
+        contract
+            .events
+            .iter()
+            .filter(|event| !emitted.contains(event.name.as_str()))
+            .map(|event| self.create_violation(event))
+            .collect()
+    }
+
+    fn coverage(&self, contract: &VyperContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.events.len(),
+            uninspected: 0,
+        }
+    }
+}
+
+impl UnemittedEventRule {
+    fn create_violation(&self, event: &VyperEvent) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!("Event '{}' is declared but never emitted.", event.name),
+            severity: self.severity(),
+            line_number: event.line_number,
+            column_number: 1,
+            variable_name: event.name.clone(),
+            suggestion: format!(
+                "Emit '{}' with `log {}(...)` where the state it describes changes, or remove the declaration.",
+                event.name, event.name
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unemitted_event_is_flagged() {
+        let source = r#"
+event Transfer:
+    sender: indexed(address)
+    amount: uint256
+
+event Approval:
+    owner: indexed(address)
+    amount: uint256
+
+@external
+def transfer(to: address, amount: uint256):
+    log Transfer(msg.sender, amount)
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = UnemittedEventRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "Approval");
+        assert_eq!(violations[0].line_number, 6);
+    }
+
+    #[test]
+    fn test_emitted_event_is_not_flagged() {
+        let source = r#"
+event Transfer:
+    sender: indexed(address)
+    amount: uint256
+
+@external
+def transfer(to: address, amount: uint256):
+    log Transfer(msg.sender, amount)
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = UnemittedEventRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
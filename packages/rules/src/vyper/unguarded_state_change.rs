@@ -0,0 +1,152 @@
+use crate::rule_engine::{RuleCategory, RuleViolation, ViolationSeverity};
+use crate::vyper::parser::{VyperContract, VyperFunction};
+use crate::vyper::redundant_external::VyperRule;
+use regex::Regex;
+
+/// Rule for detecting `@external` functions that write storage without checking the caller
+///
+/// Any account can call an external function. One that assigns to `self.<var>`
+/// before any `assert` on `msg.sender` lets anyone change that state.
+pub struct UnguardedStateChangeRule;
+
+impl VyperRule for UnguardedStateChangeRule {
+    fn name(&self) -> &str {
+        "vyper-unguarded-state-change"
+    }
+
+    fn description(&self) -> &str {
+        "Detects state-modifying @external functions that write self.<var> without a preceding assert on msg.sender."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Warning
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Security
+    }
+
+    fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
+        let write_pattern =
+            Regex::new(r"^self\.(\w+)(?:\[[^\]]*\])*\s*(?:=[^=]|[-+*/%]=)").unwrap();
+        let guard_pattern = Regex::new(r"^assert\b.*\bmsg\.sender\b").unwrap();
+
+        contract
+            .functions
+            .iter()
+            .filter(|function| Self::is_state_changing_entry_point(function))
+            .filter_map(|function| {
+                for (offset, line) in function.body.lines().enumerate() {
+                    let code = line.split('#').next().unwrap_or("").trim();
+                    if guard_pattern.is_match(code) {
+                        return None;
+                    }
+                    if let Some(captures) = write_pattern.captures(code) {
+                        return Some(self.create_violation(
+                            function,
+                            &captures[1],
+                            function.body_start_line + offset,
+                        ));
+                    }
+                }
+                None
+            })
+            .collect()
+    }
+}
+
+impl UnguardedStateChangeRule {
+    fn is_state_changing_entry_point(function: &VyperFunction) -> bool {
+        let has = |decorator| VyperContract::function_has_decorator(function, decorator);
+        // The constructor runs once, from the deployer
+        has("external")
+            && !has("view")
+            && !has("pure")
+            && !has("deploy")
+            && function.name != "__init__"
+    }
+
+    fn create_violation(
+        &self,
+        function: &VyperFunction,
+        variable: &str,
+        line_number: usize,
+    ) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "External function '{}' writes 'self.{}' without checking msg.sender first.",
+                function.name, variable
+            ),
+            severity: self.severity(),
+            line_number,
+            column_number: 1,
+            variable_name: function.name.clone(),
+            suggestion: format!(
+                "Add an access check such as 'assert msg.sender == self.owner' at the start of '{}', or document why it is open to any caller.",
+                function.name
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unguarded_state_write_is_flagged() {
+        let source = r#"
+# @version ^0.3.0
+fee: public(uint256)
+
+@external
+def set_fee(new_fee: uint256):
+    self.fee = new_fee
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = UnguardedStateChangeRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "set_fee");
+        assert_eq!(violations[0].line_number, 7);
+    }
+
+    #[test]
+    fn test_owner_gated_write_is_not_flagged() {
+        let source = r#"
+# @version ^0.3.0
+owner: public(address)
+fee: public(uint256)
+
+@external
+def set_fee(new_fee: uint256):
+    assert msg.sender == self.owner, "not owner"
+    self.fee = new_fee
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = UnguardedStateChangeRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_unguarded_write_with_multiline_signature_is_flagged() {
+        let source = r#"
+# @version ^0.3.0
+fee: public(uint256)
+
+@external
+def set_fee(
+    new_fee: uint256,
+):
+    self.fee = new_fee
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = UnguardedStateChangeRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line_number, 9);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::rule_engine::{RuleCategory, RuleCoverage, RuleViolation, ViolationSeverity};
+use crate::vyper::parser::{VyperContract, VyperImport};
+use crate::vyper::redundant_external::VyperRule;
+use regex::Regex;
+use std::collections::HashSet;
+
+/// Rule for detecting imported interfaces and modules that are never referenced
+///
+/// An unused import still has to be resolved and compiled, and usually points
+/// at an interface the contract stopped calling.
+pub struct UnusedImportRule;
+
+impl VyperRule for UnusedImportRule {
+    fn name(&self) -> &str {
+        "vyper-unused-import"
+    }
+
+    fn description(&self) -> &str {
+        "Detects imported interfaces or modules that are never referenced in the contract."
+    }
+
+    fn severity(&self) -> ViolationSeverity {
+        ViolationSeverity::Info
+    }
+
+    fn category(&self) -> RuleCategory {
+        RuleCategory::Style
+    }
+
+    fn check(&self, contract: &VyperContract) -> Vec<RuleViolation> {
+        let import_lines: HashSet<usize> = contract.imports.iter().map(|i| i.line_number).collect();
+
+        // Only code lines count as usage; the import statements themselves are excluded
+        let code: Vec<&str> = contract
+            .source
+            .lines()
+            .enumerate()
+            .filter(|(idx, _)| !import_lines.contains(&(idx + 1)))
+            .map(|(_, line)| line.split('#').next().unwrap_or(""))
+            .collect();
+        let code = code.join("\n");
+
+        contract
+            .imports
+            .iter()
+            .filter(|import| !Self::is_referenced(&code, &import.name))
+            .map(|import| self.create_violation(import))
+            .collect()
+    }
+
+    fn coverage(&self, contract: &VyperContract) -> RuleCoverage {
+        RuleCoverage {
+            inspected: contract.imports.len(),
+            uninspected: 0,
+        }
+    }
+}
+
+impl UnusedImportRule {
+    fn is_referenced(code: &str, name: &str) -> bool {
+        Regex::new(&format!(r"\b{}\b", regex::escape(name)))
+            .map(|re| re.is_match(code))
+            .unwrap_or(true)
+    }
+
+    fn create_violation(&self, import: &VyperImport) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            description: format!(
+                "'{}' is imported from '{}' but never used.",
+                import.name, import.module
+            ),
+            severity: self.severity(),
+            line_number: import.line_number,
+            column_number: 1,
+            variable_name: import.name.clone(),
+            suggestion: format!(
+                "Remove the unused import of '{}' to reduce compilation work and dead code.",
+                import.name
+            ),
+            estimated_saving: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unused_import_is_flagged() {
+        let source = r#"
+# @version ^0.3.0
+from vyper.interfaces import ERC20
+
+@external
+def ping() -> uint256:
+    return 1
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = UnusedImportRule.check(&contract);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].variable_name, "ERC20");
+        assert_eq!(violations[0].line_number, 3);
+    }
+
+    #[test]
+    fn test_used_import_is_not_flagged() {
+        let source = r#"
+# @version ^0.3.0
+from vyper.interfaces import ERC20
+
+token: public(address)
+
+@external
+def balance_of(owner: address) -> uint256:
+    return ERC20(self.token).balanceOf(owner)
+"#;
+        let contract = VyperContract::parse(source).unwrap();
+        let violations = UnusedImportRule.check(&contract);
+
+        assert!(violations.is_empty());
+    }
+}
@@ -135,29 +135,31 @@ impl WastefulContract {
 fn test_storage_savings_calculation() {
     let violations = vec![
         gasguard_rules::RuleViolation {
-            rule_name: "unused-state-variables".to_string(),
+            rule_name: "unused-state-variable".to_string(),
             description: "Test violation 1".to_string(),
             severity: gasguard_rules::ViolationSeverity::Warning,
             line_number: 10,
             column_number: 4,
             variable_name: "unused_var1".to_string(),
             suggestion: "Remove it".to_string(),
+            estimated_saving: Some(gasguard_rules::EstimatedSaving::bytes(8)),
         },
         gasguard_rules::RuleViolation {
-            rule_name: "unused-state-variables".to_string(),
+            rule_name: "unused-state-variable".to_string(),
             description: "Test violation 2".to_string(),
             severity: gasguard_rules::ViolationSeverity::Warning,
             line_number: 11,
             column_number: 4,
             variable_name: "unused_var2".to_string(),
             suggestion: "Remove it".to_string(),
+            estimated_saving: Some(gasguard_rules::EstimatedSaving::bytes(32)),
         },
     ];
     
     let savings = ScanAnalyzer::calculate_storage_savings(&violations);
     
     assert_eq!(savings.unused_variables, 2);
-    assert_eq!(savings.estimated_savings_kb, 5.0); // 2 * 2.5 KB per variable
+    assert_eq!(savings.estimated_savings_bytes, 40); // u64 + Address
     assert!(savings.monthly_ledger_rent_savings > 0.0);
 }
 